@@ -1,7 +1,28 @@
 //! Example showing most basic bot functionality of coming online.
 // Not Complete!
 
+use async_trait::async_trait;
 use tonsoe::bot::*;
+use tonsoe::event_handler::*;
+use tonsoe::gateway::EventType;
+use tonsoe::gateway_structs::Ready;
+
+/// Handler which reacts to events recieved by the bot.
+struct Handler;
+
+#[async_trait]
+impl EventHandler for Handler {
+
+    // Declaring the handled events lets the bot warn about intents which are missing for them.
+    fn subscribed_events(&self) -> Vec<EventType> {
+        vec![EventType::Ready]
+    }
+
+    async fn ready(&self, _context: Context, ready: Ready) {
+        println!("{} is now online on shard {:?}!", ready.user.username, ready.shard);
+    }
+}
+
 #[tokio::main]
 async fn main() {
 
@@ -14,13 +35,14 @@ async fn main() {
     let mut bot = Bot::new(token);
 
     // Set any intents you wish by creating an Intents and utilising the set_intents method.
-    let bot_intents = Intents::from(
-        Intents::GUILDS | Intents::DIRECT_MESSAGES | Intents::GUILD_MESSAGES
-    );
+    let bot_intents = Intents::GUILDS | Intents::DIRECT_MESSAGES | Intents::GUILD_MESSAGES;
 
     // Set the intents given by bot_intents to be true
     bot.set_intents(bot_intents, true);
 
+    // Register the handler which will be called for events recieved from discord.
+    bot.add_event_handler(Handler);
+
     // Execute the bot, Some errors in the setup process are     returned
     println!("{:#?}", bot.elevate().await);
 }
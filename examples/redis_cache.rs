@@ -0,0 +1,47 @@
+//! Example showing a bot storing its cache within a Redis-protocol server, so it can be shared by every process running its shards.
+// Requires a server, e.g: a local instance started with `redis-server` & the url within REDIS_URL
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tonsoe::bot::*;
+use tonsoe::cache::CacheConfig;
+use tonsoe::cache::redis::RedisCache;
+use tonsoe::event_handler::*;
+use tonsoe::gateway_structs::GuildCreate;
+
+/// Handler which reads the guilds stored within the cache.
+struct Handler;
+
+#[async_trait]
+impl EventHandler for Handler {
+    async fn guild_create(&self, context: Context, guild: GuildCreate) {
+        let Some(cache) = context.cache else { return };
+
+        match cache.guild_channels(guild.id()).await {
+            Ok(channels) => println!("Guild {} has {} cached channels", guild.id(), channels.len()),
+            Err(error) => println!("Failed to read the cache: {error}"),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+
+    let token = std::env::var("BOT_TOKEN")
+        .expect("Attempted to retrieve BOT_TOKEN from env var");
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+
+    let mut bot = Bot::new(token);
+    bot.set_intents(Intents::GUILDS, true);
+
+    // Connect to the server, keys are prefixed so several bots are able to share it.
+    let cache = RedisCache::connect(&redis_url, CacheConfig::new()).await
+        .expect("Attempted to connect to the Redis server")
+        .key_prefix("example");
+    bot.set_cache(Arc::new(cache));
+
+    bot.add_event_handler(Handler);
+
+    println!("{:#?}", bot.elevate().await);
+}
@@ -0,0 +1,324 @@
+//! Files uploaded alongside a [`DiscordHttpRequest`][crate::http::DiscordHttpRequest] as [multipart/form-data][https://discord.com/developers/docs/reference#uploading-files].
+use std::{fmt, io};
+
+use bytes::Bytes;
+use reqwest::Body;
+use reqwest::multipart::{Form, Part};
+use serde_json::{json, Value};
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+
+use crate::error::{Error, Result};
+
+/// The prefix Discord utilises to mark an attachment as a spoiler.
+pub const SPOILER_PREFIX: &str = "SPOILER_";
+
+/// Shortened Alias for a reader an [`AttachmentFile`] can be streamed from.
+pub type AttachmentReader = Box<dyn AsyncRead + Send + Sync + Unpin>;
+
+/// Where the contents of an [`AttachmentFile`] are read from.
+pub enum AttachmentSource {
+
+    /// Contents held in memory, these can be sent again when a request is retried.
+    Bytes(Bytes),
+
+    /// Contents streamed from a reader, such as a [`File`][tokio::fs::File].
+    /// The reader is taken when the request is sent, so a request containing one is never retried.
+    Stream {
+
+        /// The reader, None once it has been sent.
+        reader: Option<AttachmentReader>,
+
+        /// The length of the contents if known, otherwise the contents are sent chunked.
+        length: Option<u64>,
+    },
+}
+
+impl fmt::Debug for AttachmentSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Self::Stream { reader, length } => f.debug_struct("Stream")
+                .field("sent", &reader.is_none())
+                .field("length", length)
+                .finish(),
+        }
+    }
+}
+
+#[derive(Debug)]
+/// A file uploaded as an attachment of a message.
+pub struct AttachmentFile {
+
+    /// The name of the file shown within Discord, including its extension.
+    pub filename: String,
+
+    /// The description (alt text) of the file.
+    pub description: Option<String>,
+
+    /// Whether the file is hidden behind a spoiler.
+    pub spoiler: bool,
+
+    /// Where the contents of the file are read from.
+    pub source: AttachmentSource,
+}
+
+impl AttachmentFile {
+
+    /// Creates an [`AttachmentFile`] from contents held in memory.
+    pub fn from_bytes(filename: impl Into<String>, contents: impl Into<Bytes>) -> Self {
+        Self::new(filename.into(), AttachmentSource::Bytes(contents.into()))
+    }
+
+    /// Creates an [`AttachmentFile`] streamed from a reader of unknown length.
+    pub fn from_reader(filename: impl Into<String>, reader: impl AsyncRead + Send + Sync + Unpin + 'static) -> Self {
+        Self::new(filename.into(), AttachmentSource::Stream {
+            reader: Some(Box::new(reader)),
+            length: None,
+        })
+    }
+
+    /// Creates an [`AttachmentFile`] streamed from a reader whose contents are length bytes long.
+    pub fn from_reader_with_length(filename: impl Into<String>, reader: impl AsyncRead + Send + Sync + Unpin + 'static, length: u64) -> Self {
+        Self::new(filename.into(), AttachmentSource::Stream {
+            reader: Some(Box::new(reader)),
+            length: Some(length),
+        })
+    }
+
+    /// Opens the file at path to be streamed, named after the file at path.
+    pub async fn open(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let filename = path.file_name()
+            .map(|filename| filename.to_string_lossy().into_owned())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("{} is not a file", path.display())))?;
+
+        let file = tokio::fs::File::open(path).await?;
+        let length = file.metadata().await?.len();
+
+        Ok(Self::from_reader_with_length(filename, file, length))
+    }
+
+    fn new(filename: String, source: AttachmentSource) -> Self {
+        Self {
+            filename,
+            description: None,
+            spoiler: false,
+            source,
+        }
+    }
+
+    /// Sets the description (alt text) of the file.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Hides the file behind a spoiler.
+    pub fn with_spoiler(mut self) -> Self {
+        self.spoiler = true;
+        self
+    }
+
+    /// The filename sent to Discord, prefixed with [`SPOILER_PREFIX`] if the file is a spoiler.
+    pub fn upload_filename(&self) -> String {
+        match self.spoiler && !self.filename.starts_with(SPOILER_PREFIX) {
+            true => format!("{SPOILER_PREFIX}{}", self.filename),
+            false => self.filename.clone(),
+        }
+    }
+
+    /// Whether the file can be sent again, which is only the case for contents held in memory.
+    pub fn is_replayable(&self) -> bool {
+        matches!(self.source, AttachmentSource::Bytes(_))
+    }
+
+    /// The [partial attachment][https://discord.com/developers/docs/resources/channel#attachment-object] describing the file within payload_json.
+    pub fn metadata(&self, id: usize) -> Value {
+        let mut metadata = json!({
+            "id": id,
+            "filename": self.upload_filename(),
+        });
+
+        if let Some(description) = &self.description {
+            metadata["description"] = Value::from(description.as_str());
+        }
+
+        metadata
+    }
+
+    /// Creates the multipart [`Part`] of the file, taking its reader if it is streamed.
+    fn take_part(&mut self) -> Result<Part> {
+        let filename = self.upload_filename();
+
+        let part = match &mut self.source {
+            AttachmentSource::Bytes(bytes) => Part::stream_with_length(Body::from(bytes.clone()), bytes.len() as u64),
+            AttachmentSource::Stream { reader, length } => {
+                let reader = reader.take().ok_or_else(|| Error::AttachmentSent(filename.clone()))?;
+                let body = Body::wrap_stream(ReaderStream::new(reader));
+
+                match length {
+                    Some(length) => Part::stream_with_length(body, *length),
+                    None => Part::stream(body),
+                }
+            },
+        };
+
+        Ok(part.file_name(filename))
+    }
+}
+
+/// Creates the multipart/form-data [`Form`] of a request, containing payload_json & a files[n] part for every file.
+/// A partial attachment is added to the attachments of the payload for each file which doesnt already have one with its id,
+/// so existing attachments listed when editing a message are kept.
+/// The attachments of an interaction response are added to its data, which contains the message.
+pub fn multipart_form(body: Option<&[u8]>, files: &mut [AttachmentFile]) -> Result<Form> {
+
+    let mut payload = match body {
+        Some(body) => serde_json::from_slice(body)?,
+        None => json!({}),
+    };
+
+    // Interaction responses nest the message within data, so the attachments belong to it instead.
+    let message = match payload.get("data").is_some_and(Value::is_object) {
+        true => &mut payload["data"],
+        false => &mut payload,
+    };
+
+    if let Value::Object(message) = message {
+        let attachments = message.entry("attachments").or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Value::Array(attachments) = attachments {
+            for (id, file) in files.iter().enumerate() {
+                let listed = attachments.iter().any(|attachment| attachment["id"].as_u64() == Some(id as u64));
+                if !listed {
+                    attachments.push(file.metadata(id));
+                }
+            }
+        }
+    }
+
+    let payload_json = Part::text(serde_json::to_string(&payload)?).mime_str("application/json")?;
+
+    let mut form = Form::new().part("payload_json", payload_json);
+    for (id, file) in files.iter_mut().enumerate() {
+        form = form.part(format!("files[{id}]"), file.take_part()?);
+    }
+
+    Ok(form)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use reqwest::Method;
+    use serde_json::{Value, json};
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+    use tokio::sync::oneshot;
+
+    use crate::http::{DiscordHttpClient, DiscordHttpReqType, DiscordHttpRequest};
+    use crate::snowflake::Id;
+    use super::AttachmentFile;
+
+    /// A part of a multipart/form-data body, as its name, filename & contents.
+    type FormPart = (String, Option<String>, String);
+
+    /// Sends the request to a server which responds with an empty object, returning the parts of the multipart body it recieved.
+    async fn sent_parts(request: DiscordHttpRequest) -> Vec<FormPart> {
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (body_sender, body_reciever) = oneshot::channel();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            stream.read_line(&mut String::new()).await.unwrap();
+
+            let (mut content_type, mut content_length) = (String::new(), 0);
+            loop {
+                let mut header = String::new();
+                stream.read_line(&mut header).await.unwrap();
+                match header.split_once(':').map(|(name, value)| (name.to_ascii_lowercase(), value.trim().to_string())) {
+                    Some((name, value)) if name == "content-type" => content_type = value,
+                    Some((name, value)) if name == "content-length" => content_length = value.parse().unwrap(),
+                    Some(_) => {},
+                    None => break,
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            stream.read_exact(&mut body).await.unwrap();
+            stream.get_mut().write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\ncontent-type: application/json\r\n\r\n{}").await.unwrap();
+            let _ = body_sender.send((content_type, String::from_utf8(body).unwrap()));
+        });
+
+        let client = DiscordHttpClient::new(&format!("http://{address}"), 10, Arc::from("token")).unwrap();
+        client.execute(request).await.unwrap();
+
+        let (content_type, body) = body_reciever.await.unwrap();
+        let boundary = content_type.split_once("boundary=").unwrap().1;
+        body.split(&format!("--{boundary}"))
+            .filter_map(|part| part.strip_prefix("\r\n")?.strip_suffix("\r\n")?.split_once("\r\n\r\n"))
+            .map(|(headers, contents)| {
+                let disposition = |field: &str| headers.split(&format!("{field}=\"")).nth(1).and_then(|value| value.split('"').next()).map(str::to_string);
+                (disposition("name").unwrap(), disposition("filename"), contents.to_string())
+            })
+            .collect()
+    }
+
+    fn create_message(content: Value, files: Vec<AttachmentFile>) -> DiscordHttpRequest {
+        DiscordHttpRequest::new(DiscordHttpReqType::ChannelMessages { channel_id: Id::new(1) }, Method::POST)
+            .with_json(&content).unwrap()
+            .with_attachments(files)
+    }
+
+    #[tokio::test]
+    async fn files_are_sent_alongside_payload_json() {
+        let files = vec![
+            AttachmentFile::from_bytes("cat.png", "cat").with_description("A cat"),
+            AttachmentFile::from_bytes("secret.txt", "secret").with_spoiler(),
+        ];
+        let parts = sent_parts(create_message(json!({ "content": "files" }), files)).await;
+
+        let names: Vec<&str> = parts.iter().map(|(name, _, _)| name.as_str()).collect();
+        assert_eq!(names, ["payload_json", "files[0]", "files[1]"]);
+
+        assert_eq!(serde_json::from_str::<Value>(&parts[0].2).unwrap(), json!({
+            "content": "files",
+            "attachments": [
+                { "id": 0, "filename": "cat.png", "description": "A cat" },
+                { "id": 1, "filename": "SPOILER_secret.txt" },
+            ],
+        }));
+
+        assert_eq!((parts[1].1.as_deref(), parts[1].2.as_str()), (Some("cat.png"), "cat"));
+        assert_eq!((parts[2].1.as_deref(), parts[2].2.as_str()), (Some("SPOILER_secret.txt"), "secret"));
+    }
+
+    #[tokio::test]
+    async fn listed_attachments_and_existing_spoilers_are_kept() {
+        let files = vec![AttachmentFile::from_bytes("SPOILER_cat.png", "cat").with_spoiler()];
+        let payload = json!({ "attachments": [{ "id": 0, "filename": "renamed.png" }, { "id": "5" }] });
+        let parts = sent_parts(create_message(payload.clone(), files)).await;
+
+        assert_eq!(serde_json::from_str::<Value>(&parts[0].2).unwrap(), payload);
+        assert_eq!(parts[1].1.as_deref(), Some("SPOILER_cat.png"));
+    }
+
+    #[tokio::test]
+    async fn interaction_response_attachments_are_added_to_its_data() {
+        let callback = DiscordHttpReqType::InteractionCallback { interaction_id: Id::new(1), token: "token".to_string() };
+        let request = DiscordHttpRequest::new(callback, Method::POST)
+            .with_json(&json!({ "type": 4, "data": { "content": "file" } })).unwrap()
+            .with_attachments([AttachmentFile::from_bytes("cat.png", "cat")]);
+        let parts = sent_parts(request).await;
+
+        assert_eq!(serde_json::from_str::<Value>(&parts[0].2).unwrap(), json!({
+            "type": 4,
+            "data": { "content": "file", "attachments": [{ "id": 0, "filename": "cat.png" }] },
+        }));
+    }
+}
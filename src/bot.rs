@@ -1,16 +1,28 @@
 
 
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use bitflags;
 use reqwest::{Method};
 use crate::http::*;
-use crate::gateway_structs::GetGatewayBotResponse;
+use crate::gateway::EventType;
+use crate::gateway_structs::{GetGatewayBotResponse, PartialApplication};
+use crate::models::application::ApplicationFlags;
+use crate::commands::{CommandRequests, CommandSyncMode};
+use crate::models::command::ApplicationCommand;
+use crate::snowflake::Id;
+use crate::snowflake::marker::GuildMarker;
 use crate::{BASE_API_URL, DISCORD_API_VERSION};
 use crate::websocket::*;
+use crate::event_handler::EventHandler;
+use crate::retry::RetryPolicy;
+use crate::cache::{CacheBackend, CacheConfig, InMemoryCache};
+#[cfg(feature = "http-interactions")]
+use crate::interaction_server::InteractionServer;
 use tokio::sync::*;
-use anyhow::{Result, Context};
+use crate::error::{Error, Result};
 
 /// Basic structure which represents a Bot inside the library
 pub struct Bot {        
@@ -24,6 +36,27 @@ pub struct Bot {
 
     /// Enum option which determines if automatic sharding should be used or if shards should be created based on a set amount.
     pub sharding_option: ShardingOption,
+
+    /// The [`EventHandler`]s which are called for every event recieved from the Discord Gateway.
+    pub event_handlers: Vec<Arc<dyn EventHandler>>,
+
+    /// The [`RetryPolicy`] utilised for requests to the Discord api which fail.
+    pub retry_policy: RetryPolicy,
+
+    /// The global [`ApplicationCommand`]s of the bot, registered with Discord when the [`Bot`] is elevated.
+    pub commands: Vec<ApplicationCommand>,
+
+    /// The [`ApplicationCommand`]s of the bot within specific guilds, registered with Discord when the [`Bot`] is elevated.
+    pub guild_commands: HashMap<Id<GuildMarker>, Vec<ApplicationCommand>>,
+
+    /// How the declared commands are registered with Discord, only changed commands are by default.
+    pub command_sync_mode: CommandSyncMode,
+
+    /// The cache of the state recieved through the gateway which is passed to the [`EventHandler`]s, None unless enabled.
+    pub cache: Option<Arc<dyn CacheBackend>>,
+
+    /// How the [`Intents`] are checked against the [`EventHandler`]s & the application before connecting, warnings are logged by default.
+    pub intent_validation: IntentValidation,
            
 }
 
@@ -35,6 +68,136 @@ impl Bot {
             token: token.into(),
             intents: Intents::empty(),
             sharding_option: ShardingOption::Automatic,
+            event_handlers: Vec::new(),
+            retry_policy: RetryPolicy::default(),
+            commands: Vec::new(),
+            guild_commands: HashMap::new(),
+            command_sync_mode: CommandSyncMode::default(),
+            cache: None,
+            intent_validation: IntentValidation::default(),
+        }
+    }
+
+    /// Registers an [`EventHandler`] on a [`Bot`] which will be called for every event recieved once the [`Bot`] is elevated.
+    pub fn add_event_handler<H: EventHandler>(&mut self, handler: H) {
+        self.event_handlers.push(Arc::new(handler));
+    }
+
+    /// Declares a global [`ApplicationCommand`], created with a [`CommandBuilder`][crate::models::command::CommandBuilder]
+    /// Once any global command is declared, the global commands registered with Discord are synced to the declared commands when the [`Bot`] is elevated.
+    pub fn add_command(&mut self, command: ApplicationCommand) {
+        self.commands.push(command);
+    }
+
+    /// Declares an [`ApplicationCommand`] within a guild, which is usable immediately unlike global commands.
+    /// The commands of the guild registered with Discord are synced to the declared commands when the [`Bot`] is elevated.
+    pub fn add_guild_command(&mut self, guild_id: Id<GuildMarker>, command: ApplicationCommand) {
+        self.guild_commands.entry(guild_id).or_default().push(command);
+    }
+
+    /// Enables an [`InMemoryCache`] which is kept up to date by the shards & accessible to the [`EventHandler`]s through their [`Context`][crate::event_handler::Context]
+    /// Returns the cache so it can also be read outside of the handlers.
+    pub fn enable_cache(&mut self, config: CacheConfig) -> Arc<InMemoryCache> {
+        let cache = Arc::new(InMemoryCache::new(config));
+        self.cache = Some(cache.clone());
+        cache
+    }
+
+    /// Sets the [`CacheBackend`] which is kept up to date by the shards & accessible to the [`EventHandler`]s through their [`Context`][crate::event_handler::Context]
+    /// Utilised for storing the cache outside of the process, such as within a [`RedisCache`][crate::cache::redis::RedisCache] shared by every process running shards of the bot.
+    pub fn set_cache(&mut self, cache: Arc<dyn CacheBackend>) {
+        self.cache = Some(cache);
+    }
+
+    /// Sets how the [`Intents`] are checked against the [`EventHandler`]s & the application before connecting.
+    /// Which events a handler implements cant be known by the library, so the handled events are only checked for handlers which declare them through [`EventHandler::subscribed_events`]
+    /// The declared events arent checked against the implemented methods either, so an event missing from them is never validated.
+    pub fn set_intent_validation(&mut self, intent_validation: IntentValidation) {
+        self.intent_validation = intent_validation;
+    }
+
+    /// The events handled by the [`EventHandler`]s which can never be recieved, as none of the [`Intents`] they are sent for are enabled.
+    /// Only handlers which declare their [`EventHandler::subscribed_events`] are checked.
+    pub fn unreachable_events(&self) -> Vec<EventType> {
+        let mut unreachable = Vec::new();
+        for event in self.subscribed_events() {
+            if !event.intents().is_empty() && !self.intents.intersects(event.intents()) && !unreachable.contains(&event) {
+                unreachable.push(event);
+            }
+        }
+        unreachable
+    }
+
+    /// The events which the [`EventHandler`]s declared through [`EventHandler::subscribed_events`]
+    fn subscribed_events(&self) -> impl Iterator<Item = EventType> + '_ {
+        self.event_handlers.iter().flat_map(|handler| handler.subscribed_events())
+    }
+
+    /// The fewest [`Intents`] which recieve every event the [`EventHandler`]s declared through [`EventHandler::subscribed_events`]
+    pub fn required_intents(&self) -> Intents {
+        Intents::from_events(self.subscribed_events())
+    }
+
+    /// Sets the intents of a [`Bot`] to the fewest [`Intents`] which recieve every event the [`EventHandler`]s declared through [`EventHandler::subscribed_events`]
+    /// Intents which only change the contents of events, such as MESSAGE_CONTENT, must still be set manually afterwards.
+    pub fn set_required_intents(&mut self) {
+        self.intents = self.required_intents();
+    }
+
+    /// Checks the [`Intents`] against the events handled by the [`EventHandler`]s, depending on the [`IntentValidation`]
+    fn validate_handled_events(&self) -> Result<()> {
+
+        if self.intent_validation == IntentValidation::Off {
+            return Ok(())
+        }
+
+        // Handlers which dont declare their events would otherwise pass the validation without being checked.
+        for handler in self.event_handlers.iter().filter(|handler| handler.subscribed_events().is_empty()) {
+            tracing::warn!("{} doesnt declare its subscribed_events, so the intents arent checked against the events it handles", handler.handler_name());
+        }
+
+        let unreachable = self.unreachable_events();
+        for event in &unreachable {
+            tracing::warn!("{} is handled but will never be recieved, it requires one of the intents {:?}", event.name(), event.intents());
+        }
+
+        // Messages are still recieved without MESSAGE_CONTENT, so this only warns.
+        let handles_messages = self.subscribed_events().any(|event| matches!(event, EventType::MessageCreate | EventType::MessageUpdate));
+        if handles_messages && !self.intents.contains(Intents::MESSAGE_CONTENT) {
+            tracing::warn!("Messages are handled without the MESSAGE_CONTENT intent, their content will be empty unless the bot is mentioned or they are direct messages");
+        }
+
+        match (self.intent_validation, unreachable.is_empty()) {
+            (IntentValidation::Deny, false) => {
+                let names: Vec<&str> = unreachable.iter().map(|event| event.name()).collect();
+                Err(Error::Configuration(format!("the intents {:?} never recieve the handled events {}", self.intents, names.join(", "))))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the privileged [`Intents`] are enabled within the developer page of the application, as identifying with them otherwise closes the connection with 4014.
+    /// Fails unless the [`IntentValidation`] is off, as the shards could never connect.
+    async fn validate_privileged_intents(&self, http: &DiscordHttpClientReqSender) -> Result<()> {
+
+        let requested = self.intents & Intents::privileged();
+        if self.intent_validation == IntentValidation::Off || requested.is_empty() {
+            return Ok(())
+        }
+
+        let application: PartialApplication = DiscordHttpRequest::new(DiscordHttpReqType::GetCurrentApplication, Method::GET).request(http.clone())
+            .await?;
+
+        self.validate_enabled_privileged_intents(application.flags)
+    }
+
+    /// Fails when any of the privileged [`Intents`] arent enabled by the flags of the application.
+    fn validate_enabled_privileged_intents(&self, flags: ApplicationFlags) -> Result<()> {
+
+        let disallowed = (self.intents & Intents::privileged()) - Intents::enabled_privileged(flags);
+        match disallowed.is_empty() {
+            true => Ok(()),
+            false => Err(Error::Configuration(format!("the privileged intents {disallowed:?} are not enabled for the application in the developer page"))),
         }
     }
 
@@ -55,12 +218,62 @@ impl Bot {
 
     /// Main execution for a [`Bot`] and initialisation of a [`DiscordClient`]
     /// Establish a connection to the Discord Gateway & start listening to the events.
+    /// Only returns once a shard stops due to an error it can not recover from, such as an invalid token or disallowed intents.
     pub async fn elevate(self) -> Result<()> {
 
-        // Create the DiscordHttpClient to be able to request data from the Discord Api.
-        let http_client = DiscordHttpClient::new(BASE_API_URL, DISCORD_API_VERSION, self.token.clone())
-            .context("Failed to create DiscordHttpClient")?;
+        self.validate_handled_events()?;
+
+        let http_channel_sender = self.start_http_client()?;
+
+        // Cloning is an acceptable operation as Sender contains an arc so this acts as just cloning a pointer to the sender
+        let client_sender = http_channel_sender.clone();
+
+        // Send a GET request with path gateway/bot/ so we can get information on connecting to the gateway & sharding
+        // Also serves as a way to check if the token is valid or not etc. 
+        let gateway_bot_response : GetGatewayBotResponse = DiscordHttpRequest::new(DiscordHttpReqType::GetGatewayBot, Method::GET).request(client_sender)
+            .await?;
+
+        // Check the privileged intents before identifying, so the bot fails now rather than once each shard is closed by Discord.
+        self.validate_privileged_intents(&http_channel_sender).await?;
+
+        // Register the declared commands before connecting, so they exist by the time interactions are recieved.
+        self.sync_commands(&http_channel_sender).await?;
+
+        // Setup the channel which shards report errors they can not recover from through.
+        let (shard_error_sender, mut shard_error_reciever) = mpsc::channel(1);
+
+        // Create a sharded [`DiscordGatewayClient`]
+        let _gateway_client = DiscordGatewayClient::new_with_shards(&self, gateway_bot_response, http_channel_sender, shard_error_sender).await?;
+        
+        // The shards run within their own tasks, so keep the bot alive until one of them stops with a fatal error.
+        match shard_error_reciever.recv().await {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
         
+    }
+
+    /// Recieves interactions through an Interactions Endpoint URL instead of connecting to the Discord Gateway.
+    /// Listens for requests on the address, which must be reachable by Discord at the url set in the developer page of the application.
+    /// The public key shown in the developer page is utilised for verifying that requests were sent by Discord.
+    /// Only interactions are recieved, so every other method of the [`EventHandler`]s is never called.
+    #[cfg(feature = "http-interactions")]
+    pub async fn serve_interactions(self, public_key: &str, address: impl Into<std::net::SocketAddr>) -> Result<()> {
+
+        let http_channel_sender = self.start_http_client()?;
+
+        // Register the declared commands before listening, so they exist by the time interactions are recieved.
+        self.sync_commands(&http_channel_sender).await?;
+
+        let server = InteractionServer::new(public_key, http_channel_sender, Arc::new(self.event_handlers))?;
+        server.serve(address).await
+    }
+
+    /// Creates the [`DiscordHttpClient`] to be able to request data from the Discord Api & spawns its request processing channel.
+    fn start_http_client(&self) -> Result<DiscordHttpClientReqSender> {
+
+        let mut http_client = DiscordHttpClient::new(BASE_API_URL, DISCORD_API_VERSION, self.token.clone())?;
+        http_client.retry_policy = self.retry_policy.clone();
 
         // Setup the channel for Requests to the Discord api through the DiscordHttpClient
         let (http_channel_sender, http_channel_reciever) = mpsc::channel(50);
@@ -72,26 +285,65 @@ impl Bot {
             }
         );
 
-        // Cloning is an acceptable operation as Sender contains an arc so this acts as just cloning a pointer to the sender
-        let client_sender = http_channel_sender.clone();
+        Ok(http_channel_sender)
+    }
 
-        // Send a GET request with path gateway/bot/ so we can get information on connecting to the gateway & sharding
-        // Also serves as a way to check if the token is valid or not etc. 
-        let gateway_bot_response : GetGatewayBotResponse = DiscordHttpRequest::new(DiscordHttpReqType::GetGatewayBot, Method::GET).request(client_sender)
-            .await    
-            .context("Failed in retrieving gateway/bot required for starting up discord Gateway connection.")?; 
+    /// Registers the declared global & guild commands with Discord depending on the [`CommandSyncMode`]
+    /// Nothing is requested when no commands are declared, so commands registered by other means are left untouched.
+    async fn sync_commands(&self, http: &DiscordHttpClientReqSender) -> Result<()> {
+
+        if self.commands.is_empty() && self.guild_commands.is_empty() {
+            return Ok(())
+        }
+
+        let application: PartialApplication = DiscordHttpRequest::new(DiscordHttpReqType::GetCurrentApplication, Method::GET).request(http.clone())
+            .await?;
+
+        let global_commands = (!self.commands.is_empty()).then_some((None, &self.commands));
+        let guild_commands = self.guild_commands.iter().map(|(guild_id, commands)| (Some(*guild_id), commands));
+
+        for (guild_id, commands) in global_commands.into_iter().chain(guild_commands) {
+            let scope = guild_id.map_or_else(|| "global".to_string(), |guild_id| format!("guild {guild_id}"));
+
+            match (self.command_sync_mode, guild_id) {
+                (CommandSyncMode::BulkOverwrite, Some(guild_id)) => {
+                    http.bulk_overwrite_guild_commands(application.id, guild_id, commands).await?;
+                },
+                (CommandSyncMode::BulkOverwrite, None) => {
+                    http.bulk_overwrite_global_commands(application.id, commands).await?;
+                },
+                (CommandSyncMode::DryRun, _) => {
+                    let plan = http.sync_commands(application.id, guild_id, commands, true).await?;
+                    tracing::info!("Dry run of syncing {scope} commands: {plan}");
+                },
+                (CommandSyncMode::Diff, _) => {
+                    let plan = http.sync_commands(application.id, guild_id, commands, false).await?;
+                    tracing::debug!("Synced {scope} commands: {plan}");
+                },
+            }
+        }
 
-        // Create a sharded [`DiscordGatewayClient`]
-        let _gateway_client = DiscordGatewayClient::new_with_shards(&self, gateway_bot_response).await
-            .context("Failed to create DiscordGatewayClient")?;
-        
-        tokio::time::sleep(tokio::time::Duration::from_secs(1000)).await;
         Ok(())
-        
     }
 
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// How the [`Intents`] of a [`Bot`] are checked before it connects to the Discord Gateway.
+pub enum IntentValidation {
+
+    /// Nothing is checked.
+    Off,
+
+    /// A warning is logged for each handled event which can never be recieved with the intents.
+    /// Privileged intents which arent enabled for the application still fail, as the shards could never connect.
+    #[default]
+    Warn,
+
+    /// Elevating fails when any handled event can never be recieved with the intents.
+    Deny,
+}
+
 /// Enum for the different options available for sharding when the [`Bot`] is ran.
 /// Only Shard 0 will recieve DMs, 
 pub enum ShardingOption {
@@ -113,8 +365,14 @@ bitflags::bitflags! {
 
         /// This is a privileged [`Intent`]
         const GUILD_MEMBERS = 1 << 1;
-        const GUILD_BANS = 1 << 2;
-        const GUILD_EMOJIS_AND_STICKERS = 1 << 3;
+        const GUILD_MODERATION = 1 << 2;
+
+        /// The former name of GUILD_MODERATION
+        const GUILD_BANS = Self::GUILD_MODERATION.bits;
+        const GUILD_EXPRESSIONS = 1 << 3;
+
+        /// The former name of GUILD_EXPRESSIONS
+        const GUILD_EMOJIS_AND_STICKERS = Self::GUILD_EXPRESSIONS.bits;
         const GUILD_INTEGRATIONS = 1 << 4;
         const GUILD_WEBHOOKS = 1 << 5;
         const GUILD_INVITES = 1 << 6;
@@ -134,7 +392,132 @@ bitflags::bitflags! {
         const GUILD_SCHEDULED_EVENTS = 1 << 16;
         const AUTO_MODERATION_CONFIGURATION = 1 << 20;
         const AUTO_MODERATION_EXECUTION = 1 << 21;
+        const GUILD_MESSAGE_POLLS = 1 << 24;
+        const DIRECT_MESSAGE_POLLS = 1 << 25;
+
+    }
+
+}
+
+impl Intents {
 
+    /// The intents which must be enabled within the developer page of the application before they can be utilised.
+    pub fn privileged() -> Self {
+        Self::GUILD_MEMBERS | Self::GUILD_PRESENCES | Self::MESSAGE_CONTENT
     }
 
-}
\ No newline at end of file
+    /// Every intent which isnt privileged, so can be utilised without being enabled within the developer page.
+    pub fn non_privileged() -> Self {
+        Self::all() - Self::privileged()
+    }
+
+    /// The fewest intents which recieve every one of the events.
+    /// Privileged intents are only included for events which cant be recieved without them, e.g: THREAD_MEMBERS_UPDATE is recieved with GUILDS alone.
+    /// MESSAGE_CONTENT is never included as messages are recieved without it, it only fills in their content.
+    pub fn from_events(events: impl IntoIterator<Item = EventType>) -> Self {
+        events.into_iter().fold(Self::empty(), |intents, event| {
+            let event_intents = event.intents();
+            match (event_intents - Self::privileged()).is_empty() {
+                true => intents | event_intents,
+                false => intents | (event_intents - Self::privileged()),
+            }
+        })
+    }
+
+    /// The privileged intents which are enabled for an application, from the flags of the application.
+    pub fn enabled_privileged(flags: ApplicationFlags) -> Self {
+        let mut intents = Self::empty();
+        intents.set(Self::GUILD_MEMBERS, flags.intersects(ApplicationFlags::GATEWAY_GUILD_MEMBERS | ApplicationFlags::GATEWAY_GUILD_MEMBERS_LIMITED));
+        intents.set(Self::GUILD_PRESENCES, flags.intersects(ApplicationFlags::GATEWAY_PRESENCE | ApplicationFlags::GATEWAY_PRESENCE_LIMITED));
+        intents.set(Self::MESSAGE_CONTENT, flags.intersects(ApplicationFlags::GATEWAY_MESSAGE_CONTENT | ApplicationFlags::GATEWAY_MESSAGE_CONTENT_LIMITED));
+        intents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use crate::error::Error;
+    use crate::event_handler::EventHandler;
+    use crate::gateway::EventType;
+    use crate::models::application::ApplicationFlags;
+    use super::{Bot, IntentValidation, Intents};
+
+    /// A handler which declares the events it handles without implementing any of them.
+    struct Subscribed(Vec<EventType>);
+
+    #[async_trait]
+    impl EventHandler for Subscribed {
+        fn subscribed_events(&self) -> Vec<EventType> {
+            self.0.clone()
+        }
+    }
+
+    fn bot(intents: Intents, intent_validation: IntentValidation, events: Vec<EventType>) -> Bot {
+        let mut bot = Bot::new("token".to_string());
+        bot.intents = intents;
+        bot.set_intent_validation(intent_validation);
+        bot.add_event_handler(Subscribed(events));
+        bot
+    }
+
+    #[test]
+    fn unreachable_handled_events_only_fail_when_denied() {
+        let events = vec![EventType::MessageCreate, EventType::GuildMemberAdd, EventType::Ready];
+        assert_eq!(bot(Intents::GUILDS, IntentValidation::Deny, events.clone()).unreachable_events(), [EventType::MessageCreate, EventType::GuildMemberAdd]);
+
+        assert!(matches!(bot(Intents::GUILDS, IntentValidation::Deny, events.clone()).validate_handled_events(), Err(Error::Configuration(_))));
+        assert!(bot(Intents::GUILDS, IntentValidation::Warn, events.clone()).validate_handled_events().is_ok());
+        assert!(bot(Intents::GUILDS, IntentValidation::Off, events.clone()).validate_handled_events().is_ok());
+
+        // Direct messages are enough for MessageCreate to be recieved.
+        let intents = Intents::DIRECT_MESSAGES | Intents::GUILD_MEMBERS;
+        assert!(bot(intents, IntentValidation::Deny, events).validate_handled_events().is_ok());
+    }
+
+    #[test]
+    fn undeclared_events_arent_validated() {
+        assert!(bot(Intents::empty(), IntentValidation::Deny, Vec::new()).validate_handled_events().is_ok());
+    }
+
+    #[test]
+    fn privileged_intents_must_be_enabled_for_the_application() {
+        let bot = bot(Intents::GUILD_MEMBERS | Intents::MESSAGE_CONTENT, IntentValidation::Deny, Vec::new());
+
+        match bot.validate_enabled_privileged_intents(ApplicationFlags::GATEWAY_GUILD_MEMBERS) {
+            Err(Error::Configuration(message)) => assert!(message.contains("MESSAGE_CONTENT") && !message.contains("GUILD_MEMBERS"), "{message}"),
+            result => panic!("expected a configuration error, got {result:?}"),
+        }
+        assert!(bot.validate_enabled_privileged_intents(ApplicationFlags::GATEWAY_GUILD_MEMBERS | ApplicationFlags::GATEWAY_MESSAGE_CONTENT_LIMITED).is_ok());
+    }
+
+    #[test]
+    fn limited_application_flags_enable_privileged_intents() {
+        assert_eq!(Intents::enabled_privileged(ApplicationFlags::empty()), Intents::empty());
+        assert_eq!(Intents::enabled_privileged(ApplicationFlags::GATEWAY_PRESENCE_LIMITED), Intents::GUILD_PRESENCES);
+        assert_eq!(Intents::enabled_privileged(ApplicationFlags::GATEWAY_GUILD_MEMBERS_LIMITED), Intents::GUILD_MEMBERS);
+        assert_eq!(Intents::enabled_privileged(ApplicationFlags::GATEWAY_MESSAGE_CONTENT_LIMITED), Intents::MESSAGE_CONTENT);
+
+        let limited = ApplicationFlags::GATEWAY_PRESENCE_LIMITED | ApplicationFlags::GATEWAY_GUILD_MEMBERS_LIMITED | ApplicationFlags::GATEWAY_MESSAGE_CONTENT_LIMITED;
+        assert_eq!(Intents::enabled_privileged(limited), Intents::privileged());
+        assert_eq!(Intents::enabled_privileged(ApplicationFlags::GATEWAY_PRESENCE | ApplicationFlags::GATEWAY_GUILD_MEMBERS | ApplicationFlags::GATEWAY_MESSAGE_CONTENT), Intents::privileged());
+    }
+
+    #[test]
+    fn events_require_the_fewest_intents() {
+        assert_eq!(Intents::from_events([EventType::MessageCreate]), Intents::GUILD_MESSAGES | Intents::DIRECT_MESSAGES);
+        assert_eq!(Intents::from_events([EventType::GuildMemberAdd]), Intents::GUILD_MEMBERS);
+        assert_eq!(Intents::from_events([EventType::ThreadMembersUpdate]), Intents::GUILDS);
+        assert_eq!(Intents::from_events([EventType::Ready, EventType::Resumed]), Intents::empty());
+        assert_eq!(Intents::from_events([EventType::MessageCreate, EventType::GuildMemberAdd]), Intents::GUILD_MESSAGES | Intents::DIRECT_MESSAGES | Intents::GUILD_MEMBERS);
+    }
+
+    #[test]
+    fn privileged_intents_partition_every_intent() {
+        assert_eq!(Intents::non_privileged() | Intents::privileged(), Intents::all());
+        assert!(!Intents::non_privileged().intersects(Intents::privileged()));
+        assert_eq!(Intents::GUILD_MESSAGE_POLLS.bits(), 1 << 24);
+        assert_eq!(Intents::DIRECT_MESSAGE_POLLS.bits(), 1 << 25);
+    }
+}
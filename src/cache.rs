@@ -0,0 +1,632 @@
+//! An opt-in cache of the guilds, channels, members, roles & messages recieved through Discords gateway.
+//! Kept up to date by the shards before an event is passed to the [`EventHandler`][crate::event_handler::EventHandler]s, so handlers always see the state after the event.
+//! The state is stored by a [`CacheBackend`], either the [`InMemoryCache`] or a store shared between processes such as the `RedisCache` of the redis-cache feature.
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use async_trait::async_trait;
+use bitflags;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::{Error, Result};
+use crate::gateway::DispatchEvent;
+use crate::gateway_structs::{GuildCreate, GuildMemberUpdate};
+use crate::models::channel::Channel;
+use crate::models::guild::{Guild, Member, Role};
+use crate::models::message::Message;
+use crate::models::permissions::{self, Permissions};
+use crate::models::user::User;
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker};
+
+#[cfg(feature = "redis-cache")]
+pub mod redis;
+
+/// The amount of messages stored per channel by default.
+pub const DEFAULT_MESSAGE_CAPACITY: usize = 100;
+
+/// The version of the format cached models are encoded in by [`encode_model`], stored as the first byte of every encoded model.
+/// Incremented whenever the format changes, so models encoded by another version of the library are never misread.
+pub const CACHE_FORMAT_VERSION: u8 = 1;
+
+bitflags::bitflags! {
+
+    /// Bitflags Struct which represents the resources a [`CacheBackend`] stores, resources which arent stored are never retrieved from it.
+    pub struct CacheResources: u32 {
+
+        /// Guilds, without their roles which are stored separately
+        const GUILDS = 1 << 0;
+
+        /// Guild channels & threads
+        const CHANNELS = 1 << 1;
+
+        /// Guild members, only those sent by Discord are stored which requires the GUILD_MEMBERS [`Intent`][crate::bot::Intents] for most members
+        const MEMBERS = 1 << 2;
+
+        /// Guild roles
+        const ROLES = 1 << 3;
+
+        /// The most recent messages of each channel, up to [`CacheConfig::message_capacity`]
+        const MESSAGES = 1 << 4;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Determines what a [`CacheBackend`] stores, trading memory for requests to the Discord api.
+pub struct CacheConfig {
+
+    /// The resources which are stored, all by default
+    pub resources: CacheResources,
+
+    /// The most messages stored per channel, the oldest message is removed once a channel exceeds it
+    pub message_capacity: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            resources: CacheResources::all(),
+            message_capacity: DEFAULT_MESSAGE_CAPACITY,
+        }
+    }
+}
+
+impl CacheConfig {
+
+    /// Creates a config storing every resource & up to 100 messages per channel.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the resources which are stored.
+    pub fn resources(mut self, resources: CacheResources) -> Self {
+        self.resources = resources;
+        self
+    }
+
+    /// Sets the most messages stored per channel, 0 stores no messages.
+    pub fn message_capacity(mut self, message_capacity: usize) -> Self {
+        self.message_capacity = message_capacity;
+        self
+    }
+
+    /// Whether the resource is stored.
+    pub fn wants(&self, resources: CacheResources) -> bool {
+        self.resources.contains(resources)
+    }
+
+    /// Whether messages are stored, which also requires a message capacity above 0.
+    pub fn wants_messages(&self) -> bool {
+        self.wants(CacheResources::MESSAGES) && self.message_capacity > 0
+    }
+}
+
+#[async_trait]
+/// Trait which is implemented by the stores a cache can be kept within, set through [`Bot::set_cache`][crate::bot::Bot::set_cache]
+/// Every dispatched event is passed to [`CacheBackend::update`] before the [`EventHandler`][crate::event_handler::EventHandler]s are called.
+/// A backend shared between processes recieves the events of every shard which uses it, so each process only needs to run some of the shards.
+pub trait CacheBackend: Send + Sync + 'static {
+
+    /// The config determining what the backend stores.
+    fn config(&self) -> &CacheConfig;
+
+    /// Updates the backend with the state contained in the event, events which contain no cached state are ignored.
+    async fn update(&self, event: &DispatchEvent) -> Result<()>;
+
+    /// The user of the bot, stored from the Ready event.
+    async fn current_user(&self) -> Result<Option<User>>;
+
+    /// Retrieves a guild, its roles are retrieved through [`CacheBackend::guild_roles`]
+    async fn guild(&self, guild_id: Id<GuildMarker>) -> Result<Option<Guild>>;
+
+    /// The ids of every stored guild.
+    async fn guild_ids(&self) -> Result<Vec<Id<GuildMarker>>>;
+
+    /// Retrieves a channel or thread.
+    async fn channel(&self, channel_id: Id<ChannelMarker>) -> Result<Option<Channel>>;
+
+    /// Retrieves the channels & threads within a guild.
+    async fn guild_channels(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Channel>>;
+
+    /// Retrieves a member of a guild.
+    async fn member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> Result<Option<Member>>;
+
+    /// Retrieves the stored members of a guild.
+    async fn guild_members(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Member>>;
+
+    /// Retrieves a role of a guild.
+    async fn role(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> Result<Option<Role>>;
+
+    /// Retrieves the roles of a guild, including the @everyone role which shares the id of the guild.
+    async fn guild_roles(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Role>>;
+
+    /// Retrieves a message, only the most recent messages of each channel are stored.
+    async fn message(&self, channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>) -> Result<Option<Message>>;
+
+    /// Retrieves the stored messages of a channel, from oldest to newest.
+    async fn messages(&self, channel_id: Id<ChannelMarker>) -> Result<Vec<Message>>;
+
+    /// Calculates the permissions of a member within a guild, or within a channel of it when a channel is passed, from the stored guild, roles, member & channel.
+    /// The overwrites of the parent channel are utilised for threads. None if the guild, member or channel isnt stored.
+    async fn member_permissions(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>, channel_id: Option<Id<ChannelMarker>>) -> Result<Option<Permissions>> {
+
+        let (Some(guild), Some(member)) = (self.guild(guild_id).await?, self.member(guild_id, user_id).await?) else {
+            return Ok(None)
+        };
+        let roles = self.guild_roles(guild_id).await?;
+        let timed_out = member.is_timed_out();
+
+        let Some(channel_id) = channel_id else {
+            return Ok(Some(permissions::guild_permissions(&guild, &roles, user_id, &member.roles, timed_out)))
+        };
+
+        let channel = match self.channel(channel_id).await? {
+            Some(channel) if channel.kind.is_thread() => match channel.parent_id {
+                Some(parent_id) => self.channel(parent_id).await?,
+                None => None,
+            },
+            channel => channel,
+        };
+
+        Ok(channel.map(|channel| permissions::channel_permissions(&guild, &roles, user_id, &member.roles, timed_out, &channel)))
+    }
+}
+
+/// Encodes a cached model for storing outside of the process, as the [`CACHE_FORMAT_VERSION`] followed by the model as JSON.
+/// JSON is utilised as the models already (de)serialize to the payloads Discord sends, so no field is lost.
+pub fn encode_model<T: Serialize>(model: &T) -> Result<Vec<u8>> {
+    let mut bytes = vec![CACHE_FORMAT_VERSION];
+    serde_json::to_writer(&mut bytes, model)?;
+    Ok(bytes)
+}
+
+/// Decodes a cached model encoded by [`encode_model`], failing if it was encoded in another version of the format.
+pub fn decode_model<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    match bytes.split_first() {
+        Some((&CACHE_FORMAT_VERSION, json)) => Ok(serde_json::from_slice(json)?),
+        Some((version, _)) => Err(Error::CacheFormat(format!("encoded in version {version}, expected version {CACHE_FORMAT_VERSION}"))),
+        None => Err(Error::CacheFormat("the encoded model is empty".to_string())),
+    }
+}
+
+/// Applies a GuildMemberUpdate to a stored member, creating the member if it wasnt stored yet.
+pub(crate) fn apply_member_update(member: Option<Member>, event: &GuildMemberUpdate) -> Member {
+    match member {
+        Some(mut member) => {
+            member.user = Some(event.user.clone());
+            member.roles = event.roles.clone();
+            member.nick = event.nick.clone();
+            member.avatar = event.avatar.clone();
+            member.banner = event.banner.clone();
+            member.joined_at = event.joined_at.clone().or_else(|| member.joined_at.clone());
+            member.premium_since = event.premium_since.clone();
+            member.deaf = event.deaf.unwrap_or(member.deaf);
+            member.mute = event.mute.unwrap_or(member.mute);
+            member.pending = event.pending.or(member.pending);
+            member.communication_disabled_until = event.communication_disabled_until.clone();
+            member.flags = event.flags.unwrap_or(member.flags);
+            member.avatar_decoration_data = event.avatar_decoration_data.clone();
+            member
+        },
+        None => Member {
+            user: Some(event.user.clone()),
+            nick: event.nick.clone(),
+            avatar: event.avatar.clone(),
+            banner: event.banner.clone(),
+            roles: event.roles.clone(),
+            joined_at: event.joined_at.clone(),
+            premium_since: event.premium_since.clone(),
+            deaf: event.deaf.unwrap_or_default(),
+            mute: event.mute.unwrap_or_default(),
+            flags: event.flags.unwrap_or_default(),
+            pending: event.pending,
+            permissions: None,
+            communication_disabled_until: event.communication_disabled_until.clone(),
+            avatar_decoration_data: event.avatar_decoration_data.clone(),
+        },
+    }
+}
+
+/// Shortened Alias for resources stored separately for each guild.
+type PerGuild<K, V> = HashMap<Id<GuildMarker>, HashMap<K, V>>;
+
+#[derive(Debug, Default)]
+/// A cache of the state recieved through Discords gateway stored within the process, enabled through [`Bot::enable_cache`][crate::bot::Bot::enable_cache]
+/// Every resource is behind its own lock so handlers can read concurrently, retrieved resources are cloned out of the cache.
+pub struct InMemoryCache {
+    config: CacheConfig,
+    current_user: RwLock<Option<User>>,
+    guilds: RwLock<HashMap<Id<GuildMarker>, Guild>>,
+    channels: RwLock<HashMap<Id<ChannelMarker>, Channel>>,
+    guild_channels: RwLock<HashMap<Id<GuildMarker>, HashSet<Id<ChannelMarker>>>>,
+    members: RwLock<PerGuild<Id<UserMarker>, Member>>,
+    roles: RwLock<PerGuild<Id<RoleMarker>, Role>>,
+    messages: RwLock<HashMap<Id<ChannelMarker>, VecDeque<Message>>>,
+}
+
+impl InMemoryCache {
+
+    /// Creates an empty cache storing the resources of the config.
+    pub fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// The config determining what the cache stores.
+    pub fn config(&self) -> &CacheConfig {
+        &self.config
+    }
+
+    /// Whether the resource is stored by the cache.
+    fn wants(&self, resources: CacheResources) -> bool {
+        self.config.wants(resources)
+    }
+
+    /// Updates the cache with the state contained in the event, events which contain no cached state are ignored.
+    pub fn update(&self, event: &DispatchEvent) {
+        match event {
+            DispatchEvent::Ready(ready) => *write(&self.current_user) = Some(ready.user.clone()),
+            DispatchEvent::UserUpdate(user) => *write(&self.current_user) = Some(user.clone()),
+            DispatchEvent::GuildCreate(GuildCreate::Available(available)) => {
+                let guild_id = available.guild.id;
+
+                self.insert_guild(available.guild.clone());
+
+                for channel in available.channels.iter().chain(&available.threads) {
+                    // Channels within GuildCreate are sent without the id of their guild.
+                    let mut channel = channel.clone();
+                    channel.guild_id = Some(guild_id);
+                    self.insert_channel(channel);
+                }
+
+                if self.wants(CacheResources::MEMBERS) {
+                    let mut members = write(&self.members);
+                    let guild_members = members.entry(guild_id).or_default();
+                    for member in &available.members {
+                        if let Some(user) = &member.user {
+                            guild_members.insert(user.id, member.clone());
+                        }
+                    }
+                }
+            },
+            DispatchEvent::GuildUpdate(guild) => self.insert_guild(guild.as_ref().clone()),
+            // Unavailable guilds are only unavailable due to an outage so their state is kept.
+            DispatchEvent::GuildDelete(guild) if !guild.unavailable => self.remove_guild(guild.id),
+            DispatchEvent::ChannelCreate(channel)
+            | DispatchEvent::ChannelUpdate(channel)
+            | DispatchEvent::ThreadCreate(channel)
+            | DispatchEvent::ThreadUpdate(channel) => self.insert_channel(channel.clone()),
+            DispatchEvent::ChannelDelete(channel) | DispatchEvent::ThreadDelete(channel) => self.remove_channel(channel.id, channel.guild_id),
+            DispatchEvent::GuildMemberAdd(event) => {
+                if let (true, Some(user)) = (self.wants(CacheResources::MEMBERS), &event.member.user) {
+                    write(&self.members).entry(event.guild_id).or_default().insert(user.id, event.member.clone());
+                }
+            },
+            DispatchEvent::GuildMemberUpdate(event) => self.update_member(event),
+            DispatchEvent::GuildMemberRemove(event) => {
+                if let Some(members) = write(&self.members).get_mut(&event.guild_id) {
+                    members.remove(&event.user.id);
+                }
+            },
+            DispatchEvent::GuildRoleCreate(event) | DispatchEvent::GuildRoleUpdate(event) if self.wants(CacheResources::ROLES) => {
+                write(&self.roles).entry(event.guild_id).or_default().insert(event.role.id, event.role.clone());
+            },
+            DispatchEvent::GuildRoleDelete(event) => {
+                if let Some(roles) = write(&self.roles).get_mut(&event.guild_id) {
+                    roles.remove(&event.role_id);
+                }
+            },
+            DispatchEvent::MessageCreate(message) if self.config.wants_messages() => {
+                let mut messages = write(&self.messages);
+                let channel_messages = messages.entry(message.channel_id).or_default();
+
+                channel_messages.push_back(message.as_ref().clone());
+                while channel_messages.len() > self.config.message_capacity {
+                    channel_messages.pop_front();
+                }
+            },
+            DispatchEvent::MessageUpdate(message) => {
+                if let Some(existing) = write(&self.messages).get_mut(&message.channel_id).and_then(|messages| messages.iter_mut().find(|existing| existing.id == message.id)) {
+                    *existing = message.as_ref().clone();
+                }
+            },
+            DispatchEvent::MessageDelete(event) => {
+                if let Some(messages) = write(&self.messages).get_mut(&event.channel_id) {
+                    messages.retain(|message| message.id != event.id);
+                }
+            },
+            DispatchEvent::MessageDeleteBulk(event) => {
+                if let Some(messages) = write(&self.messages).get_mut(&event.channel_id) {
+                    messages.retain(|message| !event.ids.contains(&message.id));
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// Stores a guild, moving its roles into the role cache.
+    fn insert_guild(&self, mut guild: Guild) {
+
+        let roles = std::mem::take(&mut guild.roles);
+        if self.wants(CacheResources::ROLES) {
+            write(&self.roles).insert(guild.id, roles.into_iter().map(|role| (role.id, role)).collect());
+        }
+
+        if self.wants(CacheResources::GUILDS) {
+            write(&self.guilds).insert(guild.id, guild);
+        }
+    }
+
+    /// Removes a guild & everything stored within it.
+    fn remove_guild(&self, guild_id: Id<GuildMarker>) {
+
+        write(&self.guilds).remove(&guild_id);
+        write(&self.members).remove(&guild_id);
+        write(&self.roles).remove(&guild_id);
+
+        let channel_ids = write(&self.guild_channels).remove(&guild_id).unwrap_or_default();
+        let mut channels = write(&self.channels);
+        let mut messages = write(&self.messages);
+        for channel_id in channel_ids {
+            channels.remove(&channel_id);
+            messages.remove(&channel_id);
+        }
+    }
+
+    /// Stores a channel, tracking it under its guild so it is removed alongside the guild.
+    fn insert_channel(&self, channel: Channel) {
+
+        if !self.wants(CacheResources::CHANNELS) {
+            return
+        }
+
+        if let Some(guild_id) = channel.guild_id {
+            write(&self.guild_channels).entry(guild_id).or_default().insert(channel.id);
+        }
+        write(&self.channels).insert(channel.id, channel);
+    }
+
+    /// Removes a channel & its messages.
+    fn remove_channel(&self, channel_id: Id<ChannelMarker>, guild_id: Option<Id<GuildMarker>>) {
+
+        if let Some(guild_id) = guild_id {
+            if let Some(channel_ids) = write(&self.guild_channels).get_mut(&guild_id) {
+                channel_ids.remove(&channel_id);
+            }
+        }
+        write(&self.channels).remove(&channel_id);
+        write(&self.messages).remove(&channel_id);
+    }
+
+    /// Applies a GuildMemberUpdate to the stored member, storing it if it wasnt yet.
+    fn update_member(&self, event: &GuildMemberUpdate) {
+
+        if !self.wants(CacheResources::MEMBERS) {
+            return
+        }
+
+        let mut members = write(&self.members);
+        let guild_members = members.entry(event.guild_id).or_default();
+
+        let member = apply_member_update(guild_members.remove(&event.user.id), event);
+        guild_members.insert(event.user.id, member);
+    }
+
+    /// The user of the bot, stored from the Ready event.
+    pub fn current_user(&self) -> Option<User> {
+        read(&self.current_user).clone()
+    }
+
+    /// Retrieves a guild, its roles are retrieved through [`InMemoryCache::guild_roles`]
+    pub fn guild(&self, guild_id: Id<GuildMarker>) -> Option<Guild> {
+        read(&self.guilds).get(&guild_id).cloned()
+    }
+
+    /// The ids of every stored guild.
+    pub fn guild_ids(&self) -> Vec<Id<GuildMarker>> {
+        read(&self.guilds).keys().copied().collect()
+    }
+
+    /// Retrieves a channel or thread.
+    pub fn channel(&self, channel_id: Id<ChannelMarker>) -> Option<Channel> {
+        read(&self.channels).get(&channel_id).cloned()
+    }
+
+    /// Retrieves the channels & threads within a guild.
+    pub fn guild_channels(&self, guild_id: Id<GuildMarker>) -> Vec<Channel> {
+        let channels = read(&self.channels);
+        read(&self.guild_channels).get(&guild_id)
+            .map(|channel_ids| channel_ids.iter().filter_map(|channel_id| channels.get(channel_id).cloned()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Retrieves a member of a guild.
+    pub fn member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> Option<Member> {
+        read(&self.members).get(&guild_id).and_then(|members| members.get(&user_id)).cloned()
+    }
+
+    /// Retrieves the stored members of a guild.
+    pub fn guild_members(&self, guild_id: Id<GuildMarker>) -> Vec<Member> {
+        read(&self.members).get(&guild_id).map(|members| members.values().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Retrieves a role of a guild.
+    pub fn role(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> Option<Role> {
+        read(&self.roles).get(&guild_id).and_then(|roles| roles.get(&role_id)).cloned()
+    }
+
+    /// Retrieves the roles of a guild, including the @everyone role which shares the id of the guild.
+    pub fn guild_roles(&self, guild_id: Id<GuildMarker>) -> Vec<Role> {
+        read(&self.roles).get(&guild_id).map(|roles| roles.values().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Retrieves a message, only the most recent messages of each channel are stored.
+    pub fn message(&self, channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>) -> Option<Message> {
+        read(&self.messages).get(&channel_id).and_then(|messages| messages.iter().find(|message| message.id == message_id)).cloned()
+    }
+
+    /// Retrieves the stored messages of a channel, from oldest to newest.
+    pub fn messages(&self, channel_id: Id<ChannelMarker>) -> Vec<Message> {
+        read(&self.messages).get(&channel_id).map(|messages| messages.iter().cloned().collect()).unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl CacheBackend for InMemoryCache {
+
+    fn config(&self) -> &CacheConfig {
+        &self.config
+    }
+
+    async fn update(&self, event: &DispatchEvent) -> Result<()> {
+        InMemoryCache::update(self, event);
+        Ok(())
+    }
+
+    async fn current_user(&self) -> Result<Option<User>> {
+        Ok(InMemoryCache::current_user(self))
+    }
+
+    async fn guild(&self, guild_id: Id<GuildMarker>) -> Result<Option<Guild>> {
+        Ok(InMemoryCache::guild(self, guild_id))
+    }
+
+    async fn guild_ids(&self) -> Result<Vec<Id<GuildMarker>>> {
+        Ok(InMemoryCache::guild_ids(self))
+    }
+
+    async fn channel(&self, channel_id: Id<ChannelMarker>) -> Result<Option<Channel>> {
+        Ok(InMemoryCache::channel(self, channel_id))
+    }
+
+    async fn guild_channels(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Channel>> {
+        Ok(InMemoryCache::guild_channels(self, guild_id))
+    }
+
+    async fn member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> Result<Option<Member>> {
+        Ok(InMemoryCache::member(self, guild_id, user_id))
+    }
+
+    async fn guild_members(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Member>> {
+        Ok(InMemoryCache::guild_members(self, guild_id))
+    }
+
+    async fn role(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> Result<Option<Role>> {
+        Ok(InMemoryCache::role(self, guild_id, role_id))
+    }
+
+    async fn guild_roles(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Role>> {
+        Ok(InMemoryCache::guild_roles(self, guild_id))
+    }
+
+    async fn message(&self, channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>) -> Result<Option<Message>> {
+        Ok(InMemoryCache::message(self, channel_id, message_id))
+    }
+
+    async fn messages(&self, channel_id: Id<ChannelMarker>) -> Result<Vec<Message>> {
+        Ok(InMemoryCache::messages(self, channel_id))
+    }
+}
+
+/// Acquires a read lock, a panic within another holder of the lock doesnt leave the cache in an invalid state so poisoning is ignored.
+fn read<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Acquires a write lock, ignoring poisoning.
+fn write<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::fixtures;
+    use crate::gateway::DispatchEvent;
+    use crate::models::guild::Member;
+    use crate::snowflake::Id;
+    use super::{CACHE_FORMAT_VERSION, CacheConfig, InMemoryCache, decode_model, encode_model};
+
+    fn event(name: &str, data: serde_json::Value) -> DispatchEvent {
+        DispatchEvent::from_name_and_data(name.to_string(), data)
+    }
+
+    #[test]
+    fn models_round_trip_through_the_cache_format() {
+        let member: Member = serde_json::from_value(fixtures::member(30, &[40])).unwrap();
+        let bytes = encode_model(&member).unwrap();
+
+        assert_eq!(bytes[0], CACHE_FORMAT_VERSION);
+        assert_eq!(decode_model::<Member>(&bytes).unwrap(), member);
+    }
+
+    #[test]
+    fn other_cache_format_versions_are_rejected() {
+        let mut bytes = encode_model(&serde_json::from_value::<Member>(fixtures::member(30, &[])).unwrap()).unwrap();
+        bytes[0] = CACHE_FORMAT_VERSION + 1;
+
+        assert!(matches!(decode_model::<Member>(&bytes), Err(Error::CacheFormat(_))));
+        assert!(matches!(decode_model::<Member>(&[]), Err(Error::CacheFormat(_))));
+    }
+
+    #[test]
+    fn guild_create_stores_the_guild_channels_and_members() {
+        let cache = InMemoryCache::new(CacheConfig::new());
+        let data = fixtures::guild_create(10, vec![fixtures::channel(20, 10), fixtures::channel(21, 10)], vec![fixtures::member(30, &[])]);
+        cache.update(&event("GUILD_CREATE", data));
+
+        assert!(cache.guild(Id::new(10)).is_some());
+        assert_eq!(cache.guild_ids(), [Id::new(10)]);
+        assert_eq!(cache.guild_channels(Id::new(10)).len(), 2);
+        assert_eq!(cache.channel(Id::new(20)).and_then(|channel| channel.guild_id), Some(Id::new(10)));
+        assert!(cache.member(Id::new(10), Id::new(30)).is_some());
+    }
+
+    #[test]
+    fn guild_delete_removes_everything_within_the_guild() {
+        let cache = InMemoryCache::new(CacheConfig::new());
+        cache.update(&event("GUILD_CREATE", fixtures::guild_create(10, vec![fixtures::channel(20, 10)], vec![fixtures::member(30, &[])])));
+        cache.update(&event("MESSAGE_CREATE", fixtures::message(40, 20)));
+
+        // Guilds which become unavailable due to an outage are kept.
+        cache.update(&event("GUILD_DELETE", serde_json::json!({ "id": "10", "unavailable": true })));
+        assert!(cache.guild(Id::new(10)).is_some());
+
+        cache.update(&event("GUILD_DELETE", serde_json::json!({ "id": "10" })));
+        assert!(cache.guild(Id::new(10)).is_none());
+        assert!(cache.channel(Id::new(20)).is_none());
+        assert!(cache.member(Id::new(10), Id::new(30)).is_none());
+        assert!(cache.messages(Id::new(20)).is_empty());
+    }
+
+    #[test]
+    fn member_update_applies_to_the_stored_member() {
+        let cache = InMemoryCache::new(CacheConfig::new());
+        cache.update(&event("GUILD_CREATE", fixtures::guild_create(10, Vec::new(), vec![fixtures::member(30, &[])])));
+
+        let mut data = fixtures::member(30, &[50]);
+        data["guild_id"] = serde_json::json!("10");
+        data["nick"] = serde_json::json!("nick");
+        cache.update(&event("GUILD_MEMBER_UPDATE", data));
+
+        let member = cache.member(Id::new(10), Id::new(30)).unwrap();
+        assert_eq!(member.roles, [Id::new(50)]);
+        assert_eq!(member.nick.as_deref(), Some("nick"));
+        assert!(member.joined_at.is_some());
+    }
+
+    #[test]
+    fn messages_are_trimmed_to_the_capacity() {
+        let cache = InMemoryCache::new(CacheConfig::new().message_capacity(2));
+        for message_id in 1..=3 {
+            cache.update(&event("MESSAGE_CREATE", fixtures::message(message_id, 20)));
+        }
+
+        let ids: Vec<u64> = cache.messages(Id::new(20)).iter().map(|message| message.id.get()).collect();
+        assert_eq!(ids, [2, 3]);
+        assert!(cache.message(Id::new(20), Id::new(1)).is_none());
+    }
+}
@@ -0,0 +1,420 @@
+//! A [`CacheBackend`] storing the cache within a Redis-protocol server, so it can be shared between processes running different shards.
+//! Models are stored in the format of [`encode_model`], under keys starting with a prefix so several bots are able to share a server.
+use ::redis::aio::ConnectionManager;
+use ::redis::{AsyncCommands, Client, Pipeline, pipe};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+
+use crate::cache::{CacheBackend, CacheConfig, CacheResources, apply_member_update, decode_model, encode_model};
+use crate::error::Result;
+use crate::gateway::DispatchEvent;
+use crate::gateway_structs::{AvailableGuild, GuildCreate, GuildMemberUpdate};
+use crate::models::channel::Channel;
+use crate::models::guild::{Guild, Member, Role};
+use crate::models::message::Message;
+use crate::models::user::User;
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker};
+
+/// The prefix of every key stored by a [`RedisCache`] by default.
+pub const DEFAULT_KEY_PREFIX: &str = "tonsoe";
+
+#[derive(Clone)]
+/// A cache of the state recieved through Discords gateway stored within a Redis-protocol server, set through [`Bot::set_cache`][crate::bot::Bot::set_cache]
+/// The connection is re-established automatically when it drops, requests made while it is down fail with [`Error::Redis`][crate::error::Error::Redis]
+///
+/// Keys stored, where the prefix is [`DEFAULT_KEY_PREFIX`] by default:
+/// - `{prefix}:current_user` the user of the bot
+/// - `{prefix}:guilds` & `{prefix}:channels` hashes of every guild & channel by id
+/// - `{prefix}:guild_channels:{guild_id}` a set of the ids of the channels within a guild
+/// - `{prefix}:members:{guild_id}` & `{prefix}:roles:{guild_id}` hashes of the members & roles of a guild by id
+/// - `{prefix}:messages:{channel_id}` a hash of the messages of a channel by id, alongside `{prefix}:message_order:{channel_id}` a list of their ids from oldest to newest
+pub struct RedisCache {
+    config: CacheConfig,
+    connection: ConnectionManager,
+    prefix: String,
+}
+
+impl RedisCache {
+
+    /// Connects to the server at the url, e.g: "redis://127.0.0.1:6379"
+    pub async fn connect(url: &str, config: CacheConfig) -> Result<Self> {
+        let connection = Client::open(url)?.get_connection_manager().await?;
+        Ok(Self::with_connection(connection, config))
+    }
+
+    /// Creates a cache utilising an existing connection to a server.
+    pub fn with_connection(connection: ConnectionManager, config: CacheConfig) -> Self {
+        Self {
+            config,
+            connection,
+            prefix: DEFAULT_KEY_PREFIX.to_string(),
+        }
+    }
+
+    /// Sets the prefix of every key stored, so several bots are able to share a server.
+    pub fn key_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// The prefix of every key stored.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Creates a key, e.g: "tonsoe:members:81384788765712384"
+    fn key(&self, name: &str, id: Option<u64>) -> String {
+        match id {
+            Some(id) => format!("{}:{name}:{id}", self.prefix),
+            None => format!("{}:{name}", self.prefix),
+        }
+    }
+
+    /// Clones the connection, which only clones a handle to the same connection.
+    fn connection(&self) -> ConnectionManager {
+        self.connection.clone()
+    }
+
+    /// Retrieves & decodes a model stored within a hash.
+    async fn hash_model<T: DeserializeOwned>(&self, key: String, id: u64) -> Result<Option<T>> {
+        let bytes: Option<Vec<u8>> = self.connection().hget(key, id).await?;
+        bytes.map(|bytes| decode_model(&bytes)).transpose()
+    }
+
+    /// Retrieves & decodes every model stored within a hash.
+    async fn hash_models<T: DeserializeOwned>(&self, key: String) -> Result<Vec<T>> {
+        let models: Vec<Vec<u8>> = self.connection().hvals(key).await?;
+        models.iter().map(|bytes| decode_model(bytes)).collect()
+    }
+
+    /// Retrieves & decodes the models of the ids stored within a hash, skipping ids which arent stored.
+    async fn hash_models_by_id<T: DeserializeOwned>(&self, key: String, ids: &[u64]) -> Result<Vec<T>> {
+
+        if ids.is_empty() {
+            return Ok(Vec::new())
+        }
+
+        let models: Vec<Option<Vec<u8>>> = ::redis::cmd("HMGET").arg(key).arg(ids).query_async(&mut self.connection()).await?;
+        models.iter().flatten().map(|bytes| decode_model(bytes)).collect()
+    }
+
+    /// Queues storing a guild onto a pipeline, moving its roles into the role cache.
+    fn queue_guild(&self, pipeline: &mut Pipeline, mut guild: Guild) -> Result<()> {
+
+        let roles = std::mem::take(&mut guild.roles);
+        if self.config.wants(CacheResources::ROLES) {
+            let roles_key = self.key("roles", Some(guild.id.get()));
+            pipeline.del(&roles_key).ignore();
+
+            let roles = roles.iter().map(|role| Ok((role.id.get(), encode_model(role)?))).collect::<Result<Vec<_>>>()?;
+            if !roles.is_empty() {
+                pipeline.hset_multiple(&roles_key, &roles).ignore();
+            }
+        }
+
+        if self.config.wants(CacheResources::GUILDS) {
+            pipeline.hset(self.key("guilds", None), guild.id.get(), encode_model(&guild)?).ignore();
+        }
+        Ok(())
+    }
+
+    /// Queues storing a channel onto a pipeline, tracking it under its guild so it is removed alongside the guild.
+    fn queue_channel(&self, pipeline: &mut Pipeline, channel: &Channel) -> Result<()> {
+
+        if !self.config.wants(CacheResources::CHANNELS) {
+            return Ok(())
+        }
+
+        pipeline.hset(self.key("channels", None), channel.id.get(), encode_model(channel)?).ignore();
+        if let Some(guild_id) = channel.guild_id {
+            pipeline.sadd(self.key("guild_channels", Some(guild_id.get())), channel.id.get()).ignore();
+        }
+        Ok(())
+    }
+
+    /// Queues storing members of a guild onto a pipeline, members sent without their user are skipped as they cant be identified.
+    fn queue_members<'a>(&self, pipeline: &mut Pipeline, guild_id: Id<GuildMarker>, members: impl Iterator<Item = &'a Member>) -> Result<()> {
+
+        if !self.config.wants(CacheResources::MEMBERS) {
+            return Ok(())
+        }
+
+        let members = members
+            .filter_map(|member| member.user.as_ref().map(|user| Ok((user.id.get(), encode_model(member)?))))
+            .collect::<Result<Vec<_>>>()?;
+
+        if !members.is_empty() {
+            pipeline.hset_multiple(self.key("members", Some(guild_id.get())), &members).ignore();
+        }
+        Ok(())
+    }
+
+    /// Runs a pipeline built by one of the queue methods as a single transaction.
+    async fn run(&self, build: impl FnOnce(&mut Pipeline) -> Result<()>) -> Result<()> {
+        let mut pipeline = pipe();
+        pipeline.atomic();
+        build(&mut pipeline)?;
+
+        let _: () = pipeline.query_async(&mut self.connection()).await?;
+        Ok(())
+    }
+
+    /// Stores an available guild alongside its channels, threads & members within a single round trip.
+    async fn insert_guild_create(&self, available: &AvailableGuild) -> Result<()> {
+        let guild_id = available.guild.id;
+        self.run(|pipeline| {
+            self.queue_guild(pipeline, available.guild.clone())?;
+
+            for channel in available.channels.iter().chain(&available.threads) {
+                // Channels within GuildCreate are sent without the id of their guild.
+                let mut channel = channel.clone();
+                channel.guild_id = Some(guild_id);
+                self.queue_channel(pipeline, &channel)?;
+            }
+
+            self.queue_members(pipeline, guild_id, available.members.iter())
+        }).await
+    }
+
+    /// Removes a guild & everything stored within it.
+    async fn remove_guild(&self, guild_id: Id<GuildMarker>) -> Result<()> {
+
+        let guild_channels_key = self.key("guild_channels", Some(guild_id.get()));
+        let channel_ids: Vec<u64> = self.connection().smembers(&guild_channels_key).await?;
+
+        let mut pipeline = pipe();
+        pipeline.atomic()
+            .hdel(self.key("guilds", None), guild_id.get()).ignore()
+            .del(self.key("members", Some(guild_id.get()))).ignore()
+            .del(self.key("roles", Some(guild_id.get()))).ignore()
+            .del(guild_channels_key).ignore();
+
+        for channel_id in channel_ids {
+            pipeline.hdel(self.key("channels", None), channel_id).ignore()
+                .del(self.key("messages", Some(channel_id))).ignore()
+                .del(self.key("message_order", Some(channel_id))).ignore();
+        }
+
+        let _: () = pipeline.query_async(&mut self.connection()).await?;
+        Ok(())
+    }
+
+    /// Removes a channel & its messages.
+    async fn remove_channel(&self, channel_id: Id<ChannelMarker>, guild_id: Option<Id<GuildMarker>>) -> Result<()> {
+
+        let mut pipeline = pipe();
+        pipeline.atomic()
+            .hdel(self.key("channels", None), channel_id.get()).ignore()
+            .del(self.key("messages", Some(channel_id.get()))).ignore()
+            .del(self.key("message_order", Some(channel_id.get()))).ignore();
+
+        if let Some(guild_id) = guild_id {
+            pipeline.srem(self.key("guild_channels", Some(guild_id.get())), channel_id.get()).ignore();
+        }
+
+        let _: () = pipeline.query_async(&mut self.connection()).await?;
+        Ok(())
+    }
+
+    /// Applies a GuildMemberUpdate to the stored member, storing it if it wasnt yet.
+    async fn update_member(&self, event: &GuildMemberUpdate) -> Result<()> {
+
+        if !self.config.wants(CacheResources::MEMBERS) {
+            return Ok(())
+        }
+
+        let key = self.key("members", Some(event.guild_id.get()));
+        let member = self.hash_model(key.clone(), event.user.id.get()).await?;
+        let member = apply_member_update(member, event);
+
+        let _: () = self.connection().hset(key, event.user.id.get(), encode_model(&member)?).await?;
+        Ok(())
+    }
+
+    /// Stores a message, removing the oldest messages of the channel once it exceeds the message capacity.
+    async fn insert_message(&self, message: &Message) -> Result<()> {
+
+        let messages_key = self.key("messages", Some(message.channel_id.get()));
+        let order_key = self.key("message_order", Some(message.channel_id.get()));
+        let capacity = self.config.message_capacity as isize;
+
+        // The ids before the most recent messages within the capacity are retrieved before being trimmed, so their messages can be removed.
+        let (removed_ids,): (Vec<u64>,) = pipe()
+            .atomic()
+            .hset(&messages_key, message.id.get(), encode_model(message)?).ignore()
+            .rpush(&order_key, message.id.get()).ignore()
+            .lrange(&order_key, 0, -capacity - 1)
+            .ltrim(&order_key, -capacity, -1).ignore()
+            .query_async(&mut self.connection())
+            .await?;
+
+        if !removed_ids.is_empty() {
+            let _: () = self.connection().hdel(messages_key, removed_ids).await?;
+        }
+        Ok(())
+    }
+
+    /// Removes messages of a channel.
+    async fn remove_messages(&self, channel_id: Id<ChannelMarker>, message_ids: &[Id<MessageMarker>]) -> Result<()> {
+
+        if message_ids.is_empty() {
+            return Ok(())
+        }
+
+        let order_key = self.key("message_order", Some(channel_id.get()));
+        let mut pipeline = pipe();
+        pipeline.atomic().hdel(self.key("messages", Some(channel_id.get())), message_ids.iter().map(|id| id.get()).collect::<Vec<_>>()).ignore();
+
+        for message_id in message_ids {
+            pipeline.lrem(&order_key, 0, message_id.get()).ignore();
+        }
+
+        let _: () = pipeline.query_async(&mut self.connection()).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CacheBackend for RedisCache {
+
+    fn config(&self) -> &CacheConfig {
+        &self.config
+    }
+
+    async fn update(&self, event: &DispatchEvent) -> Result<()> {
+        match event {
+            DispatchEvent::Ready(ready) => self.connection().set(self.key("current_user", None), encode_model(&ready.user)?).await?,
+            DispatchEvent::UserUpdate(user) => self.connection().set(self.key("current_user", None), encode_model(user)?).await?,
+            DispatchEvent::GuildCreate(GuildCreate::Available(available)) => self.insert_guild_create(available).await?,
+            DispatchEvent::GuildUpdate(guild) => self.run(|pipeline| self.queue_guild(pipeline, guild.as_ref().clone())).await?,
+            // Unavailable guilds are only unavailable due to an outage so their state is kept.
+            DispatchEvent::GuildDelete(guild) if !guild.unavailable => self.remove_guild(guild.id).await?,
+            DispatchEvent::ChannelCreate(channel)
+            | DispatchEvent::ChannelUpdate(channel)
+            | DispatchEvent::ThreadCreate(channel)
+            | DispatchEvent::ThreadUpdate(channel) => self.run(|pipeline| self.queue_channel(pipeline, channel)).await?,
+            DispatchEvent::ChannelDelete(channel) | DispatchEvent::ThreadDelete(channel) => self.remove_channel(channel.id, channel.guild_id).await?,
+            DispatchEvent::GuildMemberAdd(event) => self.run(|pipeline| self.queue_members(pipeline, event.guild_id, std::iter::once(&event.member))).await?,
+            DispatchEvent::GuildMemberUpdate(event) => self.update_member(event).await?,
+            DispatchEvent::GuildMemberRemove(event) => self.connection().hdel(self.key("members", Some(event.guild_id.get())), event.user.id.get()).await?,
+            DispatchEvent::GuildRoleCreate(event) | DispatchEvent::GuildRoleUpdate(event) if self.config.wants(CacheResources::ROLES) => {
+                self.connection().hset(self.key("roles", Some(event.guild_id.get())), event.role.id.get(), encode_model(&event.role)?).await?
+            },
+            DispatchEvent::GuildRoleDelete(event) => self.connection().hdel(self.key("roles", Some(event.guild_id.get())), event.role_id.get()).await?,
+            DispatchEvent::MessageCreate(message) if self.config.wants_messages() => self.insert_message(message).await?,
+            DispatchEvent::MessageUpdate(message) => {
+                // Only messages which are stored are updated, so the order of the stored messages isnt changed.
+                let key = self.key("messages", Some(message.channel_id.get()));
+                let stored: bool = self.connection().hexists(&key, message.id.get()).await?;
+                if stored {
+                    let _: () = self.connection().hset(key, message.id.get(), encode_model(message.as_ref())?).await?;
+                }
+            },
+            DispatchEvent::MessageDelete(event) => self.remove_messages(event.channel_id, &[event.id]).await?,
+            DispatchEvent::MessageDeleteBulk(event) => self.remove_messages(event.channel_id, &event.ids).await?,
+            _ => {},
+        }
+
+        Ok(())
+    }
+
+    async fn current_user(&self) -> Result<Option<User>> {
+        let bytes: Option<Vec<u8>> = self.connection().get(self.key("current_user", None)).await?;
+        bytes.map(|bytes| decode_model(&bytes)).transpose()
+    }
+
+    async fn guild(&self, guild_id: Id<GuildMarker>) -> Result<Option<Guild>> {
+        self.hash_model(self.key("guilds", None), guild_id.get()).await
+    }
+
+    async fn guild_ids(&self) -> Result<Vec<Id<GuildMarker>>> {
+        let guild_ids: Vec<u64> = self.connection().hkeys(self.key("guilds", None)).await?;
+        Ok(guild_ids.into_iter().map(Id::from).collect())
+    }
+
+    async fn channel(&self, channel_id: Id<ChannelMarker>) -> Result<Option<Channel>> {
+        self.hash_model(self.key("channels", None), channel_id.get()).await
+    }
+
+    async fn guild_channels(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Channel>> {
+        let channel_ids: Vec<u64> = self.connection().smembers(self.key("guild_channels", Some(guild_id.get()))).await?;
+        self.hash_models_by_id(self.key("channels", None), &channel_ids).await
+    }
+
+    async fn member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> Result<Option<Member>> {
+        self.hash_model(self.key("members", Some(guild_id.get())), user_id.get()).await
+    }
+
+    async fn guild_members(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Member>> {
+        self.hash_models(self.key("members", Some(guild_id.get()))).await
+    }
+
+    async fn role(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> Result<Option<Role>> {
+        self.hash_model(self.key("roles", Some(guild_id.get())), role_id.get()).await
+    }
+
+    async fn guild_roles(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Role>> {
+        self.hash_models(self.key("roles", Some(guild_id.get()))).await
+    }
+
+    async fn message(&self, channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>) -> Result<Option<Message>> {
+        self.hash_model(self.key("messages", Some(channel_id.get())), message_id.get()).await
+    }
+
+    async fn messages(&self, channel_id: Id<ChannelMarker>) -> Result<Vec<Message>> {
+        let message_ids: Vec<u64> = self.connection().lrange(self.key("message_order", Some(channel_id.get())), 0, -1).await?;
+        self.hash_models_by_id(self.key("messages", Some(channel_id.get())), &message_ids).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::{CacheBackend, CacheConfig};
+    use crate::fixtures;
+    use crate::gateway::DispatchEvent;
+    use crate::snowflake::Id;
+    use super::RedisCache;
+
+    /// Connects to the server at REDIS_URL, utilising a prefix unique to the test so tests dont share keys.
+    async fn connect(test: &str, config: CacheConfig) -> RedisCache {
+        let url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let prefix = format!("tonsoe-test:{test}:{}", std::process::id());
+        RedisCache::connect(&url, config).await.expect("a Redis server at REDIS_URL").key_prefix(prefix)
+    }
+
+    fn event(name: &str, data: serde_json::Value) -> DispatchEvent {
+        DispatchEvent::from_name_and_data(name.to_string(), data)
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Redis server at REDIS_URL"]
+    async fn guild_create_stores_channels_and_members() {
+        let cache = connect("guild_create", CacheConfig::new()).await;
+        let data = fixtures::guild_create(10, vec![fixtures::channel(20, 10), fixtures::channel(21, 10)], vec![fixtures::member(30, &[])]);
+        cache.update(&event("GUILD_CREATE", data)).await.unwrap();
+
+        assert_eq!(cache.guild_channels(Id::new(10)).await.unwrap().len(), 2);
+        assert!(cache.member(Id::new(10), Id::new(30)).await.unwrap().is_some());
+
+        cache.update(&event("GUILD_DELETE", serde_json::json!({ "id": "10" }))).await.unwrap();
+        assert!(cache.guild(Id::new(10)).await.unwrap().is_none());
+        assert!(cache.channel(Id::new(20)).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Redis server at REDIS_URL"]
+    async fn messages_are_trimmed_to_the_capacity() {
+        let cache = connect("message_capacity", CacheConfig::new().message_capacity(2)).await;
+        for message_id in 1..=3 {
+            cache.update(&event("MESSAGE_CREATE", fixtures::message(message_id, 20))).await.unwrap();
+        }
+
+        let ids: Vec<u64> = cache.messages(Id::new(20)).await.unwrap().iter().map(|message| message.id.get()).collect();
+        assert_eq!(ids.len(), 2);
+        assert!(!ids.contains(&1));
+        assert!(cache.message(Id::new(20), Id::new(1)).await.unwrap().is_none());
+
+        cache.update(&event("CHANNEL_DELETE", fixtures::channel(20, 10))).await.unwrap();
+    }
+}
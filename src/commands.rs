@@ -0,0 +1,358 @@
+//! Requests to the [application command routes][https://discord.com/developers/docs/interactions/application-commands#endpoints] of the Discord api.
+use std::fmt;
+
+use async_trait::async_trait;
+use reqwest::Method;
+
+use crate::error::Result;
+use crate::http::{DiscordHttpClientReqSender, DiscordHttpReqType, DiscordHttpRequest};
+use crate::models::command::{ApplicationCommand, CommandType};
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ApplicationMarker, CommandMarker, GuildMarker};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// How the declared commands of a [`Bot`][crate::bot::Bot] are registered with Discord when it is elevated.
+pub enum CommandSyncMode {
+
+    /// Retrieves the registered commands & only creates, edits or deletes the commands which differ from those declared.
+    /// Unchanged commands keep their ids & permissions, and no requests are sent when nothing changed.
+    #[default]
+    Diff,
+
+    /// Retrieves the registered commands & logs the changes [`CommandSyncMode::Diff`] would make, without making them.
+    DryRun,
+
+    /// Replaces every registered command with the declared commands through a single bulk overwrite.
+    BulkOverwrite,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// The changes needed for the registered commands to match the declared commands, created by [`CommandSyncPlan::new`]
+pub struct CommandSyncPlan {
+
+    /// Declared commands which arent registered yet
+    pub create: Vec<ApplicationCommand>,
+
+    /// Declared commands which differ from the registered command of the same name & type, alongside the id of the registered command
+    pub edit: Vec<(Id<CommandMarker>, ApplicationCommand)>,
+
+    /// Registered commands which arent declared anymore
+    pub delete: Vec<ApplicationCommand>,
+
+    /// The amount of declared commands which are already registered as they are
+    pub unchanged: usize,
+}
+
+impl CommandSyncPlan {
+
+    /// Compares the registered commands to the declared commands, commands are matched by their name & type.
+    /// Utilises [`ApplicationCommand::is_equivalent`] so differences which Discord doesnt care about dont cause an edit.
+    pub fn new(registered: &[ApplicationCommand], declared: &[ApplicationCommand]) -> Self {
+
+        let mut plan = Self::default();
+        let find = |commands: &[ApplicationCommand], command: &ApplicationCommand| commands.iter()
+            .position(|other| other.kind == command.kind && other.name == command.name);
+
+        for command in declared {
+            match find(registered, command).map(|index| &registered[index]) {
+                Some(existing) if command.is_equivalent(existing) => plan.unchanged += 1,
+                Some(existing) => match existing.id {
+                    Some(command_id) => plan.edit.push((command_id, command.clone())),
+                    None => plan.create.push(command.clone()),
+                },
+                None => plan.create.push(command.clone()),
+            }
+        }
+
+        plan.delete = registered.iter()
+            .filter(|command| find(declared, command).is_none())
+            .cloned()
+            .collect();
+
+        plan
+    }
+
+    /// Whether the registered commands already match the declared commands.
+    pub fn is_empty(&self) -> bool {
+        self.create.is_empty() && self.edit.is_empty() && self.delete.is_empty()
+    }
+
+    /// Makes the changes of the plan, deleting commands first so their names are free to be reused.
+    async fn apply(&self, http: &DiscordHttpClientReqSender, application_id: Id<ApplicationMarker>, guild_id: Option<Id<GuildMarker>>) -> Result<()> {
+
+        for command in &self.delete {
+            if let Some(command_id) = command.id {
+                match guild_id {
+                    Some(guild_id) => http.delete_guild_command(application_id, guild_id, command_id).await?,
+                    None => http.delete_global_command(application_id, command_id).await?,
+                }
+            }
+        }
+
+        for (command_id, command) in &self.edit {
+            match guild_id {
+                Some(guild_id) => http.edit_guild_command(application_id, guild_id, *command_id, command).await?,
+                None => http.edit_global_command(application_id, *command_id, command).await?,
+            };
+        }
+
+        for command in &self.create {
+            match guild_id {
+                Some(guild_id) => http.create_guild_command(application_id, guild_id, command).await?,
+                None => http.create_global_command(application_id, command).await?,
+            };
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for CommandSyncPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+
+        let kind = |command: &ApplicationCommand| match command.kind {
+            CommandType::ChatInput => "chat input",
+            CommandType::User => "user",
+            CommandType::Message => "message",
+            _ => "other",
+        };
+
+        write!(f, "{} to create, {} to edit, {} to delete, {} unchanged", self.create.len(), self.edit.len(), self.delete.len(), self.unchanged)?;
+
+        for command in &self.create {
+            write!(f, "\n  create {} command {}", kind(command), command.name)?;
+        }
+
+        for (command_id, command) in &self.edit {
+            write!(f, "\n  edit {} command {} ({command_id})", kind(command), command.name)?;
+        }
+
+        for command in &self.delete {
+            write!(f, "\n  delete {} command {}", kind(command), command.name)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+/// Trait which exists so application commands can be registered, retrieved & deleted through a [`DiscordHttpClientReqSender`]
+/// Commands breaking a limit of the Discord api return [`Error::Validation`][crate::error::Error::Validation] without being sent.
+pub trait CommandRequests {
+
+    /// Retrieves the global commands of the application, including their localizations.
+    async fn get_global_commands(&self, application_id: Id<ApplicationMarker>) -> Result<Vec<ApplicationCommand>>;
+
+    /// Retrieves a single global command.
+    async fn get_global_command(&self, application_id: Id<ApplicationMarker>, command_id: Id<CommandMarker>) -> Result<ApplicationCommand>;
+
+    /// Creates a global command, a command with the same name & type is overwritten.
+    async fn create_global_command(&self, application_id: Id<ApplicationMarker>, command: &ApplicationCommand) -> Result<ApplicationCommand>;
+
+    /// Edits a global command, the type of a command can not be changed.
+    async fn edit_global_command(&self, application_id: Id<ApplicationMarker>, command_id: Id<CommandMarker>, command: &ApplicationCommand) -> Result<ApplicationCommand>;
+
+    /// Deletes a global command.
+    async fn delete_global_command(&self, application_id: Id<ApplicationMarker>, command_id: Id<CommandMarker>) -> Result<()>;
+
+    /// Replaces every global command with the commands, commands which arent listed are deleted.
+    async fn bulk_overwrite_global_commands(&self, application_id: Id<ApplicationMarker>, commands: &[ApplicationCommand]) -> Result<Vec<ApplicationCommand>>;
+
+    /// Retrieves the commands of the application within a guild, including their localizations.
+    async fn get_guild_commands(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>) -> Result<Vec<ApplicationCommand>>;
+
+    /// Retrieves a single command within a guild.
+    async fn get_guild_command(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>, command_id: Id<CommandMarker>) -> Result<ApplicationCommand>;
+
+    /// Creates a command within a guild, a command with the same name & type is overwritten.
+    async fn create_guild_command(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>, command: &ApplicationCommand) -> Result<ApplicationCommand>;
+
+    /// Edits a command within a guild, the type of a command can not be changed.
+    async fn edit_guild_command(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>, command_id: Id<CommandMarker>, command: &ApplicationCommand) -> Result<ApplicationCommand>;
+
+    /// Deletes a command within a guild.
+    async fn delete_guild_command(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>, command_id: Id<CommandMarker>) -> Result<()>;
+
+    /// Replaces every command within a guild with the commands, commands which arent listed are deleted.
+    async fn bulk_overwrite_guild_commands(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>, commands: &[ApplicationCommand]) -> Result<Vec<ApplicationCommand>>;
+
+    /// Makes the registered commands globally, or within a guild, match the declared commands by only creating, editing or deleting those which changed.
+    /// Nothing is changed when dry_run is true, the returned [`CommandSyncPlan`] contains the changes which were, or would have been, made.
+    async fn sync_commands(&self, application_id: Id<ApplicationMarker>, guild_id: Option<Id<GuildMarker>>, commands: &[ApplicationCommand], dry_run: bool) -> Result<CommandSyncPlan>;
+}
+
+#[async_trait]
+impl CommandRequests for DiscordHttpClientReqSender {
+
+    async fn get_global_commands(&self, application_id: Id<ApplicationMarker>) -> Result<Vec<ApplicationCommand>> {
+        DiscordHttpRequest::new(DiscordHttpReqType::GlobalCommands { application_id }, Method::GET)
+            .with_query("with_localizations", true)
+            .request(self.clone())
+            .await
+    }
+
+    async fn get_global_command(&self, application_id: Id<ApplicationMarker>, command_id: Id<CommandMarker>) -> Result<ApplicationCommand> {
+        DiscordHttpRequest::new(DiscordHttpReqType::GlobalCommand { application_id, command_id }, Method::GET)
+            .request(self.clone())
+            .await
+    }
+
+    async fn create_global_command(&self, application_id: Id<ApplicationMarker>, command: &ApplicationCommand) -> Result<ApplicationCommand> {
+        command.validate()?;
+
+        DiscordHttpRequest::new(DiscordHttpReqType::GlobalCommands { application_id }, Method::POST)
+            .with_json(command)?
+            .request(self.clone())
+            .await
+    }
+
+    async fn edit_global_command(&self, application_id: Id<ApplicationMarker>, command_id: Id<CommandMarker>, command: &ApplicationCommand) -> Result<ApplicationCommand> {
+        command.validate()?;
+
+        DiscordHttpRequest::new(DiscordHttpReqType::GlobalCommand { application_id, command_id }, Method::PATCH)
+            .with_json(command)?
+            .request(self.clone())
+            .await
+    }
+
+    async fn delete_global_command(&self, application_id: Id<ApplicationMarker>, command_id: Id<CommandMarker>) -> Result<()> {
+        DiscordHttpRequest::new(DiscordHttpReqType::GlobalCommand { application_id, command_id }, Method::DELETE)
+            .request(self.clone())
+            .await
+    }
+
+    async fn bulk_overwrite_global_commands(&self, application_id: Id<ApplicationMarker>, commands: &[ApplicationCommand]) -> Result<Vec<ApplicationCommand>> {
+        ApplicationCommand::validate_all(commands)?;
+
+        DiscordHttpRequest::new(DiscordHttpReqType::GlobalCommands { application_id }, Method::PUT)
+            .with_json(&commands)?
+            .request(self.clone())
+            .await
+    }
+
+    async fn get_guild_commands(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>) -> Result<Vec<ApplicationCommand>> {
+        DiscordHttpRequest::new(DiscordHttpReqType::GuildCommands { application_id, guild_id }, Method::GET)
+            .with_query("with_localizations", true)
+            .request(self.clone())
+            .await
+    }
+
+    async fn get_guild_command(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>, command_id: Id<CommandMarker>) -> Result<ApplicationCommand> {
+        DiscordHttpRequest::new(DiscordHttpReqType::GuildCommand { application_id, guild_id, command_id }, Method::GET)
+            .request(self.clone())
+            .await
+    }
+
+    async fn create_guild_command(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>, command: &ApplicationCommand) -> Result<ApplicationCommand> {
+        command.validate()?;
+
+        DiscordHttpRequest::new(DiscordHttpReqType::GuildCommands { application_id, guild_id }, Method::POST)
+            .with_json(command)?
+            .request(self.clone())
+            .await
+    }
+
+    async fn edit_guild_command(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>, command_id: Id<CommandMarker>, command: &ApplicationCommand) -> Result<ApplicationCommand> {
+        command.validate()?;
+
+        DiscordHttpRequest::new(DiscordHttpReqType::GuildCommand { application_id, guild_id, command_id }, Method::PATCH)
+            .with_json(command)?
+            .request(self.clone())
+            .await
+    }
+
+    async fn delete_guild_command(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>, command_id: Id<CommandMarker>) -> Result<()> {
+        DiscordHttpRequest::new(DiscordHttpReqType::GuildCommand { application_id, guild_id, command_id }, Method::DELETE)
+            .request(self.clone())
+            .await
+    }
+
+    async fn bulk_overwrite_guild_commands(&self, application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>, commands: &[ApplicationCommand]) -> Result<Vec<ApplicationCommand>> {
+        ApplicationCommand::validate_all(commands)?;
+
+        DiscordHttpRequest::new(DiscordHttpReqType::GuildCommands { application_id, guild_id }, Method::PUT)
+            .with_json(&commands)?
+            .request(self.clone())
+            .await
+    }
+
+    async fn sync_commands(&self, application_id: Id<ApplicationMarker>, guild_id: Option<Id<GuildMarker>>, commands: &[ApplicationCommand], dry_run: bool) -> Result<CommandSyncPlan> {
+        ApplicationCommand::validate_all(commands)?;
+
+        let registered = match guild_id {
+            Some(guild_id) => self.get_guild_commands(application_id, guild_id).await?,
+            None => self.get_global_commands(application_id).await?,
+        };
+
+        let plan = CommandSyncPlan::new(&registered, commands);
+        if !dry_run {
+            plan.apply(self, application_id, guild_id).await?;
+        }
+
+        Ok(plan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::command::{ApplicationCommand, CommandBuilder, CommandOption, CommandOptionType};
+    use crate::snowflake::Id;
+    use super::CommandSyncPlan;
+
+    fn command(builder: CommandBuilder) -> ApplicationCommand {
+        builder.build().unwrap()
+    }
+
+    fn registered(command_id: u64, builder: CommandBuilder) -> ApplicationCommand {
+        ApplicationCommand { id: Some(Id::new(command_id)), ..command(builder) }
+    }
+
+    fn with_options(name: &str, options: &[(CommandOptionType, &str)]) -> CommandBuilder {
+        options.iter().fold(CommandBuilder::chat_input(name, "description"), |builder, (kind, name)| builder.option(CommandOption::new(*kind, *name, "description")))
+    }
+
+    #[test]
+    fn commands_are_created_edited_and_deleted() {
+        let registered_commands = [
+            registered(1, CommandBuilder::chat_input("unchanged", "description")),
+            registered(2, CommandBuilder::chat_input("changed", "description")),
+            registered(3, CommandBuilder::chat_input("removed", "description")),
+            registered(4, CommandBuilder::user("renamed")),
+        ];
+        let declared = [
+            command(CommandBuilder::chat_input("unchanged", "description")),
+            command(CommandBuilder::chat_input("changed", "new description")),
+            command(CommandBuilder::chat_input("created", "description")),
+
+            // Commands are matched by their type as well as their name.
+            command(CommandBuilder::message("renamed")),
+        ];
+
+        let plan = CommandSyncPlan::new(&registered_commands, &declared);
+        assert_eq!(plan.unchanged, 1);
+        assert_eq!(plan.edit, [(Id::new(2), declared[1].clone())]);
+        assert_eq!(plan.create, [declared[2].clone(), declared[3].clone()]);
+        assert_eq!(plan.delete, [registered_commands[2].clone(), registered_commands[3].clone()]);
+    }
+
+    #[test]
+    fn matching_commands_need_no_changes() {
+        let sub_commands = [(CommandOptionType::SubCommand, "first"), (CommandOptionType::SubCommand, "second")];
+        let reordered = [(CommandOptionType::SubCommand, "second"), (CommandOptionType::SubCommand, "first")];
+
+        let plan = CommandSyncPlan::new(&[registered(1, with_options("command", &reordered))], &[command(with_options("command", &sub_commands))]);
+        assert!(plan.is_empty());
+        assert_eq!(plan.unchanged, 1);
+    }
+
+    #[test]
+    fn reordered_options_are_edited() {
+        let options = [(CommandOptionType::String, "first"), (CommandOptionType::String, "second")];
+        let reordered = [(CommandOptionType::String, "second"), (CommandOptionType::String, "first")];
+
+        let declared = [command(with_options("command", &options))];
+        let plan = CommandSyncPlan::new(&[registered(1, with_options("command", &reordered))], &declared);
+        assert_eq!(plan.edit, [(Id::new(1), declared[0].clone())]);
+        assert!(plan.create.is_empty() && plan.delete.is_empty());
+    }
+}
@@ -0,0 +1,247 @@
+//! The [`Error`] type returned by every fallible operation within the library.
+use reqwest::StatusCode;
+use reqwest::header::InvalidHeaderValue;
+use tokio_tungstenite::tungstenite::Error as WebSocketError;
+
+use crate::gateway::GatewayCloseCode;
+use crate::http::DiscordApiError;
+use crate::snowflake::Id;
+use crate::snowflake::marker::MessageMarker;
+
+/// Shortened Alias for a [`Result`][std::result::Result] with the error defaulting to [`Error`]
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug, thiserror::Error)]
+/// Every error which can be returned by the library, so failures can be matched on.
+pub enum Error {
+
+    /// The Discord api responded with an unsuccessful status code & no error body.
+    #[error("Discord api responded with status {status}")]
+    Status {
+
+        /// The status code of the response
+        status: StatusCode,
+    },
+
+    /// The Discord api responded with an unsuccessful status code & a [JSON error code][https://discord.com/developers/docs/topics/opcodes-and-status-codes#json]
+    #[error("Discord api responded with status {status}: {error}")]
+    DiscordApi {
+
+        /// The status code of the response
+        status: StatusCode,
+
+        /// The error body of the response
+        error: DiscordApiError,
+    },
+
+    /// A shard was stopped as Discords gateway closed it with a close code which can not be recovered from.
+    #[error("Shard {shard_id} stopped: {close_code}")]
+    GatewayClosed {
+
+        /// The id of the shard which was stopped
+        shard_id: u32,
+
+        /// The close code the connection was closed with
+        close_code: GatewayCloseCode,
+    },
+
+    /// The connection to Discords gateway ended while a payload was expected.
+    #[error("Discord Gateway connection ended while a payload was expected")]
+    GatewayConnectionEnded,
+
+    /// A payload or request body could not be serialized or deserialized.
+    #[error("Failed to serialize or deserialize JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// A request to the Discord api could not be sent or its response could not be read.
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The server recieving interactions through an Interactions Endpoint URL failed.
+    #[cfg(feature = "http-interactions")]
+    #[error("Interaction server failed: {0}")]
+    InteractionServer(#[from] hyper::Error),
+
+    /// A request to the Redis-protocol server storing the cache failed.
+    #[cfg(feature = "redis-cache")]
+    #[error("Redis cache request failed: {0}")]
+    Redis(#[from] redis::RedisError),
+
+    /// A cached model stored outside of the process could not be decoded.
+    #[error("Invalid cached model: {0}")]
+    CacheFormat(String),
+
+    /// The websocket connection to Discords gateway failed.
+    /// Boxed as the websocket error is far larger than every other variant.
+    #[error("Discord Gateway websocket failed: {0}")]
+    WebSocket(Box<WebSocketError>),
+
+    /// A header value contained invalid characters, such as a token which isnt ASCII.
+    #[error("Invalid header value: {0}")]
+    InvalidHeader(#[from] InvalidHeaderValue),
+
+    /// A url could not be created.
+    #[error("Invalid url: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+
+    /// Reading a file to upload failed.
+    #[error("Failed to read a file: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A streamed [`AttachmentFile`][crate::attachment::AttachmentFile] was sent already, so its contents can not be read again.
+    #[error("The streamed attachment {0} was already sent")]
+    AttachmentSent(String),
+
+    /// A channel within the library was closed as the task processing it has stopped.
+    #[error("The {0} channel was closed")]
+    ChannelClosed(&'static str),
+
+    /// A spawned task within the library panicked or was cancelled.
+    #[error("A spawned task failed: {0}")]
+    Task(#[from] tokio::task::JoinError),
+
+    /// The [`Bot`][crate::bot::Bot] was configured with an invalid option.
+    #[error("Invalid configuration: {0}")]
+    Configuration(String),
+
+    /// A request was rejected before being sent as it breaks a limit of the Discord api.
+    #[error("Invalid request: {0}")]
+    Validation(#[from] ValidationError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// A limit of the Discord api which a request breaks, checked before the request is sent so it doesnt fail with a 400.
+/// The field is the path to the offending field, e.g: "embeds[0].fields[3].name"
+pub enum ValidationError {
+
+    /// A text field is longer than Discord allows.
+    #[error("{field} is {length} characters long, the limit is {max}")]
+    TooLong {
+
+        /// The path to the field
+        field: String,
+
+        /// The most Discord allows
+        max: usize,
+
+        /// The length of the field in characters
+        length: usize,
+    },
+
+    /// A list contains more items than Discord allows.
+    #[error("{field} contains {count} items, the limit is {max}")]
+    TooMany {
+
+        /// The path to the field
+        field: String,
+
+        /// The most Discord allows
+        max: usize,
+
+        /// The amount of items within the list
+        count: usize,
+    },
+
+    /// A list contains fewer items than Discord requires.
+    #[error("{field} contains {count} items, at least {min} are required")]
+    TooFew {
+
+        /// The path to the field
+        field: String,
+
+        /// The fewest Discord allows
+        min: usize,
+
+        /// The amount of items within the list
+        count: usize,
+    },
+
+    /// A number is outside of the range Discord allows.
+    #[error("{field} is {value}, it must be between {min} & {max}")]
+    OutOfRange {
+
+        /// The path to the field
+        field: String,
+
+        /// The lowest value Discord allows
+        min: i64,
+
+        /// The highest value Discord allows
+        max: i64,
+
+        /// The value of the field
+        value: i64,
+    },
+
+    /// A field which is required to contain something is empty.
+    #[error("{field} must not be empty")]
+    Empty {
+
+        /// The path to the field
+        field: String,
+    },
+
+    /// A combination of fields which Discord doesnt allow, such as a link button with a custom_id.
+    #[error("{field} is invalid: {reason}")]
+    Invalid {
+
+        /// The path to the field
+        field: String,
+
+        /// Why the field is invalid
+        reason: String,
+    },
+
+    /// A message is too old to be bulk deleted.
+    #[error("message {message_id} is older than 2 weeks so it can not be bulk deleted")]
+    MessageTooOld {
+
+        /// The id of the message
+        message_id: Id<MessageMarker>,
+    },
+}
+
+impl ValidationError {
+
+    /// Prefixes the path to the offending field, e.g: "title" within the first embed becomes "embeds[0].title"
+    pub fn prefixed(mut self, prefix: &str) -> Self {
+        match &mut self {
+            Self::TooLong { field, .. }
+            | Self::TooMany { field, .. }
+            | Self::TooFew { field, .. }
+            | Self::OutOfRange { field, .. }
+            | Self::Empty { field }
+            | Self::Invalid { field, .. } => *field = format!("{prefix}.{field}"),
+            Self::MessageTooOld { .. } => {},
+        }
+
+        self
+    }
+
+    /// Checks the length of a text field in characters.
+    pub fn check_length(field: impl FnOnce() -> String, value: &str, max: usize) -> Result<(), Self> {
+        let length = value.chars().count();
+        match length > max {
+            true => Err(Self::TooLong { field: field(), max, length }),
+            false => Ok(()),
+        }
+    }
+
+    /// Checks the amount of items within a list.
+    pub fn check_count(field: impl FnOnce() -> String, count: usize, min: usize, max: usize) -> Result<(), Self> {
+        if count > max {
+            return Err(Self::TooMany { field: field(), max, count })
+        }
+
+        match count < min {
+            true => Err(Self::TooFew { field: field(), min, count }),
+            false => Ok(()),
+        }
+    }
+}
+
+impl From<WebSocketError> for Error {
+    fn from(error: WebSocketError) -> Self {
+        Self::WebSocket(Box::new(error))
+    }
+}
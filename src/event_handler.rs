@@ -0,0 +1,368 @@
+//! The [`EventHandler`] trait utilised by a [`Bot`] for reacting to events dispatched by Discords gateway.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::gateway::{Gateway, DispatchEvent, EventType, RawDispatch};
+use crate::gateway_structs::{Ready, GuildCreate, ChannelPinsUpdate, GuildBan, GuildMemberAdd, GuildMemberRemove, GuildMemberUpdate, GuildRoleUpdate, GuildRoleDelete, MessageDelete, MessageDeleteBulk};
+use crate::gateway_structs::{AutoModerationActionExecution, ThreadListSync, ThreadMemberUpdate, ThreadMembersUpdate, GuildAuditLogEntryCreate, GuildEmojisUpdate, GuildStickersUpdate, GuildIntegrationsUpdate, GuildMembersChunk, GuildScheduledEventUser, GuildSoundboardSoundDelete, GuildSoundboardSounds, IntegrationUpdate, IntegrationDelete, InviteCreate, InviteDelete, MessageReactionAdd, MessageReactionRemove, MessageReactionRemoveAll, MessageReactionRemoveEmoji, MessagePollVote, TypingStart, VoiceChannelEffectSend, VoiceServerUpdate, WebhooksUpdate};
+use crate::models::auto_moderation::AutoModerationRule;
+use crate::models::channel::Channel;
+use crate::models::command::GuildCommandPermissions;
+use crate::models::guild::{Guild, UnavailableGuild};
+use crate::models::interaction::Interaction;
+use crate::models::message::Message;
+use crate::models::monetization::{Entitlement, Subscription};
+use crate::models::presence::Presence;
+use crate::models::scheduled_event::GuildScheduledEvent;
+use crate::models::user::User;
+use crate::models::voice::{SoundboardSound, StageInstance, VoiceState};
+use crate::http::DiscordHttpClientReqSender;
+use crate::cache::CacheBackend;
+
+/// Shortened Alias for the shared list of [`EventHandler`] registered on a [`Bot`]
+pub type EventHandlers = Arc<Vec<Arc<dyn EventHandler>>>;
+
+#[derive(Clone)]
+/// Information passed to an [`EventHandler`] alongside every event so it is able to respond.
+pub struct Context {
+
+    /// The [`Gateway`] of the shard which recieved the event.
+    /// None for interactions recieved through an Interactions Endpoint URL rather than a shard.
+    pub gateway: Option<Gateway>,
+
+    /// The sender to the [`DiscordHttpClient`] channel utilised for making requests to the Discord api.
+    pub http: DiscordHttpClientReqSender,
+
+    /// The cache of the state recieved through the gateway, None unless enabled through [`Bot::enable_cache`][crate::bot::Bot::enable_cache] or [`Bot::set_cache`][crate::bot::Bot::set_cache]
+    pub cache: Option<Arc<dyn CacheBackend>>,
+}
+
+#[async_trait]
+/// Trait which is implemented to react to [events dispatched by Discords gateway][https://discord.com/developers/docs/topics/gateway-events#receive-events]
+/// Every method does nothing by default, so only the events which are wanted need implementing.
+/// Each event is handled in its own task so a slow handler does not stop a shard from recieving events.
+pub trait EventHandler: Send + Sync + 'static {
+
+    /// The events which this handler implements, utilised by the [`Bot`][crate::bot::Bot] for checking that its [`Intents`][crate::bot::Intents] recieve them.
+    /// Which methods are implemented cant be known by the library, so the handler isnt checked unless this is implemented & a warning is logged for it when the bot is elevated.
+    fn subscribed_events(&self) -> Vec<EventType> {
+        Vec::new()
+    }
+
+    /// The name of the handler within logs, the name of its type by default.
+    fn handler_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// The Identify handshake completed successfully & the session is ready.
+    async fn ready(&self, _context: Context, _ready: Ready) {}
+
+    /// A session was resumed successfully & all missed events have been replayed.
+    async fn resumed(&self, _context: Context) {}
+
+    /// Application command permissions were updated
+    async fn application_command_permissions_update(&self, _context: Context, _permissions: GuildCommandPermissions) {}
+
+    /// An auto moderation rule was created
+    async fn auto_moderation_rule_create(&self, _context: Context, _rule: AutoModerationRule) {}
+
+    /// An auto moderation rule was updated
+    async fn auto_moderation_rule_update(&self, _context: Context, _rule: AutoModerationRule) {}
+
+    /// An auto moderation rule was deleted
+    async fn auto_moderation_rule_delete(&self, _context: Context, _rule: AutoModerationRule) {}
+
+    /// An auto moderation rule was triggered and an action was executed
+    async fn auto_moderation_action_execution(&self, _context: Context, _execution: AutoModerationActionExecution) {}
+
+    /// A new guild channel was created
+    async fn channel_create(&self, _context: Context, _channel: Channel) {}
+
+    /// A channel was updated
+    async fn channel_update(&self, _context: Context, _channel: Channel) {}
+
+    /// A channel was deleted
+    async fn channel_delete(&self, _context: Context, _channel: Channel) {}
+
+    /// A message was pinned or unpinned in a channel
+    async fn channel_pins_update(&self, _context: Context, _pins_update: ChannelPinsUpdate) {}
+
+    /// A thread was created or the bot was added to a private thread
+    async fn thread_create(&self, _context: Context, _thread: Channel) {}
+
+    /// A thread was updated
+    async fn thread_update(&self, _context: Context, _thread: Channel) {}
+
+    /// A thread was deleted
+    async fn thread_delete(&self, _context: Context, _thread: Channel) {}
+
+    /// The bot gained access to a channel and is sent the active threads within it
+    async fn thread_list_sync(&self, _context: Context, _sync: ThreadListSync) {}
+
+    /// The thread member object for the bot was updated
+    async fn thread_member_update(&self, _context: Context, _member: ThreadMemberUpdate) {}
+
+    /// Some users were added to or removed from a thread
+    async fn thread_members_update(&self, _context: Context, _members: ThreadMembersUpdate) {}
+
+    /// An entitlement was created
+    async fn entitlement_create(&self, _context: Context, _entitlement: Entitlement) {}
+
+    /// An entitlement was updated or renewed
+    async fn entitlement_update(&self, _context: Context, _entitlement: Entitlement) {}
+
+    /// An entitlement was deleted
+    async fn entitlement_delete(&self, _context: Context, _entitlement: Entitlement) {}
+
+    /// A guild became available, the bot joined a guild or a guild is lazily loaded on connect
+    async fn guild_create(&self, _context: Context, _guild: GuildCreate) {}
+
+    /// A guild was updated
+    async fn guild_update(&self, _context: Context, _guild: Guild) {}
+
+    /// A guild became unavailable or the bot was removed from a guild
+    async fn guild_delete(&self, _context: Context, _guild: UnavailableGuild) {}
+
+    /// An entry was added to a guilds audit log
+    async fn guild_audit_log_entry_create(&self, _context: Context, _entry: GuildAuditLogEntryCreate) {}
+
+    /// A user was banned from a guild
+    async fn guild_ban_add(&self, _context: Context, _ban: GuildBan) {}
+
+    /// A user was unbanned from a guild
+    async fn guild_ban_remove(&self, _context: Context, _ban: GuildBan) {}
+
+    /// The emojis of a guild were updated
+    async fn guild_emojis_update(&self, _context: Context, _emojis: GuildEmojisUpdate) {}
+
+    /// The stickers of a guild were updated
+    async fn guild_stickers_update(&self, _context: Context, _stickers: GuildStickersUpdate) {}
+
+    /// The integrations of a guild were updated
+    async fn guild_integrations_update(&self, _context: Context, _integrations: GuildIntegrationsUpdate) {}
+
+    /// A new user joined a guild
+    async fn guild_member_add(&self, _context: Context, _member: GuildMemberAdd) {}
+
+    /// A user was removed from a guild
+    async fn guild_member_remove(&self, _context: Context, _removed: GuildMemberRemove) {}
+
+    /// A guild member was updated
+    async fn guild_member_update(&self, _context: Context, _member: GuildMemberUpdate) {}
+
+    /// A chunk of guild members in response to a Request Guild Members command
+    async fn guild_members_chunk(&self, _context: Context, _chunk: GuildMembersChunk) {}
+
+    /// A guild role was created
+    async fn guild_role_create(&self, _context: Context, _role: GuildRoleUpdate) {}
+
+    /// A guild role was updated
+    async fn guild_role_update(&self, _context: Context, _role: GuildRoleUpdate) {}
+
+    /// A guild role was deleted
+    async fn guild_role_delete(&self, _context: Context, _role: GuildRoleDelete) {}
+
+    /// A guild scheduled event was created
+    async fn guild_scheduled_event_create(&self, _context: Context, _event: GuildScheduledEvent) {}
+
+    /// A guild scheduled event was updated
+    async fn guild_scheduled_event_update(&self, _context: Context, _event: GuildScheduledEvent) {}
+
+    /// A guild scheduled event was deleted
+    async fn guild_scheduled_event_delete(&self, _context: Context, _event: GuildScheduledEvent) {}
+
+    /// A user subscribed to a guild scheduled event
+    async fn guild_scheduled_event_user_add(&self, _context: Context, _subscriber: GuildScheduledEventUser) {}
+
+    /// A user unsubscribed from a guild scheduled event
+    async fn guild_scheduled_event_user_remove(&self, _context: Context, _subscriber: GuildScheduledEventUser) {}
+
+    /// A guild soundboard sound was created
+    async fn guild_soundboard_sound_create(&self, _context: Context, _sound: SoundboardSound) {}
+
+    /// A guild soundboard sound was updated
+    async fn guild_soundboard_sound_update(&self, _context: Context, _sound: SoundboardSound) {}
+
+    /// A guild soundboard sound was deleted
+    async fn guild_soundboard_sound_delete(&self, _context: Context, _deleted: GuildSoundboardSoundDelete) {}
+
+    /// The soundboard sounds of a guild were updated
+    async fn guild_soundboard_sounds_update(&self, _context: Context, _sounds: GuildSoundboardSounds) {}
+
+    /// The soundboard sounds of a guild in response to a Request Soundboard Sounds command
+    async fn soundboard_sounds(&self, _context: Context, _sounds: GuildSoundboardSounds) {}
+
+    /// A guild integration was created
+    async fn integration_create(&self, _context: Context, _integration: IntegrationUpdate) {}
+
+    /// A guild integration was updated
+    async fn integration_update(&self, _context: Context, _integration: IntegrationUpdate) {}
+
+    /// A guild integration was deleted
+    async fn integration_delete(&self, _context: Context, _deleted: IntegrationDelete) {}
+
+    /// A user used an application command, component or modal, which must be responded to within 3 seconds.
+    /// A warning is logged if it isnt responded to through the methods of [`Interaction`] shortly before then.
+    async fn interaction_create(&self, _context: Context, _interaction: Interaction) {}
+
+    /// An invite to a channel was created
+    async fn invite_create(&self, _context: Context, _invite: InviteCreate) {}
+
+    /// An invite to a channel was deleted
+    async fn invite_delete(&self, _context: Context, _invite: InviteDelete) {}
+
+    /// A message was created
+    async fn message_create(&self, _context: Context, _message: Message) {}
+
+    /// A message was edited
+    async fn message_update(&self, _context: Context, _message: Message) {}
+
+    /// A message was deleted
+    async fn message_delete(&self, _context: Context, _deleted: MessageDelete) {}
+
+    /// Multiple messages were deleted at once
+    async fn message_delete_bulk(&self, _context: Context, _deleted: MessageDeleteBulk) {}
+
+    /// A user reacted to a message
+    async fn message_reaction_add(&self, _context: Context, _reaction: MessageReactionAdd) {}
+
+    /// A user removed a reaction from a message
+    async fn message_reaction_remove(&self, _context: Context, _reaction: MessageReactionRemove) {}
+
+    /// All reactions were explicitly removed from a message
+    async fn message_reaction_remove_all(&self, _context: Context, _removed: MessageReactionRemoveAll) {}
+
+    /// All reactions for a given emoji were explicitly removed from a message
+    async fn message_reaction_remove_emoji(&self, _context: Context, _removed: MessageReactionRemoveEmoji) {}
+
+    /// A user voted on a poll
+    async fn message_poll_vote_add(&self, _context: Context, _vote: MessagePollVote) {}
+
+    /// A user removed their vote on a poll
+    async fn message_poll_vote_remove(&self, _context: Context, _vote: MessagePollVote) {}
+
+    /// A users presence or info was updated
+    async fn presence_update(&self, _context: Context, _presence: Presence) {}
+
+    /// A stage instance was created
+    async fn stage_instance_create(&self, _context: Context, _stage: StageInstance) {}
+
+    /// A stage instance was updated
+    async fn stage_instance_update(&self, _context: Context, _stage: StageInstance) {}
+
+    /// A stage instance was deleted or closed
+    async fn stage_instance_delete(&self, _context: Context, _stage: StageInstance) {}
+
+    /// A premium subscription was created
+    async fn subscription_create(&self, _context: Context, _subscription: Subscription) {}
+
+    /// A premium subscription was updated
+    async fn subscription_update(&self, _context: Context, _subscription: Subscription) {}
+
+    /// A premium subscription was deleted
+    async fn subscription_delete(&self, _context: Context, _subscription: Subscription) {}
+
+    /// A user started typing in a channel
+    async fn typing_start(&self, _context: Context, _typing: TypingStart) {}
+
+    /// Properties about the user of the bot changed
+    async fn user_update(&self, _context: Context, _user: User) {}
+
+    /// Someone sent an effect in a voice channel the bot is connected to
+    async fn voice_channel_effect_send(&self, _context: Context, _effect: VoiceChannelEffectSend) {}
+
+    /// Someone joined, left or moved voice channels
+    async fn voice_state_update(&self, _context: Context, _state: VoiceState) {}
+
+    /// The voice server of a guild was updated
+    async fn voice_server_update(&self, _context: Context, _server: VoiceServerUpdate) {}
+
+    /// A guild channel webhook was created, updated or deleted
+    async fn webhooks_update(&self, _context: Context, _webhooks: WebhooksUpdate) {}
+
+    /// An event which isnt recognised by the library or which failed to be decoded into its typed variant.
+    async fn unknown_event(&self, _context: Context, _raw: RawDispatch) {}
+}
+
+/// Calls the method of an [`EventHandler`] which corresponds to the [`DispatchEvent`]
+pub async fn dispatch_event(handler: Arc<dyn EventHandler>, context: Context, event: DispatchEvent) {
+    match event {
+        DispatchEvent::Ready(ready) => handler.ready(context, ready).await,
+        DispatchEvent::Resumed => handler.resumed(context).await,
+        DispatchEvent::ApplicationCommandPermissionsUpdate(data) => handler.application_command_permissions_update(context, data).await,
+        DispatchEvent::AutoModerationRuleCreate(data) => handler.auto_moderation_rule_create(context, data).await,
+        DispatchEvent::AutoModerationRuleUpdate(data) => handler.auto_moderation_rule_update(context, data).await,
+        DispatchEvent::AutoModerationRuleDelete(data) => handler.auto_moderation_rule_delete(context, data).await,
+        DispatchEvent::AutoModerationActionExecution(data) => handler.auto_moderation_action_execution(context, data).await,
+        DispatchEvent::ChannelCreate(data) => handler.channel_create(context, data).await,
+        DispatchEvent::ChannelUpdate(data) => handler.channel_update(context, data).await,
+        DispatchEvent::ChannelDelete(data) => handler.channel_delete(context, data).await,
+        DispatchEvent::ChannelPinsUpdate(data) => handler.channel_pins_update(context, data).await,
+        DispatchEvent::ThreadCreate(data) => handler.thread_create(context, data).await,
+        DispatchEvent::ThreadUpdate(data) => handler.thread_update(context, data).await,
+        DispatchEvent::ThreadDelete(data) => handler.thread_delete(context, data).await,
+        DispatchEvent::ThreadListSync(data) => handler.thread_list_sync(context, data).await,
+        DispatchEvent::ThreadMemberUpdate(data) => handler.thread_member_update(context, data).await,
+        DispatchEvent::ThreadMembersUpdate(data) => handler.thread_members_update(context, data).await,
+        DispatchEvent::EntitlementCreate(data) => handler.entitlement_create(context, data).await,
+        DispatchEvent::EntitlementUpdate(data) => handler.entitlement_update(context, data).await,
+        DispatchEvent::EntitlementDelete(data) => handler.entitlement_delete(context, data).await,
+        DispatchEvent::GuildCreate(data) => handler.guild_create(context, data).await,
+        DispatchEvent::GuildUpdate(data) => handler.guild_update(context, *data).await,
+        DispatchEvent::GuildDelete(data) => handler.guild_delete(context, data).await,
+        DispatchEvent::GuildAuditLogEntryCreate(data) => handler.guild_audit_log_entry_create(context, data).await,
+        DispatchEvent::GuildBanAdd(data) => handler.guild_ban_add(context, data).await,
+        DispatchEvent::GuildBanRemove(data) => handler.guild_ban_remove(context, data).await,
+        DispatchEvent::GuildEmojisUpdate(data) => handler.guild_emojis_update(context, data).await,
+        DispatchEvent::GuildStickersUpdate(data) => handler.guild_stickers_update(context, data).await,
+        DispatchEvent::GuildIntegrationsUpdate(data) => handler.guild_integrations_update(context, data).await,
+        DispatchEvent::GuildMemberAdd(data) => handler.guild_member_add(context, data).await,
+        DispatchEvent::GuildMemberRemove(data) => handler.guild_member_remove(context, data).await,
+        DispatchEvent::GuildMemberUpdate(data) => handler.guild_member_update(context, data).await,
+        DispatchEvent::GuildMembersChunk(data) => handler.guild_members_chunk(context, data).await,
+        DispatchEvent::GuildRoleCreate(data) => handler.guild_role_create(context, data).await,
+        DispatchEvent::GuildRoleUpdate(data) => handler.guild_role_update(context, data).await,
+        DispatchEvent::GuildRoleDelete(data) => handler.guild_role_delete(context, data).await,
+        DispatchEvent::GuildScheduledEventCreate(data) => handler.guild_scheduled_event_create(context, data).await,
+        DispatchEvent::GuildScheduledEventUpdate(data) => handler.guild_scheduled_event_update(context, data).await,
+        DispatchEvent::GuildScheduledEventDelete(data) => handler.guild_scheduled_event_delete(context, data).await,
+        DispatchEvent::GuildScheduledEventUserAdd(data) => handler.guild_scheduled_event_user_add(context, data).await,
+        DispatchEvent::GuildScheduledEventUserRemove(data) => handler.guild_scheduled_event_user_remove(context, data).await,
+        DispatchEvent::GuildSoundboardSoundCreate(data) => handler.guild_soundboard_sound_create(context, data).await,
+        DispatchEvent::GuildSoundboardSoundUpdate(data) => handler.guild_soundboard_sound_update(context, data).await,
+        DispatchEvent::GuildSoundboardSoundDelete(data) => handler.guild_soundboard_sound_delete(context, data).await,
+        DispatchEvent::GuildSoundboardSoundsUpdate(data) => handler.guild_soundboard_sounds_update(context, data).await,
+        DispatchEvent::SoundboardSounds(data) => handler.soundboard_sounds(context, data).await,
+        DispatchEvent::IntegrationCreate(data) => handler.integration_create(context, data).await,
+        DispatchEvent::IntegrationUpdate(data) => handler.integration_update(context, data).await,
+        DispatchEvent::IntegrationDelete(data) => handler.integration_delete(context, data).await,
+        DispatchEvent::InteractionCreate(data) => handler.interaction_create(context, *data).await,
+        DispatchEvent::InviteCreate(data) => handler.invite_create(context, data).await,
+        DispatchEvent::InviteDelete(data) => handler.invite_delete(context, data).await,
+        DispatchEvent::MessageCreate(data) => handler.message_create(context, *data).await,
+        DispatchEvent::MessageUpdate(data) => handler.message_update(context, *data).await,
+        DispatchEvent::MessageDelete(data) => handler.message_delete(context, data).await,
+        DispatchEvent::MessageDeleteBulk(data) => handler.message_delete_bulk(context, data).await,
+        DispatchEvent::MessageReactionAdd(data) => handler.message_reaction_add(context, data).await,
+        DispatchEvent::MessageReactionRemove(data) => handler.message_reaction_remove(context, data).await,
+        DispatchEvent::MessageReactionRemoveAll(data) => handler.message_reaction_remove_all(context, data).await,
+        DispatchEvent::MessageReactionRemoveEmoji(data) => handler.message_reaction_remove_emoji(context, data).await,
+        DispatchEvent::MessagePollVoteAdd(data) => handler.message_poll_vote_add(context, data).await,
+        DispatchEvent::MessagePollVoteRemove(data) => handler.message_poll_vote_remove(context, data).await,
+        DispatchEvent::PresenceUpdate(data) => handler.presence_update(context, data).await,
+        DispatchEvent::StageInstanceCreate(data) => handler.stage_instance_create(context, data).await,
+        DispatchEvent::StageInstanceUpdate(data) => handler.stage_instance_update(context, data).await,
+        DispatchEvent::StageInstanceDelete(data) => handler.stage_instance_delete(context, data).await,
+        DispatchEvent::SubscriptionCreate(data) => handler.subscription_create(context, data).await,
+        DispatchEvent::SubscriptionUpdate(data) => handler.subscription_update(context, data).await,
+        DispatchEvent::SubscriptionDelete(data) => handler.subscription_delete(context, data).await,
+        DispatchEvent::TypingStart(data) => handler.typing_start(context, data).await,
+        DispatchEvent::UserUpdate(data) => handler.user_update(context, data).await,
+        DispatchEvent::VoiceChannelEffectSend(data) => handler.voice_channel_effect_send(context, data).await,
+        DispatchEvent::VoiceStateUpdate(data) => handler.voice_state_update(context, data).await,
+        DispatchEvent::VoiceServerUpdate(data) => handler.voice_server_update(context, data).await,
+        DispatchEvent::WebhooksUpdate(data) => handler.webhooks_update(context, data).await,
+        DispatchEvent::Unknown(raw) => handler.unknown_event(context, raw).await,
+    }
+}
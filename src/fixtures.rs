@@ -0,0 +1,77 @@
+//! Minimal payloads of Discord resources shared by the unit tests, containing only the fields Discord always sends.
+use serde_json::{Value, json};
+
+/// A guild without any roles, owned by the user.
+pub fn guild(guild_id: u64, owner_id: u64) -> Value {
+    json!({
+        "id": guild_id.to_string(),
+        "name": "Guild",
+        "owner_id": owner_id.to_string(),
+        "afk_timeout": 300,
+        "verification_level": 0,
+        "default_message_notifications": 0,
+        "explicit_content_filter": 0,
+        "mfa_level": 0,
+        "premium_tier": 0,
+        "preferred_locale": "en-US",
+        "nsfw_level": 0,
+    })
+}
+
+/// A user which isnt a bot.
+pub fn user(user_id: u64) -> Value {
+    json!({
+        "id": user_id.to_string(),
+        "username": "user",
+        "discriminator": "0",
+    })
+}
+
+/// A member of a guild with the roles.
+pub fn member(user_id: u64, role_ids: &[u64]) -> Value {
+    json!({
+        "user": user(user_id),
+        "roles": role_ids.iter().map(u64::to_string).collect::<Vec<_>>(),
+        "joined_at": "2022-08-23T18:24:09.123000+00:00",
+    })
+}
+
+/// A text channel within a guild.
+pub fn channel(channel_id: u64, guild_id: u64) -> Value {
+    json!({
+        "id": channel_id.to_string(),
+        "type": 0,
+        "guild_id": guild_id.to_string(),
+    })
+}
+
+/// The data of a GUILD_CREATE event for an available guild, containing the channels & members.
+pub fn guild_create(guild_id: u64, channels: Vec<Value>, members: Vec<Value>) -> Value {
+    let mut data = guild(guild_id, 1);
+    let fields = data.as_object_mut().unwrap();
+    fields.insert("joined_at".to_string(), json!("2022-08-23T18:24:09.123000+00:00"));
+    fields.insert("member_count".to_string(), json!(members.len()));
+    fields.insert("channels".to_string(), Value::Array(channels));
+    fields.insert("members".to_string(), Value::Array(members));
+    data
+}
+
+/// A default message sent within a channel.
+pub fn message(message_id: u64, channel_id: u64) -> Value {
+    json!({
+        "id": message_id.to_string(),
+        "channel_id": channel_id.to_string(),
+        "author": user(1),
+        "content": "content",
+        "timestamp": "2022-08-23T18:24:09.123000+00:00",
+        "tts": false,
+        "mention_everyone": false,
+        "mentions": [],
+        "mention_roles": [],
+        "attachments": [],
+        "embeds": [],
+        "pinned": false,
+        "type": 0,
+    })
+}
+
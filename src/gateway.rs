@@ -1,15 +1,27 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 use std::sync::atomic::{AtomicU32, Ordering};
 
-use futures_util::StreamExt;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 
 use tokio::sync::mpsc::{Sender as GatewaySinkSender};
 use tokio::sync::broadcast::{Sender as GatewayStreamSender};
 use tokio::time::*;
-use crate::gateway_structs::{Payload, Identify};
-use crate::websocket::ReadSplitStream;
+use crate::gateway_structs::{Payload, Identify, Resume, Hello, Ready, GuildCreate, ChannelPinsUpdate, GuildBan, GuildMemberAdd, GuildMemberRemove, GuildMemberUpdate, GuildRoleUpdate, GuildRoleDelete, MessageDelete, MessageDeleteBulk};
+use crate::gateway_structs::{AutoModerationActionExecution, ThreadListSync, ThreadMemberUpdate, ThreadMembersUpdate, GuildAuditLogEntryCreate, GuildEmojisUpdate, GuildStickersUpdate, GuildIntegrationsUpdate, GuildMembersChunk, GuildScheduledEventUser, GuildSoundboardSoundDelete, GuildSoundboardSounds, IntegrationUpdate, IntegrationDelete, InviteCreate, InviteDelete, MessageReactionAdd, MessageReactionRemove, MessageReactionRemoveAll, MessageReactionRemoveEmoji, MessagePollVote, TypingStart, VoiceChannelEffectSend, VoiceServerUpdate, WebhooksUpdate};
+use crate::models::auto_moderation::AutoModerationRule;
+use crate::models::channel::Channel;
+use crate::models::command::GuildCommandPermissions;
+use crate::models::guild::{Guild, UnavailableGuild};
+use crate::models::interaction::Interaction;
+use crate::models::message::Message;
+use crate::models::monetization::{Entitlement, Subscription};
+use crate::models::presence::Presence;
+use crate::models::scheduled_event::GuildScheduledEvent;
+use crate::models::user::User;
+use crate::models::voice::{SoundboardSound, StageInstance, VoiceState};
+use crate::bot::Intents;
 
 #[derive(Clone)]
 /// Contains information on a connection to the discord gateway.
@@ -42,11 +54,47 @@ pub struct GatewayConnectionIdentifier {
     pub shard_total: u32,
 
     /// The interval (in milliseconds) the client should heartbeat with
-    pub heartbeat_interval: u32,
+    /// This is updated from the Hello payload every time the shard connects.
+    pub heartbeat_interval: Arc<AtomicU32>,
     
     /// Number which is the last sequence number recieved from discords Gateway
-    pub sequence_identifier: Arc<AtomicU32>
+    /// Utilised for heartbeating & resuming the session after a disconnect.
+    pub sequence_identifier: Arc<AtomicU32>,
 
+    /// Tracks sent heartbeats & their acknowledgements, utilised for measuring latency & detecting zombied connections.
+    pub heartbeat_tracker: Arc<Mutex<HeartbeatTracker>>,
+
+}
+
+#[derive(Debug, Default)]
+/// Information about the heartbeats sent through the current connection of a shard.
+pub struct HeartbeatTracker {
+
+    /// When the last heartbeat was sent through the connection.
+    pub last_sent: Option<Instant>,
+
+    /// Whether a heartbeat has been sent which hasnt been acknowledged yet.
+    pub awaiting_ack: bool,
+
+    /// The time between the last acknowledged heartbeat being sent & its acknowledgement being recieved.
+    pub latency: Option<Duration>,
+}
+
+impl HeartbeatTracker {
+
+    /// Records that a heartbeat was just sent & is awaiting acknowledgement.
+    pub fn record_sent(&mut self) {
+        self.last_sent = Some(Instant::now());
+        self.awaiting_ack = true;
+    }
+
+    /// Records that a heartbeat was acknowledged, updating the latency from when it was sent.
+    pub fn record_ack(&mut self) {
+        if let Some(last_sent) = self.last_sent {
+            self.latency = Some(last_sent.elapsed());
+        }
+        self.awaiting_ack = false;
+    }
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -54,19 +102,605 @@ pub struct GatewayConnectionIdentifier {
 pub enum GatewayCommand {
     Heartbeat(Payload<u32>),
     Identify(Payload<Identify>),
+    Resume(Payload<Resume>),
 }
 
-#[derive(Clone)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// [Close codes sent by Discords gateway when it closes a connection.][https://discord.com/developers/docs/topics/opcodes-and-status-codes#gateway-gateway-close-event-codes]
+pub enum GatewayCloseCode {
+
+    /// 4000: Discord is unsure what went wrong.
+    UnknownError,
+
+    /// 4001: An invalid opcode or invalid payload for an opcode was sent.
+    UnknownOpcode,
+
+    /// 4002: An invalid payload was sent.
+    DecodeError,
+
+    /// 4003: A payload was sent prior to identifying.
+    NotAuthenticated,
+
+    /// 4004: The token sent with the Identify payload was incorrect.
+    AuthenticationFailed,
+
+    /// 4005: More than one Identify payload was sent.
+    AlreadyAuthenticated,
+
+    /// 4007: The sequence sent when resuming was invalid.
+    InvalidSequence,
+
+    /// 4008: Payloads were sent too quickly.
+    RateLimited,
+
+    /// 4009: The session timed out.
+    SessionTimedOut,
+
+    /// 4010: An invalid shard was sent when identifying.
+    InvalidShard,
+
+    /// 4011: The session would have handled too many guilds, more shards are required.
+    ShardingRequired,
+
+    /// 4012: An invalid version of the gateway was utilised.
+    InvalidApiVersion,
+
+    /// 4013: An invalid intent was sent.
+    InvalidIntents,
+
+    /// 4014: An intent which hasnt been enabled or approved for the bot was sent.
+    DisallowedIntents,
+
+    /// Any other close code which isnt specific to Discord, such as 1000 or 1006.
+    Other(u16),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// What a shard should do after its connection was closed with a [`GatewayCloseCode`].
+pub enum CloseCodeAction {
+
+    /// Reconnect & resume the existing session.
+    Resume,
+
+    /// Reconnect & identify with a new session.
+    Reidentify,
+
+    /// The shard is unable to continue & must be stopped.
+    Fatal,
+}
+
+impl From<u16> for GatewayCloseCode {
+    fn from(code: u16) -> Self {
+        match code {
+            4000 => Self::UnknownError,
+            4001 => Self::UnknownOpcode,
+            4002 => Self::DecodeError,
+            4003 => Self::NotAuthenticated,
+            4004 => Self::AuthenticationFailed,
+            4005 => Self::AlreadyAuthenticated,
+            4007 => Self::InvalidSequence,
+            4008 => Self::RateLimited,
+            4009 => Self::SessionTimedOut,
+            4010 => Self::InvalidShard,
+            4011 => Self::ShardingRequired,
+            4012 => Self::InvalidApiVersion,
+            4013 => Self::InvalidIntents,
+            4014 => Self::DisallowedIntents,
+            code => Self::Other(code),
+        }
+    }
+}
+
+impl GatewayCloseCode {
+
+    /// The numeric value of this close code.
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::UnknownError => 4000,
+            Self::UnknownOpcode => 4001,
+            Self::DecodeError => 4002,
+            Self::NotAuthenticated => 4003,
+            Self::AuthenticationFailed => 4004,
+            Self::AlreadyAuthenticated => 4005,
+            Self::InvalidSequence => 4007,
+            Self::RateLimited => 4008,
+            Self::SessionTimedOut => 4009,
+            Self::InvalidShard => 4010,
+            Self::ShardingRequired => 4011,
+            Self::InvalidApiVersion => 4012,
+            Self::InvalidIntents => 4013,
+            Self::DisallowedIntents => 4014,
+            Self::Other(code) => *code,
+        }
+    }
+
+    /// Classifies this close code by what the shard should do after recieving it.
+    pub fn action(&self) -> CloseCodeAction {
+        match self {
+            Self::NotAuthenticated
+            | Self::InvalidSequence
+            | Self::SessionTimedOut => CloseCodeAction::Reidentify,
+
+            Self::AuthenticationFailed
+            | Self::InvalidShard
+            | Self::ShardingRequired
+            | Self::InvalidApiVersion
+            | Self::InvalidIntents
+            | Self::DisallowedIntents => CloseCodeAction::Fatal,
+
+            _ => CloseCodeAction::Resume,
+        }
+    }
+}
+
+impl std::fmt::Display for GatewayCloseCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Self::UnknownError => "unknown error",
+            Self::UnknownOpcode => "unknown opcode",
+            Self::DecodeError => "decode error",
+            Self::NotAuthenticated => "not authenticated",
+            Self::AuthenticationFailed => "authentication failed, the token is invalid",
+            Self::AlreadyAuthenticated => "already authenticated",
+            Self::InvalidSequence => "invalid sequence",
+            Self::RateLimited => "rate limited",
+            Self::SessionTimedOut => "session timed out",
+            Self::InvalidShard => "invalid shard",
+            Self::ShardingRequired => "sharding required",
+            Self::InvalidApiVersion => "invalid api version",
+            Self::InvalidIntents => "invalid intents",
+            Self::DisallowedIntents => "disallowed intents, privileged intents must be enabled in the developer portal",
+            Self::Other(_) => "closed",
+        };
+        write!(f, "Discord Gateway closed the connection with {}: {description}", self.code())
+    }
+}
+
+impl std::error::Error for GatewayCloseCode {}
+
+#[derive(Clone, Debug)]
 /// Responses from a [`Gateway`] channel.
+/// Each variant represents a [payload recieved from Discords gateway][https://discord.com/developers/docs/topics/opcodes-and-status-codes#gateway-gateway-opcodes] decoded by its opcode.
 pub enum GatewayEvent {
 
+    /// Opcode 0: An event was dispatched, decoded by its event name.
+    /// The sequence number of the payload is stored within the [`GatewayConnectionIdentifier`] before the event is sent.
+    Dispatch(Box<DispatchEvent>),
+
+    /// Opcode 1: Discord requested a heartbeat to be sent immediately.
+    Heartbeat,
+
+    /// Opcode 7: Discord requested the client to reconnect and resume.
+    Reconnect,
+
+    /// Opcode 9: The session has been invalidated, the inner value is whether the session may be resumed.
+    InvalidSession(bool),
+
+    /// Opcode 10: Sent on connection, contains the heartbeat interval to use.
+    Hello(Hello),
+
+    /// Opcode 11: Discord acknowledged a heartbeat which was sent.
+    HeartbeatAck,
+
+    /// A payload with an opcode which isnt recognised by the library.
+    Unknown(Payload<Value>),
+}
+
+/// Creates the [`DispatchEvent`] & [`EventType`] enums from a single table of the events & their event names.
+/// Each event name is only written once, so an event cant be decoded without also being named & declarable by handlers.
+macro_rules! dispatch_events {
+    (
+        $(
+            $(#[$meta:meta])*
+            $name:literal => $variant:ident $(($data:ty))?,
+        )*
+    ) => {
+        #[derive(Clone, Debug)]
+        /// [Events dispatched by Discords gateway][https://discord.com/developers/docs/topics/gateway-events#receive-events] decoded by the event name of their payload.
+        pub enum DispatchEvent {
+            $(
+                $(#[$meta])*
+                $variant $(($data))?,
+            )*
+
+            /// An event which isnt recognised by the library or which failed to be decoded into its typed variant.
+            /// Kept in its raw form so newly added events from Discord never cause a shard to fail.
+            Unknown(RawDispatch),
+        }
+
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        /// The type of a [`DispatchEvent`] without its data, utilised for declaring which events an [`EventHandler`][crate::event_handler::EventHandler] implements.
+        /// Each variant corresponds to the [`DispatchEvent`] variant of the same name.
+        pub enum EventType {
+            $($variant,)*
+        }
+
+        impl EventType {
+
+            /// Every [`EventType`] known by the library.
+            pub const ALL: &'static [EventType] = &[$(Self::$variant,)*];
+
+            /// The event name of this [`EventType`] as sent by Discord in the payload.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+        }
+
+        impl DispatchEvent {
+
+            /// Decodes the data of a dispatch payload into a [`DispatchEvent`] using the event name of the payload.
+            /// If the event name is unknown or the data fails to decode, [`DispatchEvent::Unknown`] is returned instead.
+            pub fn from_name_and_data(event_name: String, data: Value) -> Self {
+
+                // Attempt to decode the typed events, falling back to the raw form if the data does not match.
+                let decoded = match event_name.as_str() {
+                    $($name => dispatch_events!(@decode data, $variant $(, $data)?),)*
+                    _ => return Self::Unknown(RawDispatch { event_name, data }),
+                };
+
+                match decoded {
+                    Ok(event) => event,
+                    Err(error) => {
+                        tracing::warn!("Failed to decode {event_name} dispatch event: {error}");
+                        Self::Unknown(RawDispatch { event_name, data })
+                    },
+                }
+            }
+
+            /// The event name of this [`DispatchEvent`] as sent by Discord in the payload.
+            pub fn event_name(&self) -> &str {
+                match self {
+                    $(dispatch_events!(@pattern $variant $(, $data)?) => $name,)*
+                    Self::Unknown(raw) => &raw.event_name,
+                }
+            }
+        }
+    };
+    (@decode $data_value:ident, $variant:ident, $data:ty) => {
+        <$data>::deserialize(&$data_value).map(Self::$variant)
+    };
+    (@decode $data_value:ident, $variant:ident) => {
+        Ok(Self::$variant)
+    };
+    (@pattern $variant:ident, $data:ty) => {
+        Self::$variant(_)
+    };
+    (@pattern $variant:ident) => {
+        Self::$variant
+    };
+}
+
+dispatch_events! {
+
+    /// The Identify handshake completed successfully & the session is ready.
+    "READY" => Ready(Ready),
+
+    /// A session was resumed successfully & all missed events have been replayed.
+    "RESUMED" => Resumed,
+
+    /// Application command permissions were updated
+    "APPLICATION_COMMAND_PERMISSIONS_UPDATE" => ApplicationCommandPermissionsUpdate(GuildCommandPermissions),
+
+    /// An auto moderation rule was created
+    "AUTO_MODERATION_RULE_CREATE" => AutoModerationRuleCreate(AutoModerationRule),
+
+    /// An auto moderation rule was updated
+    "AUTO_MODERATION_RULE_UPDATE" => AutoModerationRuleUpdate(AutoModerationRule),
+
+    /// An auto moderation rule was deleted
+    "AUTO_MODERATION_RULE_DELETE" => AutoModerationRuleDelete(AutoModerationRule),
+
+    /// An auto moderation rule was triggered and an action was executed
+    "AUTO_MODERATION_ACTION_EXECUTION" => AutoModerationActionExecution(AutoModerationActionExecution),
+
+    /// A new guild channel was created
+    "CHANNEL_CREATE" => ChannelCreate(Channel),
+
+    /// A channel was updated
+    "CHANNEL_UPDATE" => ChannelUpdate(Channel),
+
+    /// A channel was deleted
+    "CHANNEL_DELETE" => ChannelDelete(Channel),
+
+    /// A message was pinned or unpinned in a channel
+    "CHANNEL_PINS_UPDATE" => ChannelPinsUpdate(ChannelPinsUpdate),
+
+    /// A thread was created or the bot was added to a private thread
+    "THREAD_CREATE" => ThreadCreate(Channel),
+
+    /// A thread was updated
+    "THREAD_UPDATE" => ThreadUpdate(Channel),
+
+    /// A thread was deleted
+    "THREAD_DELETE" => ThreadDelete(Channel),
+
+    /// The bot gained access to a channel and is sent the active threads within it
+    "THREAD_LIST_SYNC" => ThreadListSync(ThreadListSync),
+
+    /// The thread member object for the bot was updated
+    "THREAD_MEMBER_UPDATE" => ThreadMemberUpdate(ThreadMemberUpdate),
+
+    /// Some users were added to or removed from a thread
+    "THREAD_MEMBERS_UPDATE" => ThreadMembersUpdate(ThreadMembersUpdate),
+
+    /// An entitlement was created
+    "ENTITLEMENT_CREATE" => EntitlementCreate(Entitlement),
+
+    /// An entitlement was updated or renewed
+    "ENTITLEMENT_UPDATE" => EntitlementUpdate(Entitlement),
+
+    /// An entitlement was deleted
+    "ENTITLEMENT_DELETE" => EntitlementDelete(Entitlement),
+
+    /// A guild became available, the bot joined a guild or a guild is lazily loaded on connect
+    "GUILD_CREATE" => GuildCreate(GuildCreate),
+
+    /// A guild was updated
+    "GUILD_UPDATE" => GuildUpdate(Box<Guild>),
+
+    /// A guild became unavailable or the bot was removed from a guild
+    "GUILD_DELETE" => GuildDelete(UnavailableGuild),
+
+    /// An entry was added to a guilds audit log
+    "GUILD_AUDIT_LOG_ENTRY_CREATE" => GuildAuditLogEntryCreate(GuildAuditLogEntryCreate),
+
+    /// A user was banned from a guild
+    "GUILD_BAN_ADD" => GuildBanAdd(GuildBan),
+
+    /// A user was unbanned from a guild
+    "GUILD_BAN_REMOVE" => GuildBanRemove(GuildBan),
+
+    /// The emojis of a guild were updated
+    "GUILD_EMOJIS_UPDATE" => GuildEmojisUpdate(GuildEmojisUpdate),
+
+    /// The stickers of a guild were updated
+    "GUILD_STICKERS_UPDATE" => GuildStickersUpdate(GuildStickersUpdate),
+
+    /// The integrations of a guild were updated
+    "GUILD_INTEGRATIONS_UPDATE" => GuildIntegrationsUpdate(GuildIntegrationsUpdate),
+
+    /// A new user joined a guild
+    "GUILD_MEMBER_ADD" => GuildMemberAdd(GuildMemberAdd),
+
+    /// A user was removed from a guild
+    "GUILD_MEMBER_REMOVE" => GuildMemberRemove(GuildMemberRemove),
+
+    /// A guild member was updated
+    "GUILD_MEMBER_UPDATE" => GuildMemberUpdate(GuildMemberUpdate),
+
+    /// A chunk of guild members in response to a Request Guild Members command
+    "GUILD_MEMBERS_CHUNK" => GuildMembersChunk(GuildMembersChunk),
+
+    /// A guild role was created
+    "GUILD_ROLE_CREATE" => GuildRoleCreate(GuildRoleUpdate),
+
+    /// A guild role was updated
+    "GUILD_ROLE_UPDATE" => GuildRoleUpdate(GuildRoleUpdate),
+
+    /// A guild role was deleted
+    "GUILD_ROLE_DELETE" => GuildRoleDelete(GuildRoleDelete),
+
+    /// A guild scheduled event was created
+    "GUILD_SCHEDULED_EVENT_CREATE" => GuildScheduledEventCreate(GuildScheduledEvent),
+
+    /// A guild scheduled event was updated
+    "GUILD_SCHEDULED_EVENT_UPDATE" => GuildScheduledEventUpdate(GuildScheduledEvent),
+
+    /// A guild scheduled event was deleted
+    "GUILD_SCHEDULED_EVENT_DELETE" => GuildScheduledEventDelete(GuildScheduledEvent),
+
+    /// A user subscribed to a guild scheduled event
+    "GUILD_SCHEDULED_EVENT_USER_ADD" => GuildScheduledEventUserAdd(GuildScheduledEventUser),
+
+    /// A user unsubscribed from a guild scheduled event
+    "GUILD_SCHEDULED_EVENT_USER_REMOVE" => GuildScheduledEventUserRemove(GuildScheduledEventUser),
+
+    /// A guild soundboard sound was created
+    "GUILD_SOUNDBOARD_SOUND_CREATE" => GuildSoundboardSoundCreate(SoundboardSound),
+
+    /// A guild soundboard sound was updated
+    "GUILD_SOUNDBOARD_SOUND_UPDATE" => GuildSoundboardSoundUpdate(SoundboardSound),
+
+    /// A guild soundboard sound was deleted
+    "GUILD_SOUNDBOARD_SOUND_DELETE" => GuildSoundboardSoundDelete(GuildSoundboardSoundDelete),
+
+    /// The soundboard sounds of a guild were updated
+    "GUILD_SOUNDBOARD_SOUNDS_UPDATE" => GuildSoundboardSoundsUpdate(GuildSoundboardSounds),
+
+    /// The soundboard sounds of a guild in response to a Request Soundboard Sounds command
+    "SOUNDBOARD_SOUNDS" => SoundboardSounds(GuildSoundboardSounds),
+
+    /// A guild integration was created
+    "INTEGRATION_CREATE" => IntegrationCreate(IntegrationUpdate),
+
+    /// A guild integration was updated
+    "INTEGRATION_UPDATE" => IntegrationUpdate(IntegrationUpdate),
+
+    /// A guild integration was deleted
+    "INTEGRATION_DELETE" => IntegrationDelete(IntegrationDelete),
+
+    /// A user used an interaction such as an application command
+    "INTERACTION_CREATE" => InteractionCreate(Box<Interaction>),
+
+    /// An invite to a channel was created
+    "INVITE_CREATE" => InviteCreate(InviteCreate),
+
+    /// An invite to a channel was deleted
+    "INVITE_DELETE" => InviteDelete(InviteDelete),
+
+    /// A message was created
+    "MESSAGE_CREATE" => MessageCreate(Box<Message>),
+
+    /// A message was edited
+    "MESSAGE_UPDATE" => MessageUpdate(Box<Message>),
+
+    /// A message was deleted
+    "MESSAGE_DELETE" => MessageDelete(MessageDelete),
+
+    /// Multiple messages were deleted at once
+    "MESSAGE_DELETE_BULK" => MessageDeleteBulk(MessageDeleteBulk),
+
+    /// A user reacted to a message
+    "MESSAGE_REACTION_ADD" => MessageReactionAdd(MessageReactionAdd),
+
+    /// A user removed a reaction from a message
+    "MESSAGE_REACTION_REMOVE" => MessageReactionRemove(MessageReactionRemove),
+
+    /// All reactions were explicitly removed from a message
+    "MESSAGE_REACTION_REMOVE_ALL" => MessageReactionRemoveAll(MessageReactionRemoveAll),
+
+    /// All reactions for a given emoji were explicitly removed from a message
+    "MESSAGE_REACTION_REMOVE_EMOJI" => MessageReactionRemoveEmoji(MessageReactionRemoveEmoji),
+
+    /// A user voted on a poll
+    "MESSAGE_POLL_VOTE_ADD" => MessagePollVoteAdd(MessagePollVote),
+
+    /// A user removed their vote on a poll
+    "MESSAGE_POLL_VOTE_REMOVE" => MessagePollVoteRemove(MessagePollVote),
+
+    /// A users presence or info was updated
+    "PRESENCE_UPDATE" => PresenceUpdate(Presence),
+
+    /// A stage instance was created
+    "STAGE_INSTANCE_CREATE" => StageInstanceCreate(StageInstance),
+
+    /// A stage instance was updated
+    "STAGE_INSTANCE_UPDATE" => StageInstanceUpdate(StageInstance),
+
+    /// A stage instance was deleted or closed
+    "STAGE_INSTANCE_DELETE" => StageInstanceDelete(StageInstance),
+
+    /// A premium subscription was created
+    "SUBSCRIPTION_CREATE" => SubscriptionCreate(Subscription),
+
+    /// A premium subscription was updated
+    "SUBSCRIPTION_UPDATE" => SubscriptionUpdate(Subscription),
+
+    /// A premium subscription was deleted
+    "SUBSCRIPTION_DELETE" => SubscriptionDelete(Subscription),
+
+    /// A user started typing in a channel
+    "TYPING_START" => TypingStart(TypingStart),
+
+    /// Properties about the user of the bot changed
+    "USER_UPDATE" => UserUpdate(User),
+
+    /// Someone sent an effect in a voice channel the bot is connected to
+    "VOICE_CHANNEL_EFFECT_SEND" => VoiceChannelEffectSend(VoiceChannelEffectSend),
+
+    /// Someone joined, left or moved voice channels
+    "VOICE_STATE_UPDATE" => VoiceStateUpdate(VoiceState),
+
+    /// The voice server of a guild was updated
+    "VOICE_SERVER_UPDATE" => VoiceServerUpdate(VoiceServerUpdate),
+
+    /// A guild channel webhook was created, updated or deleted
+    "WEBHOOKS_UPDATE" => WebhooksUpdate(WebhooksUpdate),
 }
+
+#[derive(Clone, Debug)]
+/// A dispatched event in its raw form, containing the event name & the data of the payload.
+pub struct RawDispatch {
+
+    /// The event name of the payload
+    pub event_name: String,
+
+    /// The undecoded data of the payload
+    pub data: Value,
+}
+
+impl GatewayEvent {
+
+    /// Decodes a [`Payload`] recieved from Discords gateway into a [`GatewayEvent`] depending on its opcode.
+    pub fn from_payload(payload: Payload<Value>) -> Self {
+        match payload.opcode {
+            0 => match payload.event_name {
+                Some(event_name) => Self::Dispatch(Box::new(DispatchEvent::from_name_and_data(event_name, payload.data))),
+                None => Self::Unknown(payload),
+            },
+            1 => Self::Heartbeat,
+            7 => Self::Reconnect,
+            9 => Self::InvalidSession(payload.data.as_bool().unwrap_or(false)),
+            10 => match Hello::deserialize(&payload.data) {
+                Ok(hello) => Self::Hello(hello),
+                Err(_) => Self::Unknown(payload),
+            },
+            11 => Self::HeartbeatAck,
+            _ => Self::Unknown(payload),
+        }
+    }
+}
+
+impl EventType {
+
+    /// The [`Intents`] which this event is sent for, the event is recieved when any of them are enabled.
+    /// Empty for events which are always sent regardless of the intents, such as READY & INTERACTION_CREATE.
+    pub fn intents(self) -> Intents {
+        match self {
+            Self::AutoModerationRuleCreate | Self::AutoModerationRuleUpdate | Self::AutoModerationRuleDelete => Intents::AUTO_MODERATION_CONFIGURATION,
+            Self::AutoModerationActionExecution => Intents::AUTO_MODERATION_EXECUTION,
+            Self::ChannelCreate
+            | Self::ChannelUpdate
+            | Self::ChannelDelete
+            | Self::ThreadCreate
+            | Self::ThreadUpdate
+            | Self::ThreadDelete
+            | Self::ThreadListSync
+            | Self::ThreadMemberUpdate
+            | Self::GuildCreate
+            | Self::GuildUpdate
+            | Self::GuildDelete
+            | Self::GuildRoleCreate
+            | Self::GuildRoleUpdate
+            | Self::GuildRoleDelete
+            | Self::StageInstanceCreate
+            | Self::StageInstanceUpdate
+            | Self::StageInstanceDelete => Intents::GUILDS,
+            Self::ChannelPinsUpdate => Intents::GUILDS | Intents::DIRECT_MESSAGES,
+            Self::ThreadMembersUpdate => Intents::GUILDS | Intents::GUILD_MEMBERS,
+            Self::GuildAuditLogEntryCreate | Self::GuildBanAdd | Self::GuildBanRemove => Intents::GUILD_MODERATION,
+            Self::GuildEmojisUpdate
+            | Self::GuildStickersUpdate
+            | Self::GuildSoundboardSoundCreate
+            | Self::GuildSoundboardSoundUpdate
+            | Self::GuildSoundboardSoundDelete
+            | Self::GuildSoundboardSoundsUpdate => Intents::GUILD_EXPRESSIONS,
+            Self::GuildIntegrationsUpdate
+            | Self::IntegrationCreate
+            | Self::IntegrationUpdate
+            | Self::IntegrationDelete => Intents::GUILD_INTEGRATIONS,
+            Self::GuildMemberAdd | Self::GuildMemberRemove | Self::GuildMemberUpdate => Intents::GUILD_MEMBERS,
+            Self::GuildScheduledEventCreate
+            | Self::GuildScheduledEventUpdate
+            | Self::GuildScheduledEventDelete
+            | Self::GuildScheduledEventUserAdd
+            | Self::GuildScheduledEventUserRemove => Intents::GUILD_SCHEDULED_EVENTS,
+            Self::InviteCreate | Self::InviteDelete => Intents::GUILD_INVITES,
+            Self::MessageCreate | Self::MessageUpdate | Self::MessageDelete => Intents::GUILD_MESSAGES | Intents::DIRECT_MESSAGES,
+            Self::MessageDeleteBulk => Intents::GUILD_MESSAGES,
+            Self::MessageReactionAdd
+            | Self::MessageReactionRemove
+            | Self::MessageReactionRemoveAll
+            | Self::MessageReactionRemoveEmoji => Intents::GUILD_MESSAGE_REACTIONS | Intents::DIRECT_MESSAGE_REACTIONS,
+            Self::MessagePollVoteAdd | Self::MessagePollVoteRemove => Intents::GUILD_MESSAGE_POLLS | Intents::DIRECT_MESSAGE_POLLS,
+            Self::PresenceUpdate => Intents::GUILD_PRESENCES,
+            Self::TypingStart => Intents::GUILD_MESSAGE_TYPING | Intents::DIRECT_MESSAGE_TYPING,
+            Self::VoiceChannelEffectSend | Self::VoiceStateUpdate => Intents::GUILD_VOICE_STATES,
+            Self::WebhooksUpdate => Intents::GUILD_WEBHOOKS,
+            _ => Intents::empty(),
+        }
+    }
+}
+
   
 impl Gateway {
 
-    ///Send heartbeats through this current shard to keep it alive.
+    /// Send heartbeats through this current shard to keep it alive.
+    /// Returns if a heartbeat was not acknowledged before the next one was due, as the connection has zombied & should be resumed.
     pub async fn heartbeat(self) {
-        let heartbeat_interval = Duration::from_millis(self.connection_id.heartbeat_interval as u64);
+        let heartbeat_interval = Duration::from_millis(self.connection_id.heartbeat_interval.load(Ordering::Acquire) as u64);
 
         // First heartbeat happens after heartbeat_interval * jitter
         // Jitter is a random value between 0 and 1.
@@ -77,24 +711,214 @@ impl Gateway {
 
         loop {
 
-            // Create the payload
-            let previous_sequence_number = self.connection_id.sequence_identifier.load(Ordering::Acquire);
-            let heartbeat_payload = Payload::new(1, previous_sequence_number);
+            // If the previous heartbeat was never acknowledged the connection is no longer recieving anything.
+            if self.heartbeat_tracker().awaiting_ack {
+                tracing::warn!("Shard {} did not recieve a heartbeat acknowledgement, connection has zombied", self.connection_id.shard_id);
+                return
+            }
 
             // Send it
-            self.gateway_sink_sender.send(GatewayCommand::Heartbeat(heartbeat_payload)).await
-                .expect("Unable to send Heartbeat Payload through sink channel!");
+            self.send_heartbeat().await;
 
             // Wait for next heartbeat
             sleep(heartbeat_interval).await;
         }
-    } 
+    }
+
+    /// Sends a heartbeat through this shard immediately & records it as awaiting acknowledgement.
+    pub async fn send_heartbeat(&self) {
+
+        // Create the payload
+        let previous_sequence_number = self.connection_id.sequence_identifier.load(Ordering::Acquire);
+        let heartbeat_payload = Payload::new(1, previous_sequence_number);
+
+        self.heartbeat_tracker().record_sent();
+
+        // Send it, this only fails if the shard has been stopped so there is nothing to keep alive.
+        let _ = self.gateway_sink_sender.send(GatewayCommand::Heartbeat(heartbeat_payload)).await;
+    }
+
+    /// The time it took for the last acknowledged heartbeat of this shard to be acknowledged.
+    pub fn latency(&self) -> Option<Duration> {
+        self.heartbeat_tracker().latency
+    }
+
+    /// Locks the [`HeartbeatTracker`] of this shard, the lock is never held across an await so a poisoned lock can still be utilised.
+    pub fn heartbeat_tracker(&self) -> MutexGuard<'_, HeartbeatTracker> {
+        self.connection_id.heartbeat_tracker.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::sync::atomic::AtomicU32;
+
+    use serde_json::{Value, json};
+    use tokio::sync::{broadcast, mpsc};
+    use tokio::time::{Duration, sleep, timeout};
+
+    use crate::gateway_structs::Payload;
+    use super::*;
+
+    fn gateway(heartbeat_interval: u32) -> (Gateway, mpsc::Receiver<GatewayCommand>) {
+        let (gateway_sink_sender, gateway_sink_reciever) = mpsc::channel(16);
+        let (gateway_stream_sender, _) = broadcast::channel(1);
+        let gateway = Gateway {
+            gateway_sink_sender,
+            gateway_stream_sender,
+            connection_id: GatewayConnectionIdentifier {
+                shard_id: 0,
+                shard_total: 1,
+                heartbeat_interval: Arc::new(AtomicU32::new(heartbeat_interval)),
+                sequence_identifier: Arc::new(AtomicU32::new(0)),
+                heartbeat_tracker: Arc::new(Mutex::new(HeartbeatTracker::default())),
+            },
+        };
+        (gateway, gateway_sink_reciever)
+    }
+
+    fn payload(opcode: u32, data: Value, event_name: Option<&str>) -> Payload<Value> {
+        Payload { opcode, data, sequence_number: None, event_name: event_name.map(str::to_string) }
+    }
+
+    #[test]
+    fn payloads_are_decoded_by_opcode() {
+        assert!(matches!(GatewayEvent::from_payload(payload(1, Value::Null, None)), GatewayEvent::Heartbeat));
+        assert!(matches!(GatewayEvent::from_payload(payload(7, Value::Null, None)), GatewayEvent::Reconnect));
+        assert!(matches!(GatewayEvent::from_payload(payload(9, json!(true), None)), GatewayEvent::InvalidSession(true)));
+        assert!(matches!(GatewayEvent::from_payload(payload(9, json!(false), None)), GatewayEvent::InvalidSession(false)));
+        assert!(matches!(GatewayEvent::from_payload(payload(10, json!({ "heartbeat_interval": 41250 }), None)), GatewayEvent::Hello(hello) if hello.heartbeat_interval == 41250));
+        assert!(matches!(GatewayEvent::from_payload(payload(11, Value::Null, None)), GatewayEvent::HeartbeatAck));
+        assert!(matches!(GatewayEvent::from_payload(payload(12, Value::Null, None)), GatewayEvent::Unknown(_)));
+    }
 
-    pub async fn recieve_gateway_events(self, mut read_stream: ReadSplitStream) {
+    #[test]
+    fn dispatch_events_are_decoded_by_event_name() {
+        let event = DispatchEvent::from_name_and_data("MESSAGE_DELETE".to_string(), json!({ "id": "30", "channel_id": "20" }));
+        assert!(matches!(&event, DispatchEvent::MessageDelete(delete) if delete.guild_id.is_none()));
+        assert_eq!(event.event_name(), "MESSAGE_DELETE");
 
-        // Recieve next payload
-        while let payload = read_stream.next().await {
-            println!("{:?}", payload);
+        // Data which doesnt match the event is kept raw rather than failing the shard.
+        let event = DispatchEvent::from_name_and_data("MESSAGE_DELETE".to_string(), json!({ "id": 30 }));
+        assert!(matches!(&event, DispatchEvent::Unknown(raw) if raw.event_name == "MESSAGE_DELETE"));
+        assert_eq!(event.event_name(), "MESSAGE_DELETE");
+    }
+
+    #[test]
+    fn event_names_match_their_event_type() {
+        for event_type in EventType::ALL {
+
+            // Null fails to decode for every event with data, which keeps the event name within the raw form instead.
+            let event = DispatchEvent::from_name_and_data(event_type.name().to_string(), Value::Null);
+            assert_eq!(event.event_name(), event_type.name());
+        }
+        assert_eq!(DispatchEvent::from_name_and_data("RESUMED".to_string(), Value::Null).event_name(), "RESUMED");
+    }
+
+    #[test]
+    fn unknown_dispatch_events_are_kept_raw() {
+        match GatewayEvent::from_payload(payload(0, json!({ "field": 1 }), Some("NEW_EVENT"))) {
+            GatewayEvent::Dispatch(dispatch) => match *dispatch {
+                DispatchEvent::Unknown(raw) => {
+                    assert_eq!(raw.event_name, "NEW_EVENT");
+                    assert_eq!(raw.data, json!({ "field": 1 }));
+                },
+                event => panic!("expected an unknown event, got {event:?}"),
+            },
+            event => panic!("expected a dispatch, got {event:?}"),
+        }
+    }
+
+    #[test]
+    fn close_codes_are_classified() {
+        let expected = [
+            (4000, CloseCodeAction::Resume),
+            (4001, CloseCodeAction::Resume),
+            (4002, CloseCodeAction::Resume),
+            (4003, CloseCodeAction::Reidentify),
+            (4004, CloseCodeAction::Fatal),
+            (4005, CloseCodeAction::Resume),
+            (4006, CloseCodeAction::Resume),
+            (4007, CloseCodeAction::Reidentify),
+            (4008, CloseCodeAction::Resume),
+            (4009, CloseCodeAction::Reidentify),
+            (4010, CloseCodeAction::Fatal),
+            (4011, CloseCodeAction::Fatal),
+            (4012, CloseCodeAction::Fatal),
+            (4013, CloseCodeAction::Fatal),
+            (4014, CloseCodeAction::Fatal),
+            (4015, CloseCodeAction::Resume),
+            (1000, CloseCodeAction::Resume),
+            (1006, CloseCodeAction::Resume),
+        ];
+
+        for (code, action) in expected {
+            let close_code = GatewayCloseCode::from(code);
+            assert_eq!(close_code.code(), code);
+            assert_eq!(close_code.action(), action, "{code} was classified as {:?}", close_code.action());
+        }
+        assert_eq!(GatewayCloseCode::from(4006), GatewayCloseCode::Other(4006));
+    }
+
+    #[tokio::test]
+    async fn acknowledged_heartbeat_measures_latency() {
+        let mut tracker = HeartbeatTracker::default();
+        tracker.record_sent();
+        assert!(tracker.awaiting_ack);
+
+        sleep(Duration::from_millis(20)).await;
+        tracker.record_ack();
+        assert!(!tracker.awaiting_ack);
+        assert!(tracker.latency.unwrap() >= Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn unacknowledged_heartbeat_zombies_the_connection() {
+        let (gateway, mut reciever) = gateway(20);
+
+        // heartbeat only returns once a heartbeat went unacknowledged for a whole interval.
+        timeout(Duration::from_secs(1), gateway.clone().heartbeat()).await.expect("the connection should have zombied");
+        assert!(matches!(reciever.try_recv(), Ok(GatewayCommand::Heartbeat(_))));
+        assert!(reciever.try_recv().is_err());
+        assert!(gateway.heartbeat_tracker().awaiting_ack);
+    }
+
+    #[tokio::test]
+    async fn acknowledged_heartbeats_keep_the_connection_alive() {
+        let (gateway, mut reciever) = gateway(20);
+
+        let acknowledged = Arc::new(AtomicU32::new(0));
+
+        // The acknowledging task holds a gateway, which keeps the command channel open, so its aborted instead of drained.
+        let acknowledging_gateway = gateway.clone();
+        let acknowledging_count = acknowledged.clone();
+        let acknowledger = tokio::spawn(async move {
+            while let Some(GatewayCommand::Heartbeat(_)) = reciever.recv().await {
+                acknowledging_gateway.heartbeat_tracker().record_ack();
+                acknowledging_count.fetch_add(1, Ordering::AcqRel);
+            }
+        });
+
+        assert!(timeout(Duration::from_millis(200), gateway.clone().heartbeat()).await.is_err());
+        acknowledger.abort();
+        assert!(gateway.latency().is_some());
+        assert!(acknowledged.load(Ordering::Acquire) >= 3);
+    }
+
+    #[tokio::test]
+    async fn requested_heartbeat_is_sent_immediately() {
+        let (gateway, mut reciever) = gateway(41250);
+        gateway.connection_id.sequence_identifier.store(5, Ordering::Release);
+
+        gateway.send_heartbeat().await;
+        match reciever.try_recv() {
+            Ok(GatewayCommand::Heartbeat(payload)) => {
+                assert_eq!(payload.opcode, 1);
+                assert_eq!(payload.data, 5);
+            },
+            command => panic!("expected a heartbeat, got {command:?}"),
         }
+        assert!(gateway.heartbeat_tracker().awaiting_ack);
     }
-}
\ No newline at end of file
+}
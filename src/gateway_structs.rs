@@ -2,8 +2,21 @@
 use std::{sync::Arc};
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use tokio_tungstenite::tungstenite::Message;
-use anyhow::{Result, Context};
+use crate::error::Result;
+use crate::models::Timestamp;
+use crate::models::application::ApplicationFlags;
+use crate::models::audit_log::AuditLogEntry;
+use crate::models::auto_moderation::{AutoModerationAction, AutoModerationTriggerType};
+use crate::models::channel::{Channel, ThreadMember};
+use crate::models::guild::{Emoji, Guild, Integration, Member, MemberFlags, Role, UnavailableGuild};
+use crate::models::message::Sticker;
+use crate::models::presence::Presence;
+use crate::models::user::{AvatarDecorationData, User};
+use crate::models::voice::SoundboardSound;
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ApplicationMarker, AutoModerationRuleMarker, ChannelMarker, GuildMarker, IntegrationMarker, MessageMarker, RoleMarker, ScheduledEventMarker, SoundMarker, UserMarker};
 
 #[derive(Deserialize, Debug)]
 /// [The limits imposed on new sessions which are started.][https://discord.com/developers/docs/topics/gateway#session-start-limit-object]
@@ -73,6 +86,21 @@ pub struct Identify {
     pub intents: u32,
 }
 
+#[derive(Debug, Serialize, Clone)]
+/// [Payload used to replay missed events when a disconnected session is resumed.][https://discord.com/developers/docs/topics/gateway-events#resume]
+pub struct Resume {
+
+    /// The authentication token for this bot
+    pub token: Arc<str>,
+
+    /// The id of the session being resumed
+    pub session_id: String,
+
+    #[serde(rename = "seq")]
+    /// The last sequence number recieved from the session being resumed
+    pub sequence_number: u32,
+}
+
 #[derive(Debug, Serialize, Clone, Copy)]
 /// Connection information/properties related to the Identify handshake payload.
 pub struct IdentifyConnectionProperties {
@@ -118,8 +146,7 @@ impl<T: Serialize + Send + 'static> Payload<T> {
         tokio::task::spawn_blocking(move || -> Result<Message> {
 
             // Serialize the struct by converting it into a string & then create a Message from that.
-            Ok(Message::Text(serde_json::to_string(&self)
-                .context("Failed to Serialize payload into Message for sending.")?))
+            Ok(Message::Text(serde_json::to_string(&self)?))
 
         }).await?
     }
@@ -137,3 +164,884 @@ impl<T> Payload<T> {
         }
     }
 }
+
+
+#[derive(Deserialize, Debug, Clone)]
+/// [The Ready event dispatched by Discords gateway after a successful Identify handshake.][https://discord.com/developers/docs/topics/gateway-events#ready]
+pub struct Ready {
+
+    #[serde(rename = "v")]
+    /// The version of the api the gateway is using
+    pub api_version: u32,
+
+    /// Information about the user the bot is running as
+    pub user: User,
+
+    /// The guilds the bot is in, these are unavailable until their GuildCreate event is recieved
+    pub guilds: Vec<UnavailableGuild>,
+
+    /// The id of the session, utilised for resuming this session
+    pub session_id: String,
+
+    /// The gateway url which should be utilised when resuming this session
+    pub resume_gateway_url: String,
+
+    /// The shard information associated with this session as [shard_id, total_shards]
+    pub shard: Option<[u32; 2]>,
+
+    /// Partial information about the application of the bot, contains the id & flags
+    pub application: PartialApplication,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// The id & flags of the application of the bot, sent within [`Ready`]
+pub struct PartialApplication {
+
+    /// The id of the application
+    pub id: Id<ApplicationMarker>,
+
+    #[serde(default)]
+    /// The public flags of the application
+    pub flags: ApplicationFlags,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+/// [The GuildCreate event][https://discord.com/developers/docs/topics/gateway-events#guild-create] sent when a guild becomes available, or when the bot joins a guild.
+/// During an outage Discord sends the guild as unavailable instead.
+pub enum GuildCreate {
+
+    /// The guild alongside the extra fields only sent within this event
+    Available(Box<AvailableGuild>),
+
+    /// The guild is unavailable due to an outage
+    Unavailable(UnavailableGuild),
+}
+
+impl<'de> Deserialize<'de> for GuildCreate {
+
+    /// The variant is picked from the unavailable field, so an available guild which fails to decode is an error rather than silently becoming unavailable.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let data = Value::deserialize(deserializer)?;
+
+        let decoded = match data.get("unavailable").and_then(Value::as_bool) {
+            Some(true) => UnavailableGuild::deserialize(data).map(Self::Unavailable),
+            _ => Box::<AvailableGuild>::deserialize(data).map(Self::Available),
+        };
+        decoded.map_err(serde::de::Error::custom)
+    }
+}
+
+impl GuildCreate {
+
+    /// The id of the guild which was created.
+    pub fn id(&self) -> Id<GuildMarker> {
+        match self {
+            Self::Available(available) => available.guild.id,
+            Self::Unavailable(unavailable) => unavailable.id,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// A [`Guild`] alongside the fields only sent within a [`GuildCreate`] event.
+pub struct AvailableGuild {
+
+    #[serde(flatten)]
+    /// The guild which was created
+    pub guild: Guild,
+
+    /// When the bot joined the guild
+    pub joined_at: Timestamp,
+
+    #[serde(default)]
+    /// Whether the guild is considered large, members are only sent for large guilds when requested
+    pub large: bool,
+
+    #[serde(default)]
+    /// Whether the guild is unavailable due to an outage
+    pub unavailable: bool,
+
+    /// The total number of members in the guild
+    pub member_count: u32,
+
+    #[serde(default)]
+    /// The states of members currently in voice channels
+    pub voice_states: Vec<Value>,
+
+    #[serde(default)]
+    /// The members of the guild, for large guilds this only contains the bot & members in voice channels
+    pub members: Vec<Member>,
+
+    #[serde(default)]
+    /// The channels within the guild
+    pub channels: Vec<Channel>,
+
+    #[serde(default)]
+    /// The active threads within the guild that the bot has permission to view
+    pub threads: Vec<Channel>,
+
+    #[serde(default)]
+    /// The presences of the members of the guild, for large guilds this only contains members in voice channels
+    pub presences: Vec<Value>,
+
+    #[serde(default)]
+    /// The stage instances within the guild
+    pub stage_instances: Vec<Value>,
+
+    #[serde(default)]
+    /// The scheduled events within the guild
+    pub guild_scheduled_events: Vec<Value>,
+
+    #[serde(default)]
+    /// The soundboard sounds within the guild
+    pub soundboard_sounds: Vec<Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The ChannelPinsUpdate event][https://discord.com/developers/docs/topics/gateway-events#channel-pins-update] sent when a message is pinned or unpinned.
+pub struct ChannelPinsUpdate {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    /// The id of the channel
+    pub channel_id: Id<ChannelMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the most recent pinned message was pinned
+    pub last_pin_timestamp: Option<Timestamp>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildBanAdd & GuildBanRemove events][https://discord.com/developers/docs/topics/gateway-events#guild-ban-add] sent when a user is banned or unbanned.
+pub struct GuildBan {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The user who was banned or unbanned
+    pub user: User,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildMemberAdd event][https://discord.com/developers/docs/topics/gateway-events#guild-member-add] sent when a user joins a guild.
+pub struct GuildMemberAdd {
+
+    #[serde(flatten)]
+    /// The member which joined
+    pub member: Member,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildMemberRemove event][https://discord.com/developers/docs/topics/gateway-events#guild-member-remove] sent when a user leaves or is removed from a guild.
+pub struct GuildMemberRemove {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The user who was removed
+    pub user: User,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildMemberUpdate event][https://discord.com/developers/docs/topics/gateway-events#guild-member-update] sent when a guild member is updated.
+pub struct GuildMemberUpdate {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The ids of the roles of the member
+    pub roles: Vec<Id<RoleMarker>>,
+
+    /// The user of the member
+    pub user: User,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The nickname of the member within the guild
+    pub nick: Option<String>,
+
+    /// The guild avatar hash of the member
+    pub avatar: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The guild banner hash of the member
+    pub banner: Option<String>,
+
+    /// When the member joined the guild
+    pub joined_at: Option<Timestamp>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the member started boosting the guild
+    pub premium_since: Option<Timestamp>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the member is deafened in voice channels
+    pub deaf: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the member is muted in voice channels
+    pub mute: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the member has not yet passed the Membership Screening requirements of the guild
+    pub pending: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the timeout of the member will expire
+    pub communication_disabled_until: Option<Timestamp>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The flags of the member
+    pub flags: Option<MemberFlags>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The guild avatar decoration of the member
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildRoleCreate & GuildRoleUpdate events][https://discord.com/developers/docs/topics/gateway-events#guild-role-create] sent when a role is created or updated.
+pub struct GuildRoleUpdate {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The role which was created or updated
+    pub role: Role,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildRoleDelete event][https://discord.com/developers/docs/topics/gateway-events#guild-role-delete] sent when a role is deleted.
+pub struct GuildRoleDelete {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The id of the role which was deleted
+    pub role_id: Id<RoleMarker>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The MessageDelete event][https://discord.com/developers/docs/topics/gateway-events#message-delete] sent when a message is deleted.
+pub struct MessageDelete {
+
+    /// The id of the message
+    pub id: Id<MessageMarker>,
+
+    /// The id of the channel
+    pub channel_id: Id<ChannelMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild
+    pub guild_id: Option<Id<GuildMarker>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The MessageDeleteBulk event][https://discord.com/developers/docs/topics/gateway-events#message-delete-bulk] sent when multiple messages are deleted at once.
+pub struct MessageDeleteBulk {
+
+    /// The ids of the messages
+    pub ids: Vec<Id<MessageMarker>>,
+
+    /// The id of the channel
+    pub channel_id: Id<ChannelMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild
+    pub guild_id: Option<Id<GuildMarker>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The AutoModerationActionExecution event][https://discord.com/developers/docs/topics/gateway-events#auto-moderation-action-execution] sent when an auto moderation rule is triggered & an action was executed.
+pub struct AutoModerationActionExecution {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The action which was executed
+    pub action: AutoModerationAction,
+
+    /// The id of the rule which was triggered
+    pub rule_id: Id<AutoModerationRuleMarker>,
+
+    /// What triggered the rule
+    pub rule_trigger_type: AutoModerationTriggerType,
+
+    /// The id of the user whose content triggered the rule
+    pub user_id: Id<UserMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the channel the content was sent in
+    pub channel_id: Option<Id<ChannelMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the message, not sent if the message was blocked
+    pub message_id: Option<Id<MessageMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the alert message sent by a SendAlertMessage action
+    pub alert_system_message_id: Option<Id<MessageMarker>>,
+
+    #[serde(default)]
+    /// The content which triggered the rule, empty without the MESSAGE_CONTENT intent
+    pub content: String,
+
+    /// The keyword or regular expression which was matched
+    pub matched_keyword: Option<String>,
+
+    /// The substring of the content which was matched, None without the MESSAGE_CONTENT intent
+    pub matched_content: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The ThreadListSync event][https://discord.com/developers/docs/topics/gateway-events#thread-list-sync] sent when the bot gains access to a channel, containing its active threads.
+pub struct ThreadListSync {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The ids of the parent channels which are synced, every channel of the guild is synced if empty
+    pub channel_ids: Vec<Id<ChannelMarker>>,
+
+    /// The active threads within the channels which the bot can access
+    pub threads: Vec<Channel>,
+
+    /// The thread members of the bot within the synced threads
+    pub members: Vec<ThreadMember>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The ThreadMemberUpdate event][https://discord.com/developers/docs/topics/gateway-events#thread-member-update] sent when the thread member of the bot is updated.
+pub struct ThreadMemberUpdate {
+
+    #[serde(flatten)]
+    /// The thread member of the bot
+    pub member: ThreadMember,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The ThreadMembersUpdate event][https://discord.com/developers/docs/topics/gateway-events#thread-members-update] sent when users are added to or removed from a thread.
+pub struct ThreadMembersUpdate {
+
+    /// The id of the thread
+    pub id: Id<ChannelMarker>,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The approximate amount of members within the thread, stops counting at 50
+    pub member_count: u32,
+
+    #[serde(default)]
+    /// The users which were added to the thread
+    pub added_members: Vec<ThreadMember>,
+
+    #[serde(default)]
+    /// The ids of the users which were removed from the thread
+    pub removed_member_ids: Vec<Id<UserMarker>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildAuditLogEntryCreate event][https://discord.com/developers/docs/topics/gateway-events#guild-audit-log-entry-create] sent when an entry is added to the audit log of a guild.
+pub struct GuildAuditLogEntryCreate {
+
+    #[serde(flatten)]
+    /// The entry which was added
+    pub entry: AuditLogEntry,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildEmojisUpdate event][https://discord.com/developers/docs/topics/gateway-events#guild-emojis-update] sent when the emojis of a guild are updated.
+pub struct GuildEmojisUpdate {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// Every emoji of the guild
+    pub emojis: Vec<Emoji>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildStickersUpdate event][https://discord.com/developers/docs/topics/gateway-events#guild-stickers-update] sent when the stickers of a guild are updated.
+pub struct GuildStickersUpdate {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// Every sticker of the guild
+    pub stickers: Vec<Sticker>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildIntegrationsUpdate event][https://discord.com/developers/docs/topics/gateway-events#guild-integrations-update] sent when the integrations of a guild are updated.
+pub struct GuildIntegrationsUpdate {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildMembersChunk event][https://discord.com/developers/docs/topics/gateway-events#guild-members-chunk] sent in response to a Request Guild Members command.
+pub struct GuildMembersChunk {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The members within this chunk
+    pub members: Vec<Member>,
+
+    /// The index of this chunk, starting from 0
+    pub chunk_index: u32,
+
+    /// The total amount of chunks sent in response to the command
+    pub chunk_count: u32,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The ids passed to the command which arent members of the guild
+    pub not_found: Vec<Id<UserMarker>>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The presences of the members, if requested
+    pub presences: Vec<Presence>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The nonce passed to the command
+    pub nonce: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildScheduledEventUserAdd & GuildScheduledEventUserRemove events][https://discord.com/developers/docs/topics/gateway-events#guild-scheduled-event-user-add] sent when a user subscribes to or unsubscribes from a scheduled event.
+pub struct GuildScheduledEventUser {
+
+    /// The id of the scheduled event
+    pub guild_scheduled_event_id: Id<ScheduledEventMarker>,
+
+    /// The id of the user
+    pub user_id: Id<UserMarker>,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildSoundboardSoundDelete event][https://discord.com/developers/docs/topics/gateway-events#guild-soundboard-sound-delete] sent when a soundboard sound is deleted.
+pub struct GuildSoundboardSoundDelete {
+
+    /// The id of the sound
+    pub sound_id: Id<SoundMarker>,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The GuildSoundboardSoundsUpdate & SoundboardSounds events][https://discord.com/developers/docs/topics/gateway-events#guild-soundboard-sounds-update] sent when the soundboard sounds of a guild are updated or requested.
+pub struct GuildSoundboardSounds {
+
+    /// Every soundboard sound of the guild
+    pub soundboard_sounds: Vec<SoundboardSound>,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The IntegrationCreate & IntegrationUpdate events][https://discord.com/developers/docs/topics/gateway-events#integration-create] sent when an integration is created or updated.
+pub struct IntegrationUpdate {
+
+    #[serde(flatten)]
+    /// The integration which was created or updated
+    pub integration: Integration,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The IntegrationDelete event][https://discord.com/developers/docs/topics/gateway-events#integration-delete] sent when an integration is deleted.
+pub struct IntegrationDelete {
+
+    /// The id of the integration
+    pub id: Id<IntegrationMarker>,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the bot or OAuth2 application of the integration
+    pub application_id: Option<Id<ApplicationMarker>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The InviteCreate event][https://discord.com/developers/docs/topics/gateway-events#invite-create] sent when an invite to a channel is created.
+pub struct InviteCreate {
+
+    /// The id of the channel the invite is for
+    pub channel_id: Id<ChannelMarker>,
+
+    /// The unique code of the invite
+    pub code: String,
+
+    /// When the invite was created
+    pub created_at: Timestamp,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The user who created the invite
+    pub inviter: Option<User>,
+
+    /// How long the invite is valid for in seconds, 0 if it doesnt expire
+    pub max_age: u32,
+
+    /// How many times the invite can be used, 0 if it is unlimited
+    pub max_uses: u32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The type of target of voice channel invites, 1 for a stream & 2 for an embedded application
+    pub target_type: Option<u8>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The user whose stream is the target of the invite
+    pub target_user: Option<User>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The embedded application which is the target of the invite
+    pub target_application: Option<Value>,
+
+    /// Whether the invite only grants temporary membership
+    pub temporary: bool,
+
+    /// How many times the invite has been used, always 0
+    pub uses: u32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the invite expires
+    pub expires_at: Option<Timestamp>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The InviteDelete event][https://discord.com/developers/docs/topics/gateway-events#invite-delete] sent when an invite to a channel is deleted.
+pub struct InviteDelete {
+
+    /// The id of the channel the invite was for
+    pub channel_id: Id<ChannelMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    /// The unique code of the invite
+    pub code: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The MessageReactionAdd event][https://discord.com/developers/docs/topics/gateway-events#message-reaction-add] sent when a user reacts to a message.
+pub struct MessageReactionAdd {
+
+    /// The id of the user who reacted
+    pub user_id: Id<UserMarker>,
+
+    /// The id of the channel
+    pub channel_id: Id<ChannelMarker>,
+
+    /// The id of the message
+    pub message_id: Id<MessageMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The member who reacted, only within guilds
+    pub member: Option<Member>,
+
+    /// The partial emoji of the reaction
+    pub emoji: Emoji,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the user who sent the message
+    pub message_author_id: Option<Id<UserMarker>>,
+
+    #[serde(default)]
+    /// Whether this is a super reaction
+    pub burst: bool,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The HEX colours utilised for super reactions
+    pub burst_colors: Vec<String>,
+
+    #[serde(rename = "type", default)]
+    /// The type of the reaction, 0 for normal & 1 for super reactions
+    pub kind: u8,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The MessageReactionRemove event][https://discord.com/developers/docs/topics/gateway-events#message-reaction-remove] sent when a user removes a reaction from a message.
+pub struct MessageReactionRemove {
+
+    /// The id of the user whose reaction was removed
+    pub user_id: Id<UserMarker>,
+
+    /// The id of the channel
+    pub channel_id: Id<ChannelMarker>,
+
+    /// The id of the message
+    pub message_id: Id<MessageMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    /// The partial emoji of the reaction
+    pub emoji: Emoji,
+
+    #[serde(default)]
+    /// Whether this was a super reaction
+    pub burst: bool,
+
+    #[serde(rename = "type", default)]
+    /// The type of the reaction, 0 for normal & 1 for super reactions
+    pub kind: u8,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The MessageReactionRemoveAll event][https://discord.com/developers/docs/topics/gateway-events#message-reaction-remove-all] sent when every reaction is removed from a message.
+pub struct MessageReactionRemoveAll {
+
+    /// The id of the channel
+    pub channel_id: Id<ChannelMarker>,
+
+    /// The id of the message
+    pub message_id: Id<MessageMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild
+    pub guild_id: Option<Id<GuildMarker>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The MessageReactionRemoveEmoji event][https://discord.com/developers/docs/topics/gateway-events#message-reaction-remove-emoji] sent when every reaction of an emoji is removed from a message.
+pub struct MessageReactionRemoveEmoji {
+
+    /// The id of the channel
+    pub channel_id: Id<ChannelMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    /// The id of the message
+    pub message_id: Id<MessageMarker>,
+
+    /// The partial emoji which was removed
+    pub emoji: Emoji,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The MessagePollVoteAdd & MessagePollVoteRemove events][https://discord.com/developers/docs/topics/gateway-events#message-poll-vote-add] sent when a user votes on a poll or removes their vote.
+pub struct MessagePollVote {
+
+    /// The id of the user
+    pub user_id: Id<UserMarker>,
+
+    /// The id of the channel
+    pub channel_id: Id<ChannelMarker>,
+
+    /// The id of the message containing the poll
+    pub message_id: Id<MessageMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    /// The id of the answer, which is its position within the poll starting from 1
+    pub answer_id: u32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The TypingStart event][https://discord.com/developers/docs/topics/gateway-events#typing-start] sent when a user starts typing in a channel.
+pub struct TypingStart {
+
+    /// The id of the channel
+    pub channel_id: Id<ChannelMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    /// The id of the user
+    pub user_id: Id<UserMarker>,
+
+    /// When the user started typing, in seconds since the unix epoch
+    pub timestamp: u64,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The member who started typing, only within guilds
+    pub member: Option<Member>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The VoiceChannelEffectSend event][https://discord.com/developers/docs/topics/gateway-events#voice-channel-effect-send] sent when someone sends an effect in a voice channel the bot is connected to.
+pub struct VoiceChannelEffectSend {
+
+    /// The id of the channel
+    pub channel_id: Id<ChannelMarker>,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The id of the user who sent the effect
+    pub user_id: Id<UserMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The emoji sent, for emoji reaction & soundboard effects
+    pub emoji: Option<Emoji>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The type of the emoji animation, 0 for premium & 1 for basic animations
+    pub animation_type: Option<u8>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the emoji animation
+    pub animation_id: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the soundboard sound, for soundboard effects
+    pub sound_id: Option<Id<SoundMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The volume of the soundboard sound, between 0 & 1
+    pub sound_volume: Option<f64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The VoiceServerUpdate event][https://discord.com/developers/docs/topics/gateway-events#voice-server-update] sent when the voice server of a guild is updated, utilised for connecting to voice.
+pub struct VoiceServerUpdate {
+
+    /// The token of the voice connection
+    pub token: String,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The host of the voice server, None if it was deallocated & a new one isnt allocated yet
+    pub endpoint: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// [The WebhooksUpdate event][https://discord.com/developers/docs/topics/gateway-events#webhooks-update] sent when a webhook of a channel is created, updated or deleted.
+pub struct WebhooksUpdate {
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The id of the channel
+    pub channel_id: Id<ChannelMarker>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::fixtures;
+    use crate::gateway::DispatchEvent;
+    use crate::models::presence::Status;
+    use super::GuildCreate;
+
+    #[test]
+    fn guild_create_decodes_available_guild() {
+        let data = fixtures::guild_create(10, vec![fixtures::channel(20, 10)], vec![fixtures::member(30, &[])]);
+
+        match DispatchEvent::from_name_and_data("GUILD_CREATE".to_string(), data) {
+            DispatchEvent::GuildCreate(GuildCreate::Available(available)) => {
+                assert_eq!(available.guild.id.get(), 10);
+                assert_eq!(available.channels.len(), 1);
+                assert_eq!(available.members.len(), 1);
+            },
+            event => panic!("expected an available guild, got {event:?}"),
+        }
+    }
+
+    #[test]
+    fn guild_create_decodes_unavailable_guild() {
+        let data = json!({ "id": "10", "unavailable": true });
+
+        match DispatchEvent::from_name_and_data("GUILD_CREATE".to_string(), data) {
+            DispatchEvent::GuildCreate(GuildCreate::Unavailable(unavailable)) => assert!(unavailable.unavailable),
+            event => panic!("expected an unavailable guild, got {event:?}"),
+        }
+    }
+
+    #[test]
+    fn malformed_guild_create_is_unknown_rather_than_unavailable() {
+        let mut data = fixtures::guild_create(10, Vec::new(), Vec::new());
+        data["member_count"] = json!("not a number");
+
+        match DispatchEvent::from_name_and_data("GUILD_CREATE".to_string(), data) {
+            DispatchEvent::Unknown(raw) => assert_eq!(raw.event_name, "GUILD_CREATE"),
+            event => panic!("expected the event to be unknown, got {event:?}"),
+        }
+    }
+
+    #[test]
+    fn message_reaction_add_decodes_member_and_emoji() {
+        let data = json!({
+            "user_id": "30",
+            "channel_id": "20",
+            "message_id": "40",
+            "guild_id": "10",
+            "member": fixtures::member(30, &[]),
+            "emoji": { "id": null, "name": "🔥" },
+            "burst": false,
+            "type": 0,
+        });
+
+        match DispatchEvent::from_name_and_data("MESSAGE_REACTION_ADD".to_string(), data) {
+            DispatchEvent::MessageReactionAdd(reaction) => {
+                assert_eq!(reaction.user_id.get(), 30);
+                assert_eq!(reaction.member.and_then(|member| member.user).map(|user| user.id.get()), Some(30));
+                assert_eq!(reaction.emoji.name.as_deref(), Some("🔥"));
+            },
+            event => panic!("expected a reaction, got {event:?}"),
+        }
+    }
+
+    #[test]
+    fn guild_members_chunk_decodes_presences() {
+        let data = json!({
+            "guild_id": "10",
+            "members": [fixtures::member(30, &[]), fixtures::member(31, &[])],
+            "chunk_index": 0,
+            "chunk_count": 1,
+            "not_found": ["32"],
+            "presences": [{ "user": { "id": "30" }, "status": "dnd", "activities": [], "client_status": { "desktop": "dnd" } }],
+            "nonce": "chunk",
+        });
+
+        match DispatchEvent::from_name_and_data("GUILD_MEMBERS_CHUNK".to_string(), data) {
+            DispatchEvent::GuildMembersChunk(chunk) => {
+                assert_eq!(chunk.members.len(), 2);
+                assert_eq!(chunk.not_found.len(), 1);
+                assert_eq!(chunk.presences[0].status, Status::Dnd);
+                assert_eq!(chunk.presences[0].client_status.desktop, Some(Status::Dnd));
+                assert_eq!(chunk.nonce.as_deref(), Some("chunk"));
+            },
+            event => panic!("expected a members chunk, got {event:?}"),
+        }
+    }
+}
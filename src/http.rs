@@ -1,23 +1,176 @@
 
-use reqwest::{Client, Url, Method, Response as HttpResponse, RequestBuilder};
+use reqwest::{Client, Url, Method, Response as HttpResponse, RequestBuilder, StatusCode};
 use reqwest::header::*;
-use serde::{de};
+use serde::{de, Deserialize, Serialize};
+use serde_json::Value;
 
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 use std::sync::Arc;
 
 use tokio::sync::mpsc::{Receiver as MpscReceiver, Sender as MpscSender};
 use tokio::sync::oneshot::{Sender as OneshotSender};
 use tokio::sync::*;
-use anyhow::{Result, Context};
+use tokio::time::{Duration, sleep};
+use crate::error::{Error, Result};
+use crate::ratelimit::{RateLimiter, RateLimitRoute, RateLimitHeaders};
+use crate::retry::{RetryPolicy, RateLimitedBody};
+use crate::attachment::{AttachmentFile, multipart_form};
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ApplicationMarker, ChannelMarker, CommandMarker, GuildMarker, InteractionMarker, MessageMarker, UserMarker};
 
 /// Shortened Alias for Mpsc channel sender for a [`DiscordHttpClientRequest`]
 pub type DiscordHttpClientReqSender = MpscSender<DiscordHttpClientRequest>;
 
-pub enum DiscordApiError {
-    Unauthorized
+#[derive(Deserialize, Debug, Clone)]
+/// [An error body returned by the Discord api][https://discord.com/developers/docs/topics/opcodes-and-status-codes#json] alongside an unsuccessful status code.
+pub struct DiscordApiError {
+
+    /// The JSON error code which identifies the error, e.g: 50013 for Missing Permissions
+    pub code: u32,
+
+    /// A human readable description of the error
+    pub message: String,
+
+    #[serde(default)]
+    /// The nested validation errors for each field of the request body which was invalid.
+    /// Utilise [`DiscordApiError::field_errors`] to retrieve them with the path to each field.
+    pub errors: Option<Value>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+/// A validation error for a single field of a request body.
+pub struct FieldError {
+
+    #[serde(skip)]
+    /// The path to the invalid field, e.g: "embeds.0.title"
+    pub path: String,
+
+    /// The code which identifies the validation error, e.g: "BASE_TYPE_MAX_LENGTH"
+    pub code: String,
+
+    /// A human readable description of the validation error
+    pub message: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Commonly encountered [JSON error codes][https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes] which can be matched on.
+pub enum JsonErrorCode {
+    GeneralError,
+    UnknownChannel,
+    UnknownGuild,
+    UnknownMember,
+    UnknownMessage,
+    UnknownRole,
+    UnknownUser,
+    UnknownEmoji,
+    UnknownWebhook,
+    UnknownInteraction,
+    UnknownApplicationCommand,
+    Unauthorized,
+    InteractionAlreadyAcknowledged,
+    MissingAccess,
+    CannotEditAnotherUsersMessage,
+    CannotSendEmptyMessage,
+    CannotSendMessagesToUser,
+    MissingPermissions,
+    MessageTooOldToBulkDelete,
+    InvalidFormBody,
+    ThreadArchived,
+    ResourceOverloaded,
+
+    /// Any other JSON error code.
+    Other(u32),
+}
+
+impl From<u32> for JsonErrorCode {
+    fn from(code: u32) -> Self {
+        match code {
+            0 => Self::GeneralError,
+            10003 => Self::UnknownChannel,
+            10004 => Self::UnknownGuild,
+            10007 => Self::UnknownMember,
+            10008 => Self::UnknownMessage,
+            10011 => Self::UnknownRole,
+            10013 => Self::UnknownUser,
+            10014 => Self::UnknownEmoji,
+            10015 => Self::UnknownWebhook,
+            10062 => Self::UnknownInteraction,
+            10063 => Self::UnknownApplicationCommand,
+            40001 => Self::Unauthorized,
+            40060 => Self::InteractionAlreadyAcknowledged,
+            50001 => Self::MissingAccess,
+            50005 => Self::CannotEditAnotherUsersMessage,
+            50006 => Self::CannotSendEmptyMessage,
+            50007 => Self::CannotSendMessagesToUser,
+            50013 => Self::MissingPermissions,
+            50034 => Self::MessageTooOldToBulkDelete,
+            50035 => Self::InvalidFormBody,
+            50083 => Self::ThreadArchived,
+            130000 => Self::ResourceOverloaded,
+            code => Self::Other(code),
+        }
+    }
+}
+
+impl DiscordApiError {
+
+    /// The [`JsonErrorCode`] of this error so it can be matched on.
+    pub fn kind(&self) -> JsonErrorCode {
+        JsonErrorCode::from(self.code)
+    }
+
+    /// Flattens the nested errors object into a list of every invalid field alongside the path to it.
+    pub fn field_errors(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        if let Some(errors) = &self.errors {
+            collect_field_errors(errors, String::new(), &mut field_errors);
+        }
+        field_errors
+    }
+}
+
+/// Walks the nested errors object, field errors are stored within an "_errors" array at the path of the invalid field.
+fn collect_field_errors(errors: &Value, path: String, field_errors: &mut Vec<FieldError>) {
+
+    let errors = match errors.as_object() {
+        Some(errors) => errors,
+        None => return,
+    };
+
+    for (key, value) in errors {
+        if key == "_errors" {
+            for error in value.as_array().into_iter().flatten() {
+                if let Ok(mut field_error) = FieldError::deserialize(error) {
+                    field_error.path = path.clone();
+                    field_errors.push(field_error);
+                }
+            }
+            continue
+        }
+
+        let nested_path = match path.is_empty() {
+            true => key.clone(),
+            false => format!("{path}.{key}"),
+        };
+        collect_field_errors(value, nested_path, field_errors);
+    }
+}
+
+impl std::fmt::Display for DiscordApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (code {})", self.message, self.code)?;
+
+        // Include the invalid fields so the error is useful when it is only printed.
+        for field_error in self.field_errors() {
+            write!(f, ", {}: {}", field_error.path, field_error.message)?;
+        }
+        Ok(())
+    }
 }
+
 /// The client
 pub struct DiscordHttpClient {
 
@@ -27,12 +180,141 @@ pub struct DiscordHttpClient {
     // The base url to be built upon when requesting.
     pub base_url: Url,
 
+    // The rate limiter which every request waits on before being sent.
+    pub rate_limiter: Arc<RateLimiter>,
+
+    // The policy utilised for retrying requests which dont have their own.
+    pub retry_policy: RetryPolicy,
+
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The routes of the Discord api which can be requested, carrying the parameters of their path.
 pub enum DiscordHttpReqType {
     /// Retrieves information on connecting the Discord [`Gateway`] and additional metadata for sharding bots.
-    GetGatewayBot
+    GetGatewayBot,
+
+    /// Retrieves the user of the bot.
+    GetCurrentUser,
+
+    /// Retrieves a user.
+    GetUser { user_id: Id<UserMarker> },
+
+    /// Retrieves the application of the bot.
+    GetCurrentApplication,
+
+    /// Retrieves, modifies or deletes a channel depending on the method.
+    Channel { channel_id: Id<ChannelMarker> },
+
+    /// Triggers the typing indicator of the bot within a channel.
+    TriggerTypingIndicator { channel_id: Id<ChannelMarker> },
+
+    /// Retrieves or modifies a guild depending on the method.
+    Guild { guild_id: Id<GuildMarker> },
+
+    /// Retrieves the messages of a channel, or creates one when posted to.
+    ChannelMessages { channel_id: Id<ChannelMarker> },
+
+    /// Retrieves, edits or deletes a message within a channel depending on the method.
+    ChannelMessage { channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker> },
+
+    /// Deletes multiple messages within a channel at once.
+    BulkDeleteMessages { channel_id: Id<ChannelMarker> },
+
+    /// Retrieves the channels of a guild, or creates one when posted to.
+    GuildChannels { guild_id: Id<GuildMarker> },
+
+    /// Retrieves the roles of a guild, or creates one when posted to.
+    GuildRoles { guild_id: Id<GuildMarker> },
+
+    /// Retrieves, modifies or removes a member of a guild depending on the method.
+    GuildMember { guild_id: Id<GuildMarker>, user_id: Id<UserMarker> },
+
+    /// Retrieves the global commands of an application, creates one when posted to or overwrites them all when put to.
+    GlobalCommands { application_id: Id<ApplicationMarker> },
+
+    /// Retrieves, edits or deletes a global command depending on the method.
+    GlobalCommand { application_id: Id<ApplicationMarker>, command_id: Id<CommandMarker> },
+
+    /// Retrieves the commands of an application within a guild, creates one when posted to or overwrites them all when put to.
+    GuildCommands { application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker> },
+
+    /// Retrieves, edits or deletes a command within a guild depending on the method.
+    GuildCommand { application_id: Id<ApplicationMarker>, guild_id: Id<GuildMarker>, command_id: Id<CommandMarker> },
+
+    /// Responds to an interaction.
+    InteractionCallback { interaction_id: Id<InteractionMarker>, token: String },
+
+    /// Retrieves, edits or deletes the response to an interaction depending on the method.
+    OriginalInteractionResponse { application_id: Id<ApplicationMarker>, token: String },
+
+    /// Sends a follow-up message to an interaction.
+    InteractionFollowups { application_id: Id<ApplicationMarker>, token: String },
+
+    /// Retrieves, edits or deletes a follow-up message to an interaction depending on the method.
+    InteractionFollowup { application_id: Id<ApplicationMarker>, token: String, message_id: Id<MessageMarker> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The path of a [`DiscordHttpReqType`] alongside the information utilised for rate limiting it.
+pub struct Route {
+
+    /// The path of the route relative to the base url, with its parameters filled in.
+    pub path: String,
+
+    /// The path of the route with its parameters left as placeholders.
+    pub template: &'static str,
+
+    /// The value of the major parameter of the route, empty if the route has none.
+    pub major_parameter: String,
+}
+
+impl DiscordHttpReqType {
+
+    /// Creates the [`Route`] of this request type from its parameters.
+    pub fn route(&self) -> Route {
+        let (path, template, major_parameter) = match self {
+            Self::GetGatewayBot => ("gateway/bot".to_string(), "gateway/bot", String::new()),
+            Self::GetCurrentUser => ("users/@me".to_string(), "users/@me", String::new()),
+            Self::GetUser { user_id } => (format!("users/{user_id}"), "users/{user_id}", String::new()),
+            Self::GetCurrentApplication => ("applications/@me".to_string(), "applications/@me", String::new()),
+            Self::Channel { channel_id } => (format!("channels/{channel_id}"), "channels/{channel_id}", channel_id.to_string()),
+            Self::TriggerTypingIndicator { channel_id } => (format!("channels/{channel_id}/typing"), "channels/{channel_id}/typing", channel_id.to_string()),
+            Self::ChannelMessages { channel_id } => (format!("channels/{channel_id}/messages"), "channels/{channel_id}/messages", channel_id.to_string()),
+            Self::ChannelMessage { channel_id, message_id } => (format!("channels/{channel_id}/messages/{message_id}"), "channels/{channel_id}/messages/{message_id}", channel_id.to_string()),
+            Self::BulkDeleteMessages { channel_id } => (format!("channels/{channel_id}/messages/bulk-delete"), "channels/{channel_id}/messages/bulk-delete", channel_id.to_string()),
+            Self::Guild { guild_id } => (format!("guilds/{guild_id}"), "guilds/{guild_id}", guild_id.to_string()),
+            Self::GuildChannels { guild_id } => (format!("guilds/{guild_id}/channels"), "guilds/{guild_id}/channels", guild_id.to_string()),
+            Self::GuildRoles { guild_id } => (format!("guilds/{guild_id}/roles"), "guilds/{guild_id}/roles", guild_id.to_string()),
+            Self::GuildMember { guild_id, user_id } => (format!("guilds/{guild_id}/members/{user_id}"), "guilds/{guild_id}/members/{user_id}", guild_id.to_string()),
+            Self::GlobalCommands { application_id } => (format!("applications/{application_id}/commands"), "applications/{application_id}/commands", String::new()),
+            Self::GlobalCommand { application_id, command_id } => (format!("applications/{application_id}/commands/{command_id}"), "applications/{application_id}/commands/{command_id}", String::new()),
+            Self::GuildCommands { application_id, guild_id } => (format!("applications/{application_id}/guilds/{guild_id}/commands"), "applications/{application_id}/guilds/{guild_id}/commands", guild_id.to_string()),
+            Self::GuildCommand { application_id, guild_id, command_id } => (format!("applications/{application_id}/guilds/{guild_id}/commands/{command_id}"), "applications/{application_id}/guilds/{guild_id}/commands/{command_id}", guild_id.to_string()),
+            Self::InteractionCallback { interaction_id, token } => (format!("interactions/{interaction_id}/{token}/callback"), "interactions/{interaction_id}/{interaction_token}/callback", interaction_id.to_string()),
+            Self::OriginalInteractionResponse { application_id, token } => (format!("webhooks/{application_id}/{token}/messages/@original"), "webhooks/{application_id}/{interaction_token}/messages/@original", webhook_major_parameter(*application_id, token)),
+            Self::InteractionFollowups { application_id, token } => (format!("webhooks/{application_id}/{token}"), "webhooks/{application_id}/{interaction_token}", webhook_major_parameter(*application_id, token)),
+            Self::InteractionFollowup { application_id, token, message_id } => (format!("webhooks/{application_id}/{token}/messages/{message_id}"), "webhooks/{application_id}/{interaction_token}/messages/{message_id}", webhook_major_parameter(*application_id, token)),
+        };
+
+        Route {
+            path,
+            template,
+            major_parameter,
+        }
+    }
+
+    /// Whether requests of this type count towards the global rate limit, interaction callbacks & the webhooks of interactions dont.
+    pub fn is_globally_rate_limited(&self) -> bool {
+        !matches!(self, Self::InteractionCallback { .. } | Self::OriginalInteractionResponse { .. } | Self::InteractionFollowups { .. } | Self::InteractionFollowup { .. })
+    }
+}
+
+/// The major parameter of an interaction webhook, the token is hashed so it isnt kept within the rate limiter after it has expired.
+fn webhook_major_parameter(application_id: Id<ApplicationMarker>, token: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    token.hash(&mut hasher);
+    format!("{application_id}/{:016x}", hasher.finish())
 }
 
 #[derive(Debug)]
@@ -59,6 +341,18 @@ pub struct DiscordHttpRequest {
 
     /// A map of extra headers to add upon the default ones.
     pub headers: HeaderMap,
+
+    /// Overrides the [`RetryPolicy`] of the [`DiscordHttpClient`] for this request.
+    pub retry_policy: Option<RetryPolicy>,
+
+    /// The serialized JSON body of the request, if it has one.
+    pub body: Option<Vec<u8>>,
+
+    /// The query string parameters added to the url of the request.
+    pub query: Vec<(String, String)>,
+
+    /// Files uploaded with the request, which sends it as multipart/form-data with the body as payload_json.
+    pub attachments: Vec<AttachmentFile>,
     
 }
 
@@ -69,22 +363,69 @@ impl DiscordHttpRequest {
         Self { 
             request_type, 
             method, 
-            headers: HeaderMap::new() 
+            headers: HeaderMap::new(),
+            retry_policy: None,
+            body: None,
+            query: Vec::new(),
+            attachments: Vec::new(),
         }
     }
 
+    /// Uploads a file with the request.
+    pub fn with_attachment(mut self, attachment: AttachmentFile) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
+    /// Uploads several files with the request.
+    pub fn with_attachments(mut self, attachments: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.attachments.extend(attachments);
+        self
+    }
+
+    /// Whether the request can be sent more than once, which isnt the case when it contains a streamed [`AttachmentFile`].
+    pub fn is_replayable(&self) -> bool {
+        self.attachments.iter().all(AttachmentFile::is_replayable)
+    }
+
+    /// Serializes the value into the JSON body of the request.
+    pub fn with_json<T: Serialize + ?Sized>(mut self, body: &T) -> Result<Self> {
+        self.body = Some(serde_json::to_vec(body)?);
+        Ok(self)
+    }
+
+    /// Adds a parameter to the query string of the request.
+    pub fn with_query(mut self, key: &str, value: impl ToString) -> Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets the [`RetryPolicy`] utilised for this request instead of the one of the [`DiscordHttpClient`].
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
     /// Sends a [`DiscordHttpRequest`] to a [`DiscordHttpClientReqSender`] to process the request
+    /// Responses without a body, such as 204 No Content, are deserialized as null so T can be () or an [`Option`].
     pub async fn request<T: de::DeserializeOwned>(self, http_client_sender: DiscordHttpClientReqSender) -> Result<T> {
-        Ok(send_discord_http_request(DiscordHttpRequest::new(DiscordHttpReqType::GetGatewayBot, Method::GET), http_client_sender)
+        let response_body = send_discord_http_request(self, http_client_sender)
             .await?
-            .json::<T>()
-            .await?)
+            .bytes()
+            .await?;
+
+        let response_body: &[u8] = match response_body.is_empty() {
+            true => b"null",
+            false => &response_body,
+        };
+
+        Ok(serde_json::from_slice(response_body)?)
     }    
 
     /// Adds a header to the [`HeaderMap`] of the request.
-    pub fn add_header(&mut self, header_key: &'static str, header_value: &String) -> Result<(), InvalidHeaderValue> {
+    pub fn add_header(&mut self, header_key: &'static str, header_value: &str) -> Result<(), InvalidHeaderValue> {
 
-        // Convert &String value into HeaderValue
+        // Convert &str value into HeaderValue
         let header_value = HeaderValue::from_str(header_value)?; 
 
         // Append new header to existing map
@@ -94,115 +435,180 @@ impl DiscordHttpRequest {
         Result::Ok(())
     }
 
-    /// Retrieve the str extension to the base uri's path from the enum representation.
-    pub fn get_request_path(&self) -> &'static str {
-        match self.request_type {
-            DiscordHttpReqType::GetGatewayBot => "gateway/bot",
+    /// The [`RateLimitRoute`] of this request, which decides the rate limit bucket it is made within.
+    pub fn rate_limit_route(&self) -> RateLimitRoute {
+        let route = self.request_type.route();
+
+        RateLimitRoute {
+            template: format!("{} {}", self.method, route.template),
+            major_parameter: route.major_parameter,
+            global: self.request_type.is_globally_rate_limited(),
         }
     }
+
+    /// Retrieve the extension to the base uri's path from the enum representation.
+    pub fn get_request_path(&self) -> String {
+        self.request_type.route().path
+    }
 }
 
 
 
 impl DiscordHttpClient {
 
-    pub fn new(base_url: &'static str, version: u32, token: Arc<str>) -> Result<Self> {
+    /// Creates a new [`DiscordHttpClient`] which makes requests to base_url with the token as its authorization.
+    /// Fails with [`Error::InvalidHeader`] if the token contains characters which arent ASCII.
+    pub fn new(base_url: &str, version: u32, token: Arc<str>) -> Result<Self> {
 
         // Default headers required for utilising discord api.
         let mut default_headers = HeaderMap::new();
 
         // Add authorization to the default headers as every request which involves the bot requires this.
-        let authorization_header_value = HeaderValue::from_str(&format!("Bot {token}"))
-            .context("Failed to create default authorization header for Http Requests, perhaps token inputted is not ASCII?")?;
+        let authorization_header_value = HeaderValue::from_str(&format!("Bot {token}"))?;
         default_headers.append("Authorization", authorization_header_value);
 
 
         // Create the reqwest client utilised for https requests to discords api.
         let client = Client::builder()
             .default_headers(default_headers)
-            .build()
-            .context("Should've created reqwest DiscordHttpClient with default headers")?;
+            .build()?;
 
         // Create the base_uri utilised for all requests once here.
-        let url = Url::from_str(&format!("{base_url}/v{version}/"))
-            .context("URL should be created by combining base_url with version through format! macro")?;
+        let url = Url::from_str(&format!("{base_url}/v{version}/"))?;
         
         Ok(Self {
             client,
             base_url: url,
+            rate_limiter: Arc::new(RateLimiter::default()),
+            retry_policy: RetryPolicy::default(),
         })
     }
 
     /// Sets up a request through [`DiscordHttpClient`]
     /// The [`Method`], [Path][`Url`] and [Headers][`HeaderMap`] are retrieved through a [`DiscordHttpRequest`]
-    pub fn request(&self, request: DiscordHttpRequest) ->  Result<RequestBuilder> {
+    /// Streamed attachments are taken from the request, so it can only be set up once if it contains any.
+    pub fn request(&self, request: &mut DiscordHttpRequest) ->  Result<RequestBuilder> {
 
         // Constructs the full URL utilised for this request
-        let url_address = self.base_url.join(request.get_request_path())
-            .context("Failed to join base_url with Request path")?;
+        let mut url_address = self.base_url.join(&request.get_request_path())?;
+        if !request.query.is_empty() {
+            url_address.query_pairs_mut().extend_pairs(&request.query);
+        }
 
         // Create the Request and send it retrieving the result of the request.
-        Ok(self.client.request(request.method, url_address)
-            .headers(request.headers))
+        let request_builder = self.client.request(request.method.clone(), url_address)
+            .headers(request.headers.clone());
+
+        // Requests with files are sent as multipart/form-data, with the JSON body as payload_json.
+        if !request.attachments.is_empty() {
+            let form = multipart_form(request.body.as_deref(), &mut request.attachments)?;
+            return Ok(request_builder.multipart(form))
+        }
+
+        // Attach the JSON body if the request has one.
+        Ok(match &request.body {
+            Some(body) => request_builder
+                .header(CONTENT_TYPE, "application/json")
+                .body(body.clone()),
+            None => request_builder,
+        })
+    }
+
+    /// Sends a [`DiscordHttpRequest`] once the [`RateLimiter`] allows it, retrying it according to its [`RetryPolicy`].
+    /// The request specific [`RetryPolicy`] is utilised if one was set, otherwise the one of this [`DiscordHttpClient`].
+    /// Requests containing a streamed [`AttachmentFile`] are never retried, as the stream can only be read once.
+    pub async fn execute(&self, mut request: DiscordHttpRequest) -> Result<HttpResponse> {
+
+        // The route decides the rate limit bucket the request waits within.
+        let rate_limit_route = request.rate_limit_route();
+        let retry_policy = match request.is_replayable() {
+            true => request.retry_policy.clone().unwrap_or_else(|| self.retry_policy.clone()),
+            false => RetryPolicy::never(),
+        };
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
+
+            // Create the request to send, this is done for every attempt as a sent request can not be reused.
+            let request_builder = self.request(&mut request)?;
+
+            // Wait until the rate limits allow the request to be made.
+            let rate_limit_ticket = self.rate_limiter.acquire(&rate_limit_route).await;
+
+            let response = match request_builder.send().await {
+                Ok(response) => response,
+                Err(error) => {
+                    if !retry_policy.has_attempts_remaining(attempts) || !retry_policy.should_retry_error(&error, &request.method) {
+                        return Err(error.into())
+                    }
+
+                    let backoff = retry_policy.backoff(attempts);
+                    tracing::warn!("Request to {} failed, retrying in {:?}: {error}", rate_limit_route.template, backoff);
+                    sleep(backoff).await;
+                    continue
+                },
+            };
+
+            // Update the bucket with the rate limit headers of the response.
+            let status = response.status();
+            self.rate_limiter.update(rate_limit_ticket, status, response.headers());
+
+            if status.is_success() {
+                return Ok(response)
+            }
+
+            // Unsuccessful responses are turned into an error from the error body discord sent.
+            if !retry_policy.has_attempts_remaining(attempts) || !retry_policy.should_retry_status(status, &request.method) {
+                return Err(error_from_response(response).await)
+            }
+
+            if status == StatusCode::TOO_MANY_REQUESTS {
+
+                // The body contains a more precise retry_after than the header, the next attempt waits on the rate limiter until it has passed.
+                let rate_limit_headers = RateLimitHeaders::from_headers(response.headers());
+                let body = response.json::<RateLimitedBody>().await.ok();
+
+                let retry_after = body.as_ref()
+                    .map(|body| body.retry_after)
+                    .filter(|retry_after| retry_after.is_finite() && *retry_after >= 0.0)
+                    .map(Duration::from_secs_f64)
+                    .or(rate_limit_headers.retry_after)
+                    .unwrap_or(Duration::from_secs(1));
+                let global = rate_limit_headers.global || body.is_some_and(|body| body.global);
+
+                tracing::warn!("Rate limited on {}, retrying in {:?}", rate_limit_route.template, retry_after);
+                self.rate_limiter.rate_limited(&rate_limit_route, retry_after, global);
+            } else {
+                let backoff = retry_policy.backoff(attempts);
+                tracing::warn!("Request to {} recieved {status}, retrying in {:?}", rate_limit_route.template, backoff);
+                sleep(backoff).await;
+            }
+        }
     }
 
     /// Method which acts as the processor for recieving inbound a [`DiscordHttpRequest`] to the [`DiscordHttpClient`] channel.
+    /// Each request waits on the [`RateLimiter`] for its bucket before it is sent, & is retried according to its [`RetryPolicy`].
     /// The inbound channel is [multi-producer single-consumer][`MspcReceiver`]
     /// The response channel is [oneshot][`OneshotSender`]
     pub async fn handle_channel_inbound_requests(self, mut reciever: MpscReceiver<DiscordHttpClientRequest>) -> Result<()> {
 
+        // Shared between the tasks spawned for each request.
+        let http_client = Arc::new(self);
+
         // Recieve new inbound requests from the reciever
         while let Some(request) = reciever.recv().await {
 
-            // Create our request to send, 
-            // If error is encountered in process of creating the request we send it back & skip to next request in the channel
-            // Errors are ignored as the original sender may not wish for a response and this is acceptable behaviour
-            let request_builder = match self.request(request.request).context("Failed to create Request Builder") {
-                Ok(request) => request,
-                Err(error) => {let _ = request.response_sender.send(Err(error)); continue},
-            };
-            
-            
+            let http_client = http_client.clone();
 
             // Spawn a new task so we can handle sending the request asynchronously so it doesnt block this channel.
             tokio::spawn(
                 async move {
-                    
-                    // Send the request to discord, if an error is encountered send it back through the recieving channel
-                    // Errors are ignored as the original sender may not wish for a response and this is acceptable behaviour
-                    let response = match request_builder.send().await.context("Failed to send request to Discord Api") {
-                        Ok(response) => response,
-                        Err(error) => {let _ = request.response_sender.send(Err(error)); return},
-                    };
-
-                    
-
-                    // Get the StatusCode of the request
-                    let status = response.status();
-
-                    // Check for any issues which can be identified through the status code 
-                    let response = match status {
-                        reqwest::StatusCode::UNAUTHORIZED => {
-
-                            // Unwrap because we've already confirmed its an Unauthorized error.
-                            let error = response.error_for_status().unwrap_err();
-                            
-                            // Send the error back
-                            // If the sender of the original requests no longer wants to recieve a response which is possible behaviour then we can just ignore the error.
-                            let _ = request.response_sender.send(Err(anyhow::Error::new(error).context(
-                                "Unauthorized most likely due to Invalid Token Passed"
-                            )));
-                            return
-
-                        },
-                        _ => {
-                            response
-                        },
-                    };
+                    let response = http_client.execute(request.request).await;
 
                     // Send back the result
                     // Errors are ignored as dropping the reciever is acceptable when it no longer wants the response.
-                    let _ = request.response_sender.send(Ok(response));
+                    let _ = request.response_sender.send(response);
                 }
             );
 
@@ -213,6 +619,18 @@ impl DiscordHttpClient {
     }
 }
 
+/// Creates an [`Error`] from an unsuccessful response, decoding the [`DiscordApiError`] body if one was sent.
+pub async fn error_from_response(response: HttpResponse) -> Error {
+
+    let status = response.status();
+
+    // Responses such as those from Cloudflare dont contain a Discord error body, so only the status is returned.
+    match response.json::<DiscordApiError>().await {
+        Ok(error) => Error::DiscordApi { status, error },
+        Err(_) => Error::Status { status },
+    }
+}
+
 /// Simplifies sending process for sending a [`DiscordHttpRequest`] through the [`DiscordHttpClient`] mspc request processing channel.
 pub async fn send_discord_http_request(request: DiscordHttpRequest, http_client_sender: DiscordHttpClientReqSender) -> Result<HttpResponse> {
 
@@ -221,17 +639,100 @@ pub async fn send_discord_http_request(request: DiscordHttpRequest, http_client_
 
     // Create the Request struct to send through the channel
     let client_request = DiscordHttpClientRequest { 
-        response_sender: response_sender, 
-        request: request
+        response_sender, 
+        request
     };
 
-    // Send it through the request processing channel, expect here because this is a critical error which would cause failure of the bot if it did not panic as no further http requests could be sent.
+    // Send it through the request processing channel, this only fails if the DiscordHttpClient has stopped so no further requests can be sent.
     http_client_sender.send(client_request)
         .await
-        .expect("Attempted to send Request through DiscordHttpClient mpsc channel");
+        .map_err(|_| Error::ChannelClosed("DiscordHttpClient request"))?;
 
-    // Recieve the response from the Request processor and return it, expect here because the sending half should never be disconnected while this hasnt been recieved yet.
+    // Recieve the response from the Request processor and return it, the sending half is only dropped if the DiscordHttpClient has stopped.
     response_reciever.await
-        .expect("Attempted to recieve response from DiscordHttpClient channel processor")
+        .map_err(|_| Error::ChannelClosed("DiscordHttpClient response"))?
     
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use reqwest::Method;
+
+    use crate::snowflake::Id;
+    use super::{DiscordApiError, DiscordHttpClient, DiscordHttpReqType, DiscordHttpRequest, FieldError, JsonErrorCode};
+
+    fn field_error(path: &str, code: &str, message: &str) -> FieldError {
+        FieldError { path: path.to_string(), code: code.to_string(), message: message.to_string() }
+    }
+
+    #[test]
+    fn nested_field_errors_are_flattened() {
+        let error: DiscordApiError = serde_json::from_str(r#"{
+            "code": 50035,
+            "errors": {
+                "activities": {
+                    "0": {
+                        "platform": { "_errors": [{ "code": "BASE_TYPE_CHOICES", "message": "Value must be one of ('desktop', 'android', 'ios')." }] },
+                        "type": { "_errors": [{ "code": "BASE_TYPE_CHOICES", "message": "Value must be one of (0, 1, 2, 3, 4, 5)." }] }
+                    }
+                },
+                "embeds": {
+                    "0": {
+                        "title": { "_errors": [{ "code": "BASE_TYPE_MAX_LENGTH", "message": "Must be 256 or fewer in length." }] }
+                    }
+                }
+            },
+            "message": "Invalid Form Body"
+        }"#).unwrap();
+
+        assert_eq!(error.kind(), JsonErrorCode::InvalidFormBody);
+
+        let mut field_errors = error.field_errors();
+        field_errors.sort_by(|error, other| error.path.cmp(&other.path));
+        assert_eq!(field_errors, [
+            field_error("activities.0.platform", "BASE_TYPE_CHOICES", "Value must be one of ('desktop', 'android', 'ios')."),
+            field_error("activities.0.type", "BASE_TYPE_CHOICES", "Value must be one of (0, 1, 2, 3, 4, 5)."),
+            field_error("embeds.0.title", "BASE_TYPE_MAX_LENGTH", "Must be 256 or fewer in length."),
+        ]);
+        assert!(error.to_string().starts_with("Invalid Form Body (code 50035), activities.0.platform: "));
+    }
+
+    #[test]
+    fn errors_of_the_whole_body_have_an_empty_path() {
+        let error: DiscordApiError = serde_json::from_str(r#"{
+            "code": 50035,
+            "errors": { "_errors": [{ "code": "APPLICATION_COMMAND_TOO_LARGE", "message": "Command exceeds maximum size (8000)" }] },
+            "message": "Invalid Form Body"
+        }"#).unwrap();
+
+        assert_eq!(error.field_errors(), [field_error("", "APPLICATION_COMMAND_TOO_LARGE", "Command exceeds maximum size (8000)")]);
+    }
+
+    #[test]
+    fn errors_without_field_errors_are_empty() {
+        let error: DiscordApiError = serde_json::from_str(r#"{ "code": 50013, "message": "Missing Permissions" }"#).unwrap();
+        assert_eq!(error.kind(), JsonErrorCode::MissingPermissions);
+        assert!(error.field_errors().is_empty());
+        assert_eq!(error.to_string(), "Missing Permissions (code 50013)");
+    }
+
+    #[test]
+    fn routes_fill_in_their_parameters() {
+        let client = DiscordHttpClient::new("https://discord.com/api", 10, Arc::from("token")).unwrap();
+        let mut request = DiscordHttpRequest::new(DiscordHttpReqType::ChannelMessage { channel_id: Id::new(1), message_id: Id::new(2) }, Method::PATCH)
+            .with_query("limit", 50)
+            .with_json(&serde_json::json!({"content": "edited"}))
+            .unwrap();
+
+        let route = request.rate_limit_route();
+        assert_eq!(route.template, "PATCH channels/{channel_id}/messages/{message_id}");
+        assert_eq!(route.major_parameter, "1");
+
+        let built = client.request(&mut request).unwrap().build().unwrap();
+        assert_eq!(built.url().as_str(), "https://discord.com/api/v10/channels/1/messages/2?limit=50");
+        assert_eq!(built.headers()["content-type"], "application/json");
+        assert_eq!(built.body().and_then(|body| body.as_bytes()), Some(&br#"{"content":"edited"}"#[..]));
+    }
+}
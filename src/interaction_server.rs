@@ -0,0 +1,323 @@
+//! Recieving [interactions through an Interactions Endpoint URL][https://discord.com/developers/docs/interactions/overview#preparing-for-interactions] instead of the gateway.
+//! Only available with the `http-interactions` feature.
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use hyper::body::{Bytes, HttpBody};
+use hyper::header::{CONTENT_LENGTH, CONTENT_TYPE, HeaderValue};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use serde::Serialize;
+use tokio::sync::oneshot;
+
+use crate::error::{Error, Result};
+use crate::event_handler::{Context, EventHandlers, dispatch_event};
+use crate::gateway::DispatchEvent;
+use crate::http::DiscordHttpClientReqSender;
+use crate::interactions::InteractionResponse;
+use crate::models::interaction::{Interaction, InteractionType};
+
+/// The header containing the hex encoded Ed25519 signature of a request sent to an Interactions Endpoint URL.
+pub const SIGNATURE_HEADER: &str = "X-Signature-Ed25519";
+
+/// The header containing the timestamp which is signed alongside the body of a request sent to an Interactions Endpoint URL.
+pub const TIMESTAMP_HEADER: &str = "X-Signature-Timestamp";
+
+/// The largest body accepted by the [`InteractionServer`] in bytes, larger requests are rejected before being verified as the endpoint is public.
+pub const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+#[derive(Clone, Debug)]
+/// Verifies that requests sent to an Interactions Endpoint URL were signed by Discord, utilising the public key of the application.
+/// Discord regularly sends requests with invalid signatures & removes the endpoint if any of them are accepted.
+pub struct InteractionVerifier {
+    public_key: VerifyingKey,
+}
+
+impl InteractionVerifier {
+
+    /// Creates a verifier from the hex encoded public key shown in the developer page of the application.
+    pub fn new(public_key: &str) -> Result<Self> {
+
+        let bytes: [u8; 32] = hex::decode(public_key.trim()).ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| Error::Configuration("Interaction public key must be 32 hex encoded bytes".to_string()))?;
+
+        let public_key = VerifyingKey::from_bytes(&bytes)
+            .map_err(|error| Error::Configuration(format!("Interaction public key is invalid: {error}")))?;
+
+        Ok(Self { public_key })
+    }
+
+    /// Whether the hex encoded signature is a valid signature of the timestamp followed by the body.
+    pub fn verify(&self, signature: &str, timestamp: &str, body: &[u8]) -> bool {
+
+        let signature = match hex::decode(signature).ok().and_then(|bytes| <[u8; 64]>::try_from(bytes).ok()) {
+            Some(bytes) => Signature::from_bytes(&bytes),
+            None => return false,
+        };
+
+        let message = [timestamp.as_bytes(), body].concat();
+        self.public_key.verify(&message, &signature).is_ok()
+    }
+}
+
+/// A small HTTP server which recieves interactions sent to an Interactions Endpoint URL & passes them to the [`EventHandler`][crate::event_handler::EventHandler]s.
+/// Interactions are passed to [`EventHandler::interaction_create`][crate::event_handler::EventHandler::interaction_create] just as if they were recieved through the gateway,
+/// but with no [`Gateway`][crate::gateway::Gateway] in their [`Context`]
+/// The response given through [`Interaction::respond`] is returned as the HTTP response, follow-ups are sent through the [`DiscordHttpClient`][crate::http::DiscordHttpClient]
+pub struct InteractionServer {
+
+    /// Verifies the signature of every request before it is handled
+    pub verifier: InteractionVerifier,
+
+    /// The sender utilised by handlers for making requests to the Discord api.
+    pub http: DiscordHttpClientReqSender,
+
+    /// The handlers which are called for every interaction.
+    pub event_handlers: EventHandlers,
+}
+
+impl InteractionServer {
+
+    /// Creates a server verifying requests against the hex encoded public key of the application.
+    pub fn new(public_key: &str, http: DiscordHttpClientReqSender, event_handlers: EventHandlers) -> Result<Self> {
+        Ok(Self {
+            verifier: InteractionVerifier::new(public_key)?,
+            http,
+            event_handlers,
+        })
+    }
+
+    /// Listens for requests on the address until the server fails, utilised by [`Bot::serve_interactions`][crate::bot::Bot::serve_interactions]
+    pub async fn serve(self, address: impl Into<SocketAddr>) -> Result<()> {
+
+        let server = Arc::new(self);
+        let make_service = make_service_fn(move |_| {
+            let server = server.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let server = server.clone();
+                    async move { Ok::<_, Infallible>(server.handle(request).await) }
+                }))
+            }
+        });
+
+        Server::try_bind(&address.into())?.serve(make_service).await?;
+        Ok(())
+    }
+
+    /// Handles a single request sent to the Interactions Endpoint URL, so the server can be mounted within another hyper service.
+    /// Requests which arent signed by Discord are rejected with 401 Unauthorized & PINGs are answered without calling the handlers.
+    /// Bodies larger than [`MAX_BODY_SIZE`] are rejected with 413 Payload Too Large before being read into memory.
+    /// Waits until a handler responds to the interaction, responding with 500 Internal Server Error if none do before its deadline.
+    pub async fn handle(&self, request: Request<Body>) -> Response<Body> {
+
+        if request.method() != Method::POST {
+            return status_response(StatusCode::METHOD_NOT_ALLOWED)
+        }
+
+        let header = |name: &str| request.headers().get(name).and_then(|value| value.to_str().ok()).map(str::to_string);
+        let (signature, timestamp) = match (header(SIGNATURE_HEADER), header(TIMESTAMP_HEADER)) {
+            (Some(signature), Some(timestamp)) => (signature, timestamp),
+            _ => return status_response(StatusCode::UNAUTHORIZED),
+        };
+
+        let body = match read_body(request, MAX_BODY_SIZE).await {
+            Ok(body) => body,
+            Err(status) => return status_response(status),
+        };
+
+        if !self.verifier.verify(&signature, &timestamp, &body) {
+            return status_response(StatusCode::UNAUTHORIZED)
+        }
+
+        let interaction: Interaction = match serde_json::from_slice(&body) {
+            Ok(interaction) => interaction,
+            Err(error) => {
+                tracing::warn!("Failed to decode an interaction recieved through the Interactions Endpoint URL: {error}");
+                return status_response(StatusCode::BAD_REQUEST)
+            },
+        };
+
+        if interaction.kind == InteractionType::Ping {
+            return json_response(&InteractionResponse::pong())
+        }
+
+        // The response is sent back through the channel by Interaction::respond, so it can be returned as the HTTP response.
+        let (responder, response_reciever) = oneshot::channel();
+        interaction.acknowledgement.set_responder(responder);
+        interaction.acknowledgement.watch(interaction.id);
+
+        let interaction_id = interaction.id;
+        let remaining = interaction.acknowledgement.remaining();
+        let event = DispatchEvent::InteractionCreate(Box::new(interaction));
+
+        for handler in self.event_handlers.iter() {
+            let context = Context {
+                gateway: None,
+                http: self.http.clone(),
+                cache: None,
+            };
+            tokio::spawn(dispatch_event(handler.clone(), context, event.clone()));
+        }
+
+        // Dropping the event means the channel closes once every handler has finished without responding.
+        drop(event);
+
+        match tokio::time::timeout(remaining, response_reciever).await {
+            Ok(Ok(Some(response))) => json_response(&response),
+            Ok(Ok(None)) => status_response(StatusCode::ACCEPTED),
+            Ok(Err(_)) => {
+                tracing::warn!("Interaction {interaction_id} was not responded to by any handler");
+                status_response(StatusCode::INTERNAL_SERVER_ERROR)
+            },
+            Err(_) => {
+                tracing::warn!("Interaction {interaction_id} was not responded to before its deadline");
+                status_response(StatusCode::INTERNAL_SERVER_ERROR)
+            },
+        }
+    }
+}
+
+/// Reads the body of a request, failing with 413 Payload Too Large once it is larger than the limit.
+/// The Content-Length is checked first so oversized requests are rejected without reading any of the body.
+async fn read_body(request: Request<Body>, limit: usize) -> std::result::Result<Bytes, StatusCode> {
+
+    let content_length = request.headers().get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+    if content_length.is_some_and(|length| length > limit as u64) {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE)
+    }
+
+    let mut body = request.into_body();
+    let mut bytes = Vec::with_capacity(content_length.map_or(0, |length| length as usize));
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(|_| StatusCode::BAD_REQUEST)?;
+        if bytes.len() + chunk.len() > limit {
+            return Err(StatusCode::PAYLOAD_TOO_LARGE)
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+
+    Ok(bytes.into())
+}
+
+/// Creates an empty response with the status.
+fn status_response(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+    response
+}
+
+/// Creates a response with the value serialized as its JSON body.
+fn json_response(value: &impl Serialize) -> Response<Body> {
+    match serde_json::to_vec(value) {
+        Ok(body) => {
+            let mut response = Response::new(Body::from(body));
+            response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            response
+        },
+        Err(error) => {
+            tracing::warn!("Failed to serialize an interaction response: {error}");
+            status_response(StatusCode::INTERNAL_SERVER_ERROR)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use ed25519_dalek::{Signer, SigningKey};
+    use hyper::{Body, Request, StatusCode};
+    use tokio::sync::mpsc;
+
+    use super::*;
+
+    /// The key the requests are signed with, as Discord would.
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    /// A server with no handlers verifying against a fixed key.
+    fn server() -> InteractionServer {
+        let public_key = signing_key().verifying_key();
+        let (http, _) = mpsc::channel(1);
+        InteractionServer {
+            verifier: InteractionVerifier { public_key },
+            http,
+            event_handlers: Arc::new(Vec::new()),
+        }
+    }
+
+    /// A signed looking POST request, the signature is never checked for oversized bodies.
+    fn request(body: Body, content_length: Option<usize>) -> Request<Body> {
+        let mut request = Request::post("/")
+            .header(SIGNATURE_HEADER, "00")
+            .header(TIMESTAMP_HEADER, "0");
+        if let Some(content_length) = content_length {
+            request = request.header(CONTENT_LENGTH, content_length);
+        }
+        request.body(body).unwrap()
+    }
+
+    /// A POST request with the timestamp & body signed by the [`signing_key`]
+    fn signed_request(timestamp: &str, body: &str) -> Request<Body> {
+        let signature = signing_key().sign(format!("{timestamp}{body}").as_bytes());
+        Request::post("/")
+            .header(SIGNATURE_HEADER, hex::encode(signature.to_bytes()))
+            .header(TIMESTAMP_HEADER, timestamp)
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    const PING: &str = r#"{"id": "1", "application_id": "2", "type": 1, "token": "token", "version": 1}"#;
+
+    #[tokio::test]
+    async fn signed_ping_is_answered_with_a_pong() {
+        let response = server().handle(signed_request("1700000000", PING)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(serde_json::from_slice::<serde_json::Value>(&body).unwrap(), serde_json::json!({ "type": 1 }));
+    }
+
+    #[tokio::test]
+    async fn tampered_timestamp_is_unauthorized() {
+        let mut request = signed_request("1700000000", PING);
+        request.headers_mut().insert(TIMESTAMP_HEADER, HeaderValue::from_static("1700000001"));
+
+        let response = server().handle(request).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn oversized_content_length_is_rejected() {
+        let response = server().handle(request(Body::empty(), Some(MAX_BODY_SIZE + 1))).await;
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn oversized_streamed_body_is_rejected() {
+        let (mut sender, body) = Body::channel();
+        tokio::spawn(async move {
+            for _ in 0..=MAX_BODY_SIZE / 1024 {
+                if sender.send_data(vec![0; 1024].into()).await.is_err() {
+                    return
+                }
+            }
+        });
+
+        let response = server().handle(request(body, None)).await;
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn unsigned_body_within_the_limit_is_unauthorized() {
+        let response = server().handle(request(Body::from("{}"), Some(2))).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
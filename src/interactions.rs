@@ -0,0 +1,465 @@
+//! Responding to [interactions][https://discord.com/developers/docs/interactions/receiving-and-responding#responding-to-an-interaction] & their follow-up messages.
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::Serialize;
+
+use crate::attachment::AttachmentFile;
+#[cfg(feature = "http-interactions")]
+use crate::error::Error;
+use crate::error::{Result, ValidationError};
+use crate::http::{DiscordHttpClientReqSender, DiscordHttpReqType, DiscordHttpRequest};
+use crate::messages::{AllowedMentions, EditMessage, MESSAGE_CONTENT_LIMIT, MESSAGE_EMBED_LIMIT};
+use crate::models::command::{CommandOptionChoice, COMMAND_CHOICE_LIMIT};
+use crate::models::component::{ActionRow, Component, TextInput, ACTION_ROW_LIMIT, CUSTOM_ID_LIMIT};
+use crate::models::embed::Embed;
+use crate::models::interaction::{Interaction, InteractionResponseType};
+use crate::models::message::{Message, MessageFlags};
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ApplicationMarker, InteractionMarker, MessageMarker};
+
+/// The most characters the title of a modal can contain.
+pub const MODAL_TITLE_LIMIT: usize = 45;
+
+#[derive(Serialize, Debug)]
+/// [A response to an interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object]
+pub struct InteractionResponse {
+
+    #[serde(rename = "type")]
+    /// The type of the response
+    pub kind: InteractionResponseType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The message, choices or modal of the response
+    pub data: Option<InteractionResponseData>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(untagged)]
+/// [The data of a response to an interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-data-structure] which depends on its type.
+pub enum InteractionResponseData {
+
+    /// The message of a message or update response, or the flags of a deferred response
+    Message(InteractionMessage),
+
+    /// The choices of an autocomplete response
+    Autocomplete { choices: Vec<CommandOptionChoice> },
+
+    /// The modal of a modal response
+    Modal(Modal),
+}
+
+impl InteractionResponse {
+
+    /// Creates a response of the type with the data.
+    pub fn new(kind: InteractionResponseType, data: Option<InteractionResponseData>) -> Self {
+        Self {
+            kind,
+            data,
+        }
+    }
+
+    /// Acknowledges a ping, only sent to interactions recieved through an outgoing webhook.
+    pub fn pong() -> Self {
+        Self::new(InteractionResponseType::Pong, None)
+    }
+
+    /// Responds with a message.
+    pub fn message(message: InteractionMessage) -> Self {
+        Self::new(InteractionResponseType::ChannelMessageWithSource, Some(InteractionResponseData::Message(message)))
+    }
+
+    /// Acknowledges the interaction so the response can be edited in later, for responses which take longer than 3 seconds.
+    /// Whether the response is ephemeral can not be changed once it is deferred.
+    pub fn deferred_message(ephemeral: bool) -> Self {
+        let message = InteractionMessage::new().ephemeral(ephemeral);
+        Self::new(InteractionResponseType::DeferredChannelMessageWithSource, Some(InteractionResponseData::Message(message)))
+    }
+
+    /// Acknowledges a component interaction so the message of the component can be edited later.
+    pub fn deferred_update() -> Self {
+        Self::new(InteractionResponseType::DeferredUpdateMessage, None)
+    }
+
+    /// Edits the message of the component which was used.
+    pub fn update_message(message: InteractionMessage) -> Self {
+        Self::new(InteractionResponseType::UpdateMessage, Some(InteractionResponseData::Message(message)))
+    }
+
+    /// Responds to an autocomplete interaction with up to 25 choices.
+    pub fn autocomplete(choices: Vec<CommandOptionChoice>) -> Self {
+        Self::new(InteractionResponseType::ApplicationCommandAutocompleteResult, Some(InteractionResponseData::Autocomplete { choices }))
+    }
+
+    /// Responds with a popup modal, can not be used to respond to a modal submit or ping.
+    pub fn modal(modal: Modal) -> Self {
+        Self::new(InteractionResponseType::Modal, Some(InteractionResponseData::Modal(modal)))
+    }
+
+    /// Checks the response against the limits of the Discord api.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match &self.data {
+            Some(InteractionResponseData::Message(message)) => message.validate(),
+            Some(InteractionResponseData::Autocomplete { choices }) => {
+                ValidationError::check_count(|| "choices".to_string(), choices.len(), 0, COMMAND_CHOICE_LIMIT)?;
+
+                for (index, choice) in choices.iter().enumerate() {
+                    choice.validate().map_err(|error| error.prefixed(&format!("choices[{index}]")))?;
+                }
+                Ok(())
+            },
+            Some(InteractionResponseData::Modal(modal)) => modal.validate(),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the message of the response uploads any files.
+    #[cfg(feature = "http-interactions")]
+    fn has_files(&self) -> bool {
+        matches!(&self.data, Some(InteractionResponseData::Message(message)) if !message.files.is_empty())
+    }
+
+    /// Takes the files uploaded with the message of the response.
+    fn take_files(&mut self) -> Vec<AttachmentFile> {
+        match &mut self.data {
+            Some(InteractionResponseData::Message(message)) => std::mem::take(&mut message.files),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+/// [A message sent in response to an interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-messages], or as a follow-up message.
+pub struct InteractionMessage {
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The contents of the message, up to 2000 characters
+    pub content: Option<String>,
+
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    /// Whether the message is a text to speech message
+    pub tts: bool,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// The embedded content of the message, up to 10 embeds
+    pub embeds: Vec<Embed>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Which mentions within the message notify their target
+    pub allowed_mentions: Option<AllowedMentions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The flags of the message, only EPHEMERAL, SUPPRESS_EMBEDS & SUPPRESS_NOTIFICATIONS can be set
+    pub flags: Option<MessageFlags>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// The components of the message, such as buttons, up to 5 action rows
+    pub components: Vec<Component>,
+
+    #[serde(skip)]
+    /// The files uploaded with the message
+    pub files: Vec<AttachmentFile>,
+}
+
+impl InteractionMessage {
+
+    /// Creates an empty [`InteractionMessage`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the contents of the message.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Adds an embed to the message, created with an [`EmbedBuilder`][crate::models::embed::EmbedBuilder]
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embeds.push(embed);
+        self
+    }
+
+    /// Adds a row of components to the message, see [`ActionRow`]
+    pub fn component(mut self, component: impl Into<Component>) -> Self {
+        self.components.push(component.into());
+        self
+    }
+
+    /// Uploads a file with the message.
+    pub fn file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+        self
+    }
+
+    /// Sets which mentions within the message notify their target.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    /// Sets whether only the user which created the interaction can see the message.
+    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
+        self.flags.get_or_insert_with(MessageFlags::empty).set(MessageFlags::EPHEMERAL, ephemeral);
+        self
+    }
+
+    /// Sets the flags of the message.
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Sets whether the message is a text to speech message.
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.tts = tts;
+        self
+    }
+
+    /// Checks the message against the limits of the Discord api.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        if let Some(content) = &self.content {
+            ValidationError::check_length(|| "content".to_string(), content, MESSAGE_CONTENT_LIMIT)?;
+        }
+
+        ValidationError::check_count(|| "embeds".to_string(), self.embeds.len(), 0, MESSAGE_EMBED_LIMIT)?;
+        Embed::validate_all(&self.embeds)?;
+        Component::validate_all(&self.components)
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+/// [A popup modal][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal] containing text inputs.
+pub struct Modal {
+
+    /// The developer defined identifier of the modal, up to 100 characters
+    pub custom_id: String,
+
+    /// The title shown at the top of the modal, up to 45 characters
+    pub title: String,
+
+    /// The action rows of the modal, each containing a single text input
+    pub components: Vec<Component>,
+}
+
+impl Modal {
+
+    /// Creates a modal without any text inputs.
+    pub fn new(custom_id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            title: title.into(),
+            components: Vec::new(),
+        }
+    }
+
+    /// Adds a text input to the modal within its own action row, a modal can contain up to 5.
+    pub fn text_input(mut self, text_input: TextInput) -> Self {
+        self.components.push(ActionRow::new().text_input(text_input).into());
+        self
+    }
+
+    /// Checks the modal against the limits of the Discord api.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        for (field, value, max) in [("custom_id", &self.custom_id, CUSTOM_ID_LIMIT), ("title", &self.title, MODAL_TITLE_LIMIT)] {
+            if value.is_empty() {
+                return Err(ValidationError::Empty { field: field.to_string() })
+            }
+
+            ValidationError::check_length(|| field.to_string(), value, max)?;
+        }
+
+        ValidationError::check_count(|| "components".to_string(), self.components.len(), 1, ACTION_ROW_LIMIT)?;
+        Component::validate_all(&self.components)
+    }
+}
+
+#[async_trait]
+/// Trait which exists so interactions can be responded to & followed up through a [`DiscordHttpClientReqSender`]
+/// Responses breaking a limit of the Discord api return [`Error::Validation`][crate::error::Error::Validation] without being sent.
+/// The methods of [`Interaction`] also track whether it has been responded to within its deadline, so are preferred.
+pub trait InteractionRequests {
+
+    /// Responds to an interaction, which must be done within 3 seconds of it being created.
+    async fn create_interaction_response(&self, interaction_id: Id<InteractionMarker>, token: &str, response: InteractionResponse) -> Result<()>;
+
+    /// Retrieves the message sent in response to an interaction.
+    async fn get_original_response(&self, application_id: Id<ApplicationMarker>, token: &str) -> Result<Message>;
+
+    /// Edits the message sent in response to an interaction, or sends the message of a deferred response.
+    async fn edit_original_response(&self, application_id: Id<ApplicationMarker>, token: &str, edit: EditMessage) -> Result<Message>;
+
+    /// Deletes the message sent in response to an interaction.
+    async fn delete_original_response(&self, application_id: Id<ApplicationMarker>, token: &str) -> Result<()>;
+
+    /// Sends a follow-up message to an interaction, which can be done for 15 minutes after it was created.
+    async fn create_followup(&self, application_id: Id<ApplicationMarker>, token: &str, message: InteractionMessage) -> Result<Message>;
+
+    /// Edits a follow-up message to an interaction.
+    async fn edit_followup(&self, application_id: Id<ApplicationMarker>, token: &str, message_id: Id<MessageMarker>, edit: EditMessage) -> Result<Message>;
+
+    /// Deletes a follow-up message to an interaction.
+    async fn delete_followup(&self, application_id: Id<ApplicationMarker>, token: &str, message_id: Id<MessageMarker>) -> Result<()>;
+}
+
+#[async_trait]
+impl InteractionRequests for DiscordHttpClientReqSender {
+
+    async fn create_interaction_response(&self, interaction_id: Id<InteractionMarker>, token: &str, mut response: InteractionResponse) -> Result<()> {
+        response.validate()?;
+
+        let files = response.take_files();
+
+        DiscordHttpRequest::new(DiscordHttpReqType::InteractionCallback { interaction_id, token: token.to_string() }, Method::POST)
+            .with_json(&response)?
+            .with_attachments(files)
+            .request(self.clone())
+            .await
+    }
+
+    async fn get_original_response(&self, application_id: Id<ApplicationMarker>, token: &str) -> Result<Message> {
+        DiscordHttpRequest::new(DiscordHttpReqType::OriginalInteractionResponse { application_id, token: token.to_string() }, Method::GET)
+            .request(self.clone())
+            .await
+    }
+
+    async fn edit_original_response(&self, application_id: Id<ApplicationMarker>, token: &str, mut edit: EditMessage) -> Result<Message> {
+        edit.validate()?;
+
+        let files = std::mem::take(&mut edit.files);
+
+        DiscordHttpRequest::new(DiscordHttpReqType::OriginalInteractionResponse { application_id, token: token.to_string() }, Method::PATCH)
+            .with_json(&edit)?
+            .with_attachments(files)
+            .request(self.clone())
+            .await
+    }
+
+    async fn delete_original_response(&self, application_id: Id<ApplicationMarker>, token: &str) -> Result<()> {
+        DiscordHttpRequest::new(DiscordHttpReqType::OriginalInteractionResponse { application_id, token: token.to_string() }, Method::DELETE)
+            .request(self.clone())
+            .await
+    }
+
+    async fn create_followup(&self, application_id: Id<ApplicationMarker>, token: &str, mut message: InteractionMessage) -> Result<Message> {
+        message.validate()?;
+
+        let files = std::mem::take(&mut message.files);
+
+        DiscordHttpRequest::new(DiscordHttpReqType::InteractionFollowups { application_id, token: token.to_string() }, Method::POST)
+            .with_json(&message)?
+            .with_attachments(files)
+            .request(self.clone())
+            .await
+    }
+
+    async fn edit_followup(&self, application_id: Id<ApplicationMarker>, token: &str, message_id: Id<MessageMarker>, mut edit: EditMessage) -> Result<Message> {
+        edit.validate()?;
+
+        let files = std::mem::take(&mut edit.files);
+
+        DiscordHttpRequest::new(DiscordHttpReqType::InteractionFollowup { application_id, token: token.to_string(), message_id }, Method::PATCH)
+            .with_json(&edit)?
+            .with_attachments(files)
+            .request(self.clone())
+            .await
+    }
+
+    async fn delete_followup(&self, application_id: Id<ApplicationMarker>, token: &str, message_id: Id<MessageMarker>) -> Result<()> {
+        DiscordHttpRequest::new(DiscordHttpReqType::InteractionFollowup { application_id, token: token.to_string(), message_id }, Method::DELETE)
+            .request(self.clone())
+            .await
+    }
+}
+
+impl Interaction {
+
+    /// Responds to the interaction, marking it as acknowledged so no warning is logged about its deadline.
+    /// A warning is logged instead when the deadline has already passed, as Discord will reject the response.
+    /// Interactions recieved through an [`InteractionServer`][crate::interaction_server::InteractionServer] are responded to within its HTTP response,
+    /// unless the response uploads files in which case it is sent to the callback endpoint.
+    pub async fn respond(&self, http: &DiscordHttpClientReqSender, response: InteractionResponse) -> Result<()> {
+
+        if self.acknowledgement.remaining().is_zero() {
+            tracing::warn!("Responding to interaction {} after its deadline has passed, the response will likely fail", self.id);
+        }
+
+        // Validated before the responder of an InteractionServer is taken, so a handler is able to fix the response & retry.
+        response.validate()?;
+
+        #[cfg(feature = "http-interactions")]
+        if self.acknowledgement.has_responder() {
+
+            // Files cant be sent within the HTTP response, so they are uploaded to the callback endpoint before the responder is taken.
+            let inline_response = match response.has_files() {
+                true => {
+                    http.create_interaction_response(self.id, &self.token, response).await?;
+                    None
+                },
+                false => Some(response),
+            };
+
+            match (self.acknowledgement.take_responder(), inline_response) {
+                (Some(responder), inline_response) => {
+                    responder.send(inline_response).map_err(|_| Error::ChannelClosed("InteractionServer response"))?;
+                },
+                // Another handler took the responder meanwhile, so the response is sent to the callback endpoint instead.
+                (None, Some(response)) => http.create_interaction_response(self.id, &self.token, response).await?,
+                (None, None) => {},
+            }
+
+            self.acknowledgement.acknowledge();
+            return Ok(())
+        }
+
+        http.create_interaction_response(self.id, &self.token, response).await?;
+        self.acknowledgement.acknowledge();
+        Ok(())
+    }
+
+    /// Responds to the interaction with a message.
+    pub async fn reply(&self, http: &DiscordHttpClientReqSender, message: InteractionMessage) -> Result<()> {
+        self.respond(http, InteractionResponse::message(message)).await
+    }
+
+    /// Acknowledges the interaction so the response can be sent later through [`Interaction::edit_response`]
+    pub async fn defer(&self, http: &DiscordHttpClientReqSender, ephemeral: bool) -> Result<()> {
+        self.respond(http, InteractionResponse::deferred_message(ephemeral)).await
+    }
+
+    /// Acknowledges a component interaction so the message of the component can be edited later.
+    pub async fn defer_update(&self, http: &DiscordHttpClientReqSender) -> Result<()> {
+        self.respond(http, InteractionResponse::deferred_update()).await
+    }
+
+    /// Responds to a component interaction by editing the message of the component.
+    pub async fn update_message(&self, http: &DiscordHttpClientReqSender, message: InteractionMessage) -> Result<()> {
+        self.respond(http, InteractionResponse::update_message(message)).await
+    }
+
+    /// Responds to an autocomplete interaction with choices for the focused option.
+    pub async fn autocomplete(&self, http: &DiscordHttpClientReqSender, choices: Vec<CommandOptionChoice>) -> Result<()> {
+        self.respond(http, InteractionResponse::autocomplete(choices)).await
+    }
+
+    /// Responds to the interaction with a popup modal.
+    pub async fn show_modal(&self, http: &DiscordHttpClientReqSender, modal: Modal) -> Result<()> {
+        self.respond(http, InteractionResponse::modal(modal)).await
+    }
+
+    /// Edits the response to the interaction, or sends the message of a deferred response.
+    pub async fn edit_response(&self, http: &DiscordHttpClientReqSender, edit: EditMessage) -> Result<Message> {
+        http.edit_original_response(self.application_id, &self.token, edit).await
+    }
+
+    /// Deletes the response to the interaction.
+    pub async fn delete_response(&self, http: &DiscordHttpClientReqSender) -> Result<()> {
+        http.delete_original_response(self.application_id, &self.token).await
+    }
+
+    /// Sends a follow-up message to the interaction.
+    pub async fn followup(&self, http: &DiscordHttpClientReqSender, message: InteractionMessage) -> Result<Message> {
+        http.create_followup(self.application_id, &self.token, message).await
+    }
+}
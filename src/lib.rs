@@ -3,14 +3,32 @@
 //! The library is currently in an extremely experimental state where changes are made rapidly and will be breaking.
 
 /// The base api url for Discord http requests.
-pub const BASE_API_URL : &'static str = "https://discord.com/api";
+pub const BASE_API_URL : &str = "https://discord.com/api";
 
 /// Represents the version of the discord api utilised by the library
 pub const DISCORD_API_VERSION: u32 = 10;
 
  
+pub mod error;
+pub mod snowflake;
+pub mod models;
 pub mod bot;
 pub mod websocket;
 pub mod gateway;
 pub mod gateway_structs;
-pub mod http;
\ No newline at end of file
+pub mod http;
+pub mod attachment;
+pub mod messages;
+pub mod commands;
+pub mod interactions;
+#[cfg(feature = "http-interactions")]
+pub mod interaction_server;
+pub mod cache;
+pub mod ratelimit;
+pub mod retry;
+pub mod event_handler;
+pub mod shard;
+#[cfg(test)]
+mod fixtures;
+
+pub use error::{Error, Result};
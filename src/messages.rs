@@ -0,0 +1,605 @@
+//! Requests to the [message routes][https://discord.com/developers/docs/resources/message] of the Discord api.
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::attachment::AttachmentFile;
+use crate::error::{Result, ValidationError};
+use crate::http::{DiscordHttpClientReqSender, DiscordHttpReqType, DiscordHttpRequest};
+use crate::models::component::Component;
+use crate::models::embed::Embed;
+use crate::models::message::{Message, MessageFlags, MessageReference};
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ChannelMarker, MessageMarker, RoleMarker, StickerMarker, UserMarker};
+
+/// The most characters the content of a message can contain.
+pub const MESSAGE_CONTENT_LIMIT: usize = 2000;
+
+/// The most embeds a message can contain.
+pub const MESSAGE_EMBED_LIMIT: usize = 10;
+
+/// The most stickers a message can contain.
+pub const MESSAGE_STICKER_LIMIT: usize = 3;
+
+/// The fewest & most messages which can be deleted by a single bulk delete.
+pub const BULK_DELETE_RANGE: (usize, usize) = (2, 100);
+
+/// Messages older than this can not be bulk deleted.
+pub const BULK_DELETE_MAX_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// The most messages which can be retrieved by a single request.
+pub const GET_MESSAGES_LIMIT: u8 = 100;
+
+#[derive(Serialize, Debug, Default)]
+/// [The body of a request creating a message][https://discord.com/developers/docs/resources/message#create-message]
+/// At least one of content, embeds, sticker_ids, components or a file is required.
+pub struct CreateMessage {
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The contents of the message, up to 2000 characters
+    pub content: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Used to verify a message was sent, up to 25 characters
+    pub nonce: Option<String>,
+
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    /// Whether the message is a text to speech message
+    pub tts: bool,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// The embedded content of the message, up to 10 embeds
+    pub embeds: Vec<Embed>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Which mentions within the message notify their target
+    pub allowed_mentions: Option<AllowedMentions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The message this message replies to or forwards
+    pub message_reference: Option<MessageReference>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// The components of the message, such as buttons, up to 5 action rows
+    pub components: Vec<Component>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// The ids of up to 3 stickers in the guild to send with the message
+    pub sticker_ids: Vec<Id<StickerMarker>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The flags of the message, only SUPPRESS_EMBEDS & SUPPRESS_NOTIFICATIONS can be set
+    pub flags: Option<MessageFlags>,
+
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    /// Whether Discord should check the nonce is unique within the past few minutes, returning the existing message if it isnt
+    pub enforce_nonce: bool,
+
+    #[serde(skip)]
+    /// The files uploaded with the message
+    pub files: Vec<AttachmentFile>,
+}
+
+impl CreateMessage {
+
+    /// Creates an empty [`CreateMessage`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the contents of the message.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Adds an embed to the message, created with an [`EmbedBuilder`][crate::models::embed::EmbedBuilder]
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embeds.push(embed);
+        self
+    }
+
+    /// Adds a row of components to the message, see [`ActionRow`][crate::models::component::ActionRow]
+    pub fn component(mut self, component: impl Into<Component>) -> Self {
+        self.components.push(component.into());
+        self
+    }
+
+    /// Adds a sticker to the message.
+    pub fn sticker(mut self, sticker_id: Id<StickerMarker>) -> Self {
+        self.sticker_ids.push(sticker_id);
+        self
+    }
+
+    /// Uploads a file with the message.
+    pub fn file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+        self
+    }
+
+    /// Replies to a message within the same channel, failing if the message doesnt exist.
+    pub fn reply_to(mut self, message_id: Id<MessageMarker>) -> Self {
+        self.message_reference = Some(MessageReference {
+            message_id: Some(message_id),
+            fail_if_not_exists: Some(true),
+            ..MessageReference::default()
+        });
+        self
+    }
+
+    /// Sets which mentions within the message notify their target.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    /// Sets the flags of the message.
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Sets whether the message is a text to speech message.
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.tts = tts;
+        self
+    }
+
+    /// Sets the nonce of the message, Discord returns the existing message if the nonce was used within the past few minutes.
+    pub fn nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = Some(nonce.into());
+        self.enforce_nonce = true;
+        self
+    }
+
+    /// Checks the message against the limits of the Discord api.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        let is_empty = self.content.as_deref().is_none_or(str::is_empty)
+            && self.embeds.is_empty()
+            && self.sticker_ids.is_empty()
+            && self.components.is_empty()
+            && self.files.is_empty();
+
+        if is_empty {
+            return Err(ValidationError::Empty { field: "message".to_string() })
+        }
+
+        if let Some(content) = &self.content {
+            ValidationError::check_length(|| "content".to_string(), content, MESSAGE_CONTENT_LIMIT)?;
+        }
+
+        if let Some(nonce) = &self.nonce {
+            ValidationError::check_length(|| "nonce".to_string(), nonce, 25)?;
+        }
+
+        ValidationError::check_count(|| "embeds".to_string(), self.embeds.len(), 0, MESSAGE_EMBED_LIMIT)?;
+        Embed::validate_all(&self.embeds)?;
+        Component::validate_all(&self.components)?;
+
+        ValidationError::check_count(|| "sticker_ids".to_string(), self.sticker_ids.len(), 0, MESSAGE_STICKER_LIMIT)
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+/// [The body of a request editing a message][https://discord.com/developers/docs/resources/message#edit-message]
+/// Fields which are None are left unchanged.
+pub struct EditMessage {
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The new contents of the message, up to 2000 characters
+    pub content: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The new embedded content of the message, up to 10 embeds
+    pub embeds: Option<Vec<Embed>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The new flags of the message, only SUPPRESS_EMBEDS can be set
+    pub flags: Option<MessageFlags>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Which mentions within the message notify their target
+    pub allowed_mentions: Option<AllowedMentions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The new components of the message, up to 5 action rows
+    pub components: Option<Vec<Component>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The existing attachments to keep, as partial attachments containing their id. Attachments which arent listed are removed.
+    pub attachments: Option<Vec<Value>>,
+
+    #[serde(skip)]
+    /// New files uploaded to the message
+    pub files: Vec<AttachmentFile>,
+}
+
+impl EditMessage {
+
+    /// Creates an [`EditMessage`] which changes nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the new contents of the message, an empty string removes the contents.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Sets the new embeds of the message, an empty list removes every embed.
+    pub fn embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.embeds = Some(embeds);
+        self
+    }
+
+    /// Sets the new components of the message, an empty list removes every component.
+    pub fn components(mut self, components: Vec<Component>) -> Self {
+        self.components = Some(components);
+        self
+    }
+
+    /// Sets the new flags of the message.
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Sets which mentions within the message notify their target.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    /// Uploads a new file to the message, existing attachments are kept unless attachments is set.
+    pub fn file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+        self
+    }
+
+    /// Checks the edit against the limits of the Discord api.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        if let Some(content) = &self.content {
+            ValidationError::check_length(|| "content".to_string(), content, MESSAGE_CONTENT_LIMIT)?;
+        }
+
+        if let Some(embeds) = &self.embeds {
+            ValidationError::check_count(|| "embeds".to_string(), embeds.len(), 0, MESSAGE_EMBED_LIMIT)?;
+            Embed::validate_all(embeds)?;
+        }
+
+        match &self.components {
+            Some(components) => Component::validate_all(components),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+/// [Which mentions within a message notify their target][https://discord.com/developers/docs/resources/message#allowed-mentions-object]
+/// The default allows no mentions at all.
+pub struct AllowedMentions {
+
+    /// The types of mention which are parsed from the content
+    pub parse: Vec<AllowedMentionType>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// The roles which can be mentioned, up to 100
+    pub roles: Vec<Id<RoleMarker>>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// The users which can be mentioned, up to 100
+    pub users: Vec<Id<UserMarker>>,
+
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    /// Whether the author of the message replied to is mentioned
+    pub replied_user: bool,
+}
+
+impl AllowedMentions {
+
+    /// Allows every type of mention.
+    pub fn all() -> Self {
+        Self {
+            parse: vec![AllowedMentionType::Roles, AllowedMentionType::Users, AllowedMentionType::Everyone],
+            replied_user: true,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+/// [A type of mention][https://discord.com/developers/docs/resources/message#allowed-mentions-object-allowed-mention-types] parsed from the content of a message.
+pub enum AllowedMentionType {
+
+    /// Role mentions
+    Roles,
+
+    /// User mentions
+    Users,
+
+    /// @everyone & @here mentions
+    Everyone,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Which messages are retrieved relative to a message when [retrieving the messages of a channel][https://discord.com/developers/docs/resources/message#get-channel-messages]
+/// Messages before or after a point in time can be retrieved using [`Snowflake::from_time`][crate::snowflake::Snowflake::from_time].
+pub enum MessagePagination {
+
+    /// The messages sent before the message
+    Before(Id<MessageMarker>),
+
+    /// The messages sent after the message
+    After(Id<MessageMarker>),
+
+    /// The messages sent around the message
+    Around(Id<MessageMarker>),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// The query of a request [retrieving the messages of a channel][https://discord.com/developers/docs/resources/message#get-channel-messages]
+/// The default retrieves the 50 most recent messages.
+pub struct GetMessages {
+
+    /// Which messages are retrieved, the most recent if None
+    pub pagination: Option<MessagePagination>,
+
+    /// The amount of messages retrieved, 1-100, 50 if None
+    pub limit: Option<u8>,
+}
+
+impl GetMessages {
+
+    /// Retrieves the messages sent before the message.
+    pub fn before(message_id: Id<MessageMarker>) -> Self {
+        Self { pagination: Some(MessagePagination::Before(message_id)), limit: None }
+    }
+
+    /// Retrieves the messages sent after the message.
+    pub fn after(message_id: Id<MessageMarker>) -> Self {
+        Self { pagination: Some(MessagePagination::After(message_id)), limit: None }
+    }
+
+    /// Retrieves the messages sent around the message.
+    pub fn around(message_id: Id<MessageMarker>) -> Self {
+        Self { pagination: Some(MessagePagination::Around(message_id)), limit: None }
+    }
+
+    /// Sets the amount of messages retrieved, 1-100.
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Serialize)]
+/// The body of a bulk delete request.
+struct BulkDeleteMessages<'a> {
+    messages: &'a [Id<MessageMarker>],
+}
+
+#[async_trait]
+/// Trait which exists so messages can be created, retrieved & deleted through a [`DiscordHttpClientReqSender`]
+/// Requests breaking a limit of the Discord api return [`Error::Validation`][crate::error::Error::Validation] without being sent.
+pub trait MessageRequests {
+
+    /// Sends a message in a channel.
+    async fn create_message(&self, channel_id: Id<ChannelMarker>, message: CreateMessage) -> Result<Message>;
+
+    /// Edits a message previously sent by the bot.
+    async fn edit_message(&self, channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>, edit: EditMessage) -> Result<Message>;
+
+    /// Deletes a message, requires MANAGE_MESSAGES for messages not sent by the bot.
+    async fn delete_message(&self, channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>) -> Result<()>;
+
+    /// Deletes 2-100 messages at once, none of which can be older than 2 weeks.
+    async fn bulk_delete_messages(&self, channel_id: Id<ChannelMarker>, message_ids: &[Id<MessageMarker>]) -> Result<()>;
+
+    /// Retrieves a single message.
+    async fn get_message(&self, channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>) -> Result<Message>;
+
+    /// Retrieves messages from a channel, newest first.
+    async fn get_messages(&self, channel_id: Id<ChannelMarker>, query: GetMessages) -> Result<Vec<Message>>;
+}
+
+#[async_trait]
+impl MessageRequests for DiscordHttpClientReqSender {
+
+    async fn create_message(&self, channel_id: Id<ChannelMarker>, mut message: CreateMessage) -> Result<Message> {
+        message.validate()?;
+
+        // The files are uploaded alongside the JSON body, which is sent as payload_json.
+        let files = std::mem::take(&mut message.files);
+
+        DiscordHttpRequest::new(DiscordHttpReqType::ChannelMessages { channel_id }, Method::POST)
+            .with_json(&message)?
+            .with_attachments(files)
+            .request(self.clone())
+            .await
+    }
+
+    async fn edit_message(&self, channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>, mut edit: EditMessage) -> Result<Message> {
+        edit.validate()?;
+
+        let files = std::mem::take(&mut edit.files);
+
+        DiscordHttpRequest::new(DiscordHttpReqType::ChannelMessage { channel_id, message_id }, Method::PATCH)
+            .with_json(&edit)?
+            .with_attachments(files)
+            .request(self.clone())
+            .await
+    }
+
+    async fn delete_message(&self, channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>) -> Result<()> {
+        DiscordHttpRequest::new(DiscordHttpReqType::ChannelMessage { channel_id, message_id }, Method::DELETE)
+            .request(self.clone())
+            .await
+    }
+
+    async fn bulk_delete_messages(&self, channel_id: Id<ChannelMarker>, message_ids: &[Id<MessageMarker>]) -> Result<()> {
+
+        let (min, max) = BULK_DELETE_RANGE;
+        ValidationError::check_count(|| "messages".to_string(), message_ids.len(), min, max)?;
+
+        // Discord rejects the whole request if any message is older than 2 weeks, which is known from the timestamp within its id.
+        let oldest_allowed = SystemTime::now() - BULK_DELETE_MAX_AGE;
+        if let Some(message_id) = message_ids.iter().find(|message_id| message_id.snowflake().created_at() < oldest_allowed) {
+            return Err(ValidationError::MessageTooOld { message_id: *message_id }.into())
+        }
+
+        DiscordHttpRequest::new(DiscordHttpReqType::BulkDeleteMessages { channel_id }, Method::POST)
+            .with_json(&BulkDeleteMessages { messages: message_ids })?
+            .request(self.clone())
+            .await
+    }
+
+    async fn get_message(&self, channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>) -> Result<Message> {
+        DiscordHttpRequest::new(DiscordHttpReqType::ChannelMessage { channel_id, message_id }, Method::GET)
+            .request(self.clone())
+            .await
+    }
+
+    async fn get_messages(&self, channel_id: Id<ChannelMarker>, query: GetMessages) -> Result<Vec<Message>> {
+
+        let mut request = DiscordHttpRequest::new(DiscordHttpReqType::ChannelMessages { channel_id }, Method::GET);
+
+        if let Some(limit) = query.limit {
+            if !(1..=GET_MESSAGES_LIMIT).contains(&limit) {
+                return Err(ValidationError::OutOfRange { field: "limit".to_string(), min: 1, max: GET_MESSAGES_LIMIT.into(), value: limit.into() }.into())
+            }
+
+            request = request.with_query("limit", limit);
+        }
+
+        request = match query.pagination {
+            Some(MessagePagination::Before(message_id)) => request.with_query("before", message_id),
+            Some(MessagePagination::After(message_id)) => request.with_query("after", message_id),
+            Some(MessagePagination::Around(message_id)) => request.with_query("around", message_id),
+            None => request,
+        };
+
+        request.request(self.clone()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::time::{Duration, SystemTime};
+
+    use serde_json::json;
+    use tokio::sync::mpsc;
+
+    use crate::error::{Error, Result, ValidationError};
+    use crate::http::{DiscordHttpClientReqSender, DiscordHttpRequest};
+    use crate::models::embed::EmbedBuilder;
+    use crate::snowflake::{Id, Snowflake};
+    use crate::snowflake::marker::MessageMarker;
+    use super::{CreateMessage, GetMessages, MessageRequests};
+
+    /// Makes a request through a channel in place of the [`DiscordHttpClient`][crate::http::DiscordHttpClient]
+    /// Returns the request which was sent, or the error if it was never sent.
+    async fn sent_request<T, F: Future<Output = Result<T>>>(request: impl FnOnce(DiscordHttpClientReqSender) -> F) -> Result<DiscordHttpRequest> {
+        let (sender, mut reciever) = mpsc::channel(1);
+
+        // Dropping the response sender fails the request once it was recieved, which finishes it.
+        let (result, sent) = tokio::join!(request(sender), async move { reciever.recv().await.map(|sent| sent.request) });
+        match (sent, result) {
+            (Some(sent), _) => Ok(sent),
+            (None, result) => Err(result.err().expect("a request which was never sent succeeded")),
+        }
+    }
+
+    /// The id of a message sent the duration ago.
+    fn sent_ago(ago: Duration) -> Id<MessageMarker> {
+        Snowflake::from_time(SystemTime::now() - ago).into()
+    }
+
+    fn query(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    #[tokio::test]
+    async fn bulk_delete_requires_2_to_100_messages() {
+        let recent = sent_ago(Duration::from_secs(60));
+
+        for count in [0, 1, 101] {
+            let message_ids = vec![recent; count];
+            let result = sent_request(|http| async move { http.bulk_delete_messages(Id::new(1), &message_ids).await }).await;
+            assert!(matches!(result, Err(Error::Validation(ValidationError::TooFew { .. } | ValidationError::TooMany { .. }))), "{count} messages: {result:?}");
+        }
+
+        for count in [2, 100] {
+            let message_ids = vec![recent; count];
+            let request = sent_request(|http| async move { http.bulk_delete_messages(Id::new(1), &message_ids).await }).await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(request.body.as_deref().unwrap()).unwrap();
+            assert_eq!(body["messages"].as_array().unwrap().len(), count);
+        }
+    }
+
+    #[tokio::test]
+    async fn bulk_delete_rejects_messages_older_than_2_weeks() {
+        let old = sent_ago(Duration::from_secs(15 * 24 * 60 * 60));
+        let message_ids = [sent_ago(Duration::from_secs(60)), old];
+
+        let result = sent_request(|http| async move { http.bulk_delete_messages(Id::new(1), &message_ids).await }).await;
+        assert!(matches!(result, Err(Error::Validation(ValidationError::MessageTooOld { message_id })) if message_id == old));
+
+        let message_ids = [sent_ago(Duration::from_secs(60)), sent_ago(Duration::from_secs(13 * 24 * 60 * 60))];
+        assert!(sent_request(|http| async move { http.bulk_delete_messages(Id::new(1), &message_ids).await }).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn get_messages_limit_is_between_1_and_100() {
+        for limit in [0, 101] {
+            let result = sent_request(|http| async move { http.get_messages(Id::new(1), GetMessages::default().limit(limit)).await }).await;
+            assert!(matches!(result, Err(Error::Validation(ValidationError::OutOfRange { min: 1, max: 100, .. }))), "limit {limit}: {result:?}");
+        }
+
+        for limit in [1, 100] {
+            let request = sent_request(|http| async move { http.get_messages(Id::new(1), GetMessages::default().limit(limit)).await }).await.unwrap();
+            assert_eq!(request.query, query(&[("limit", &limit.to_string())]));
+        }
+    }
+
+    #[tokio::test]
+    async fn get_messages_query_contains_the_pagination() {
+        let cases = [
+            (GetMessages::default(), query(&[])),
+            (GetMessages::before(Id::new(10)).limit(5), query(&[("limit", "5"), ("before", "10")])),
+            (GetMessages::after(Id::new(20)), query(&[("after", "20")])),
+            (GetMessages::around(Id::new(30)), query(&[("around", "30")])),
+        ];
+
+        for (get_messages, expected) in cases {
+            let request = sent_request(|http| async move { http.get_messages(Id::new(1), get_messages).await }).await.unwrap();
+            assert_eq!(request.query, expected);
+        }
+    }
+
+    #[test]
+    fn messages_must_contain_something() {
+        assert!(matches!(CreateMessage::new().validate(), Err(ValidationError::Empty { field }) if field == "message"));
+        assert!(matches!(CreateMessage::new().content("").validate(), Err(ValidationError::Empty { .. })));
+
+        assert!(CreateMessage::new().content("hello").validate().is_ok());
+        assert!(CreateMessage::new().embed(EmbedBuilder::new().title("hello").build().unwrap()).validate().is_ok());
+        assert!(CreateMessage::new().sticker(Id::new(1)).validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn empty_messages_arent_sent() {
+        let result = sent_request(|http| async move { http.create_message(Id::new(1), CreateMessage::new()).await }).await;
+        assert!(matches!(result, Err(Error::Validation(ValidationError::Empty { .. }))));
+
+        let request = sent_request(|http| async move { http.create_message(Id::new(1), CreateMessage::new().content("hello")).await }).await.unwrap();
+        assert_eq!(serde_json::from_slice::<serde_json::Value>(request.body.as_deref().unwrap()).unwrap(), json!({ "content": "hello" }));
+    }
+}
@@ -0,0 +1,227 @@
+//! Models of the core resources of the Discord api, shared by the Gateway events & the routes of the [`DiscordHttpClient`][crate::http::DiscordHttpClient].
+//!
+//! Fields which Discord may leave out are [`Option`] & are skipped when serialized, fields which are only ever null are also [`Option`].
+//! Booleans which Discord leaves out when false default to false.
+//! Enums keep values which arent known by the library within their Unknown variant instead of failing to decode,
+//! so newly added types from Discord never cause an event to be dropped.
+
+/// Creates an enum represented by an integer within Discord payloads, which keeps unknown values in an Unknown variant.
+macro_rules! enum_number {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident: $repr:ty {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal,
+            )*
+        }
+    ) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        $(#[$meta])*
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+
+            /// A value which isnt known by the library.
+            Unknown($repr),
+        }
+
+        impl From<$repr> for $name {
+            fn from(value: $repr) -> Self {
+                match value {
+                    $($value => Self::$variant,)*
+                    value => Self::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$name> for $repr {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                <$repr>::from(*self).serialize(serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <$repr as serde::Deserialize>::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+/// Implements (de)serializing a bitflags struct as its bits.
+/// Bits which arent known by the library are kept, so they are sent back to Discord & stored by the cache unchanged.
+macro_rules! bitflags_serde {
+    ($name:ident: $repr:ty) => {
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.bits().serialize(serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                // SAFETY: bitflags structs are plain wrappers of their bits, unknown bits only appear within the Debug output.
+                <$repr as serde::Deserialize>::deserialize(deserializer).map(|bits| unsafe { Self::from_bits_unchecked(bits) })
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::empty()
+            }
+        }
+    };
+}
+
+pub mod user;
+pub mod application;
+pub mod guild;
+pub mod channel;
+pub mod message;
+pub mod embed;
+pub mod component;
+pub mod command;
+pub mod interaction;
+pub mod permissions;
+pub mod audit_log;
+pub mod auto_moderation;
+pub mod monetization;
+pub mod presence;
+pub mod scheduled_event;
+pub mod voice;
+
+/// An [ISO8601 timestamp][https://discord.com/developers/docs/reference#iso8601-datetime] as sent by Discord, e.g: "2022-08-23T18:24:09.123000+00:00"
+pub type Timestamp = String;
+
+/// Formats a time as a [`Timestamp`] in UTC, e.g: "2022-08-23T18:24:09.123Z"
+pub fn timestamp_from_time(time: std::time::SystemTime) -> Timestamp {
+
+    let since_epoch = time.duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let (days, seconds_of_day) = ((seconds / 86400) as i64, seconds % 86400);
+
+    // Converts the days since the unix epoch into a civil date, from http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        seconds_of_day / 3600,
+        seconds_of_day / 60 % 60,
+        seconds_of_day % 60,
+        since_epoch.subsec_millis(),
+    )
+}
+
+/// Parses a [`Timestamp`] sent by Discord into a time, None if it isnt a valid ISO8601 timestamp.
+/// Both the "Z" & "+00:00" forms of the offset are accepted, alongside any amount of fractional digits.
+pub fn time_from_timestamp(timestamp: &str) -> Option<std::time::SystemTime> {
+
+    let number = |range: std::ops::Range<usize>| timestamp.get(range)?.parse::<i64>().ok();
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hours, minutes, seconds) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if timestamp.get(4..5)? != "-" || timestamp.get(7..8)? != "-" || !matches!(timestamp.get(10..11)?, "T" | " ") || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None
+    }
+
+    let rest = &timestamp[19..];
+    let fraction_length = rest.strip_prefix('.').map_or(0, |fraction| fraction.bytes().take_while(u8::is_ascii_digit).count() + 1);
+    let nanos = match fraction_length {
+        0 | 1 => 0,
+        _ => rest[1..fraction_length].bytes().take(9).enumerate().fold(0, |nanos, (index, digit)| nanos + u32::from(digit - b'0') * 10u32.pow(8 - index as u32)),
+    };
+
+    let offset = match &rest[fraction_length..] {
+        "Z" | "z" => 0,
+        offset if offset.len() == 6 && offset.get(3..4)? == ":" => {
+            let minutes = offset.get(1..3)?.parse::<i64>().ok()? * 60 + offset.get(4..6)?.parse::<i64>().ok()?;
+            match offset.get(..1)? {
+                "+" => minutes * 60,
+                "-" => minutes * -60,
+                _ => return None,
+            }
+        },
+        _ => return None,
+    };
+
+    // Converts the civil date into days since the unix epoch, from http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    let seconds = days * 86400 + hours * 3600 + minutes * 60 + seconds - offset;
+    let since_epoch = std::time::Duration::new(seconds.unsigned_abs(), 0);
+    match seconds >= 0 {
+        true => std::time::UNIX_EPOCH.checked_add(since_epoch)?.checked_add(std::time::Duration::from_nanos(nanos.into())),
+        false => std::time::UNIX_EPOCH.checked_sub(since_epoch)?.checked_add(std::time::Duration::from_nanos(nanos.into())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use serde_json::json;
+
+    use super::channel::{Channel, ChannelType};
+    use super::message::MessageFlags;
+    use super::{time_from_timestamp, timestamp_from_time};
+
+    #[test]
+    fn unknown_enum_values_are_kept() {
+        assert_eq!(serde_json::from_value::<ChannelType>(json!(0)).unwrap(), ChannelType::GuildText);
+        assert_eq!(serde_json::from_value::<ChannelType>(json!(99)).unwrap(), ChannelType::Unknown(99));
+        assert_eq!(serde_json::to_value(ChannelType::Unknown(99)).unwrap(), json!(99));
+
+        // A resource of a type added by Discord after the library still decodes.
+        let channel: Channel = serde_json::from_value(json!({ "id": "1", "type": 99 })).unwrap();
+        assert_eq!(channel.kind, ChannelType::Unknown(99));
+    }
+
+    #[test]
+    fn unknown_bits_are_kept() {
+        let bits = MessageFlags::EPHEMERAL.bits() | 1 << 30;
+        let flags: MessageFlags = serde_json::from_value(json!(bits)).unwrap();
+
+        assert!(flags.contains(MessageFlags::EPHEMERAL));
+        assert_eq!(serde_json::to_value(flags).unwrap(), json!(bits));
+    }
+
+    #[test]
+    fn timestamps_round_trip() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_661_279_049_123);
+        assert_eq!(time_from_timestamp(&timestamp_from_time(time)), Some(time));
+        assert_eq!(time_from_timestamp("2022-08-23T18:24:09.123000+00:00"), Some(time));
+        assert_eq!(time_from_timestamp("2022-08-23T20:24:09.123+02:00"), Some(time));
+    }
+
+    #[test]
+    fn invalid_timestamps_are_none() {
+        assert_eq!(time_from_timestamp("2022-08-23"), None);
+        assert_eq!(time_from_timestamp("2022-13-23T18:24:09+00:00"), None);
+        assert_eq!(time_from_timestamp("2022-08-23T18:24:09+0000"), None);
+        assert_eq!(time_from_timestamp("2022-08-23T18:24:09+0é00"), None);
+    }
+}
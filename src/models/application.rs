@@ -0,0 +1,21 @@
+//! The [Application][https://discord.com/developers/docs/resources/application] resource, which a bot belongs to.
+
+bitflags::bitflags! {
+
+    /// [The public flags of an application][https://discord.com/developers/docs/resources/application#application-object-application-flags]
+    /// Includes whether each privileged [`Intent`][crate::bot::Intents] is enabled within the developer page, the LIMITED flags are set instead for bots in fewer than 100 guilds.
+    pub struct ApplicationFlags: u64 {
+        const APPLICATION_AUTO_MODERATION_RULE_CREATE_BADGE = 1 << 6;
+        const GATEWAY_PRESENCE = 1 << 12;
+        const GATEWAY_PRESENCE_LIMITED = 1 << 13;
+        const GATEWAY_GUILD_MEMBERS = 1 << 14;
+        const GATEWAY_GUILD_MEMBERS_LIMITED = 1 << 15;
+        const VERIFICATION_PENDING_GUILD_LIMIT = 1 << 16;
+        const EMBEDDED = 1 << 17;
+        const GATEWAY_MESSAGE_CONTENT = 1 << 18;
+        const GATEWAY_MESSAGE_CONTENT_LIMITED = 1 << 19;
+        const APPLICATION_COMMAND_BADGE = 1 << 23;
+    }
+}
+
+bitflags_serde!(ApplicationFlags: u64);
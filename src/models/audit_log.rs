@@ -0,0 +1,123 @@
+//! The [Audit Log][https://discord.com/developers/docs/resources/audit-log] resource.
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::snowflake::Id;
+use crate::snowflake::marker::{AuditLogEntryMarker, GenericMarker, UserMarker};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [An administrative action within a guild][https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object] recorded in its audit log.
+pub struct AuditLogEntry {
+
+    /// The id of the entry
+    pub id: Id<AuditLogEntryMarker>,
+
+    /// The id of the affected resource, such as a user, role or webhook
+    pub target_id: Option<Id<GenericMarker>>,
+
+    #[serde(default)]
+    /// The changes made to the affected resource
+    pub changes: Vec<AuditLogChange>,
+
+    /// The id of the user or application which made the changes
+    pub user_id: Option<Id<UserMarker>>,
+
+    /// The type of the action which was taken
+    pub action_type: AuditLogEvent,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Additional information for certain types of actions
+    pub options: Option<Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The reason for the change
+    pub reason: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [A change made to a resource][https://discord.com/developers/docs/resources/audit-log#audit-log-change-object], the values are the type of the changed field so they arent decoded.
+pub struct AuditLogChange {
+
+    /// The name of the changed field, with some exceptions listed within the Discord documentation
+    pub key: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The new value of the field
+    pub new_value: Option<Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The old value of the field
+    pub old_value: Option<Value>,
+}
+
+enum_number! {
+    /// [The type of an action recorded in the audit log][https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events]
+    pub enum AuditLogEvent: u16 {
+        GuildUpdate = 1,
+        ChannelCreate = 10,
+        ChannelUpdate = 11,
+        ChannelDelete = 12,
+        ChannelOverwriteCreate = 13,
+        ChannelOverwriteUpdate = 14,
+        ChannelOverwriteDelete = 15,
+        MemberKick = 20,
+        MemberPrune = 21,
+        MemberBanAdd = 22,
+        MemberBanRemove = 23,
+        MemberUpdate = 24,
+        MemberRoleUpdate = 25,
+        MemberMove = 26,
+        MemberDisconnect = 27,
+        BotAdd = 28,
+        RoleCreate = 30,
+        RoleUpdate = 31,
+        RoleDelete = 32,
+        InviteCreate = 40,
+        InviteUpdate = 41,
+        InviteDelete = 42,
+        WebhookCreate = 50,
+        WebhookUpdate = 51,
+        WebhookDelete = 52,
+        EmojiCreate = 60,
+        EmojiUpdate = 61,
+        EmojiDelete = 62,
+        MessageDelete = 72,
+        MessageBulkDelete = 73,
+        MessagePin = 74,
+        MessageUnpin = 75,
+        IntegrationCreate = 80,
+        IntegrationUpdate = 81,
+        IntegrationDelete = 82,
+        StageInstanceCreate = 83,
+        StageInstanceUpdate = 84,
+        StageInstanceDelete = 85,
+        StickerCreate = 90,
+        StickerUpdate = 91,
+        StickerDelete = 92,
+        GuildScheduledEventCreate = 100,
+        GuildScheduledEventUpdate = 101,
+        GuildScheduledEventDelete = 102,
+        ThreadCreate = 110,
+        ThreadUpdate = 111,
+        ThreadDelete = 112,
+        ApplicationCommandPermissionUpdate = 121,
+        SoundboardSoundCreate = 130,
+        SoundboardSoundUpdate = 131,
+        SoundboardSoundDelete = 132,
+        AutoModerationRuleCreate = 140,
+        AutoModerationRuleUpdate = 141,
+        AutoModerationRuleDelete = 142,
+        AutoModerationBlockMessage = 143,
+        AutoModerationFlagToChannel = 144,
+        AutoModerationUserCommunicationDisabled = 145,
+        CreatorMonetizationRequestCreated = 150,
+        CreatorMonetizationTermsAccepted = 151,
+        OnboardingPromptCreate = 163,
+        OnboardingPromptUpdate = 164,
+        OnboardingPromptDelete = 165,
+        OnboardingCreate = 166,
+        OnboardingUpdate = 167,
+        HomeSettingsCreate = 190,
+        HomeSettingsUpdate = 191,
+    }
+}
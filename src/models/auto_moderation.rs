@@ -0,0 +1,143 @@
+//! The [Auto Moderation][https://discord.com/developers/docs/resources/auto-moderation] resource.
+use serde::{Deserialize, Serialize};
+
+use crate::snowflake::Id;
+use crate::snowflake::marker::{AutoModerationRuleMarker, ChannelMarker, GuildMarker, RoleMarker, UserMarker};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A rule which moderates a guild automatically][https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object] once triggered.
+pub struct AutoModerationRule {
+
+    /// The id of the rule
+    pub id: Id<AutoModerationRuleMarker>,
+
+    /// The id of the guild which the rule belongs to
+    pub guild_id: Id<GuildMarker>,
+
+    /// The name of the rule
+    pub name: String,
+
+    /// The id of the user who created the rule
+    pub creator_id: Id<UserMarker>,
+
+    /// The event which the rule is checked on
+    pub event_type: AutoModerationEventType,
+
+    /// What triggers the rule
+    pub trigger_type: AutoModerationTriggerType,
+
+    #[serde(default)]
+    /// Additional data utilised to decide whether the rule is triggered, depending on the trigger type
+    pub trigger_metadata: AutoModerationTriggerMetadata,
+
+    /// The actions taken once the rule is triggered
+    pub actions: Vec<AutoModerationAction>,
+
+    /// Whether the rule is enabled
+    pub enabled: bool,
+
+    #[serde(default)]
+    /// The ids of the roles which the rule doesnt apply to
+    pub exempt_roles: Vec<Id<RoleMarker>>,
+
+    #[serde(default)]
+    /// The ids of the channels which the rule doesnt apply to
+    pub exempt_channels: Vec<Id<ChannelMarker>>,
+}
+
+enum_number! {
+    /// [The event an auto moderation rule is checked on][https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-event-types]
+    pub enum AutoModerationEventType: u8 {
+        MessageSend = 1,
+        MemberUpdate = 2,
+    }
+}
+
+enum_number! {
+    /// [What triggers an auto moderation rule][https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-types]
+    pub enum AutoModerationTriggerType: u8 {
+        Keyword = 1,
+        Spam = 3,
+        KeywordPreset = 4,
+        MentionSpam = 5,
+        MemberProfile = 6,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// [Additional data of an auto moderation rule][https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-metadata] utilised to decide whether it is triggered.
+pub struct AutoModerationTriggerMetadata {
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The substrings which trigger the rule
+    pub keyword_filter: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The regular expressions which trigger the rule
+    pub regex_patterns: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The wordsets defined by Discord which trigger the rule
+    pub presets: Vec<KeywordPresetType>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The substrings which never trigger the rule
+    pub allow_list: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The amount of unique role & user mentions allowed within a message
+    pub mention_total_limit: Option<u8>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether mention raids are detected automatically
+    pub mention_raid_protection_enabled: Option<bool>,
+}
+
+enum_number! {
+    /// [A wordset defined by Discord][https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-keyword-preset-types]
+    pub enum KeywordPresetType: u8 {
+        Profanity = 1,
+        SexualContent = 2,
+        Slurs = 3,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [An action taken once an auto moderation rule is triggered][https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-action-object]
+pub struct AutoModerationAction {
+
+    #[serde(rename = "type")]
+    /// The type of the action
+    pub kind: AutoModerationActionType,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Additional data of the action, depending on its type
+    pub metadata: Option<AutoModerationActionMetadata>,
+}
+
+enum_number! {
+    /// [The type of an auto moderation action][https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-action-object-action-types]
+    pub enum AutoModerationActionType: u8 {
+        BlockMessage = 1,
+        SendAlertMessage = 2,
+        Timeout = 3,
+        BlockMemberInteraction = 4,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// [Additional data of an auto moderation action][https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-action-object-action-metadata]
+pub struct AutoModerationActionMetadata {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The channel which alerts are sent to, for SendAlertMessage actions
+    pub channel_id: Option<Id<ChannelMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// How long the member is timed out for in seconds, for Timeout actions
+    pub duration_seconds: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The message shown to the member when their message is blocked, for BlockMessage actions
+    pub custom_message: Option<String>,
+}
@@ -0,0 +1,331 @@
+//! The [Channel][https://discord.com/developers/docs/resources/channel] resource, which includes threads.
+use serde::{Deserialize, Serialize};
+
+use crate::models::Timestamp;
+use crate::models::guild::Member;
+use crate::models::permissions::Permissions;
+use crate::models::user::User;
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ApplicationMarker, ChannelMarker, EmojiMarker, GenericMarker, GuildMarker, MessageMarker, UserMarker};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A channel][https://discord.com/developers/docs/resources/channel#channel-object] within a guild, a direct message or a thread.
+/// Which fields are sent depends on the type of the channel.
+pub struct Channel {
+
+    /// The id of the channel
+    pub id: Id<ChannelMarker>,
+
+    #[serde(rename = "type")]
+    /// The type of the channel
+    pub kind: ChannelType,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild, may be missing for some channel objects recieved over gateway guild dispatches
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The sorting position of the channel
+    pub position: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The explicit permission overwrites for members and roles
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The name of the channel, 1-100 characters
+    pub name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The topic of the channel, 0-4096 characters for forum & media channels, 0-1024 characters for all others
+    pub topic: Option<String>,
+
+    #[serde(default)]
+    /// Whether the channel is NSFW
+    pub nsfw: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the last message sent in this channel, or thread for forum & media channels
+    pub last_message_id: Option<Id<MessageMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The bitrate in bits of the voice channel
+    pub bitrate: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The user limit of the voice channel
+    pub user_limit: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The amount of seconds a user has to wait before sending another message (slowmode), 0-21600
+    pub rate_limit_per_user: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The recipients of the direct message
+    pub recipients: Vec<User>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The icon hash of the group direct message
+    pub icon: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the creator of the group direct message or thread
+    pub owner_id: Option<Id<UserMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The application id of the group direct message creator if it is bot-created
+    pub application_id: Option<Id<ApplicationMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the group direct message is managed by an application
+    pub managed: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the parent category for a guild channel, or the id of the channel a thread was created in
+    pub parent_id: Option<Id<ChannelMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the last pinned message was pinned
+    pub last_pin_timestamp: Option<Timestamp>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The voice region id of the voice channel, None for automatic
+    pub rtc_region: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The camera video quality mode of the voice channel
+    pub video_quality_mode: Option<VideoQualityMode>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The number of messages in the thread, excluding the initial message & deleted messages
+    pub message_count: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// An approximate count of the users in the thread, stops counting at 50
+    pub member_count: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Thread specific fields not needed by other channels
+    pub thread_metadata: Option<ThreadMetadata>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The thread member of the current user, if they have joined the thread
+    pub member: Option<ThreadMember>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The default duration in minutes after which newly created threads are hidden from the channel list
+    pub default_auto_archive_duration: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The computed permissions of the invoking user in the channel, only sent within an interaction
+    pub permissions: Option<Permissions>,
+
+    #[serde(default)]
+    /// The flags of the channel
+    pub flags: ChannelFlags,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The number of messages ever sent in a thread
+    pub total_message_sent: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The tags which can be applied to threads in a forum or media channel
+    pub available_tags: Vec<ForumTag>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The ids of the tags applied to a thread in a forum or media channel
+    pub applied_tags: Vec<Id<GenericMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The emoji shown in the add reaction button on threads in a forum or media channel
+    pub default_reaction_emoji: Option<DefaultReaction>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The slowmode copied to newly created threads
+    pub default_thread_rate_limit_per_user: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The default sort order of posts in a forum or media channel
+    pub default_sort_order: Option<SortOrderType>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The default layout of posts in a forum channel
+    pub default_forum_layout: Option<ForumLayoutType>,
+}
+
+impl Channel {
+
+    /// The mention of the channel which can be placed within a message, e.g: <#41771983423143937>
+    pub fn mention(&self) -> String {
+        format!("<#{}>", self.id)
+    }
+}
+
+enum_number! {
+    /// [The type of a channel][https://discord.com/developers/docs/resources/channel#channel-object-channel-types]
+    pub enum ChannelType: u8 {
+        GuildText = 0,
+        Dm = 1,
+        GuildVoice = 2,
+        GroupDm = 3,
+        GuildCategory = 4,
+        GuildAnnouncement = 5,
+        AnnouncementThread = 10,
+        PublicThread = 11,
+        PrivateThread = 12,
+        GuildStageVoice = 13,
+        GuildDirectory = 14,
+        GuildForum = 15,
+        GuildMedia = 16,
+    }
+}
+
+impl ChannelType {
+
+    /// Whether channels of this type are threads.
+    pub fn is_thread(self) -> bool {
+        matches!(self, Self::AnnouncementThread | Self::PublicThread | Self::PrivateThread)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A permission overwrite][https://discord.com/developers/docs/resources/channel#overwrite-object] for a role or member within a channel.
+pub struct PermissionOverwrite {
+
+    /// The id of the role or user
+    pub id: Id<GenericMarker>,
+
+    #[serde(rename = "type")]
+    /// Whether the overwrite applies to a role or a member
+    pub kind: PermissionOverwriteType,
+
+    /// The permissions which are allowed
+    pub allow: Permissions,
+
+    /// The permissions which are denied
+    pub deny: Permissions,
+}
+
+enum_number! {
+    /// What a [`PermissionOverwrite`] applies to.
+    pub enum PermissionOverwriteType: u8 {
+        Role = 0,
+        Member = 1,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [Thread specific fields][https://discord.com/developers/docs/resources/channel#thread-metadata-object] of a channel.
+pub struct ThreadMetadata {
+
+    /// Whether the thread is archived
+    pub archived: bool,
+
+    /// The duration in minutes after which the thread is hidden from the channel list, 60, 1440, 4320 or 10080
+    pub auto_archive_duration: u32,
+
+    /// When the archive status of the thread was last changed
+    pub archive_timestamp: Timestamp,
+
+    /// Whether the thread is locked, only users with MANAGE_THREADS can unarchive it
+    pub locked: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether non-moderators can add other non-moderators to a private thread
+    pub invitable: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the thread was created, only sent for threads created after 2022-01-09
+    pub create_timestamp: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A user which has joined a thread][https://discord.com/developers/docs/resources/channel#thread-member-object]
+pub struct ThreadMember {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the thread, not sent within GUILD_CREATE
+    pub id: Option<Id<ChannelMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the user, not sent within GUILD_CREATE
+    pub user_id: Option<Id<UserMarker>>,
+
+    /// When the user last joined the thread
+    pub join_timestamp: Timestamp,
+
+    /// Flags used for notification settings
+    pub flags: u32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The guild member of the user
+    pub member: Option<Member>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A tag][https://discord.com/developers/docs/resources/channel#forum-tag-object] which can be applied to threads in a forum or media channel.
+pub struct ForumTag {
+
+    /// The id of the tag
+    pub id: Id<GenericMarker>,
+
+    /// The name of the tag, 0-20 characters
+    pub name: String,
+
+    /// Whether the tag can only be added or removed by members with MANAGE_THREADS
+    pub moderated: bool,
+
+    /// The id of the custom emoji of the tag
+    pub emoji_id: Option<Id<EmojiMarker>>,
+
+    /// The unicode character of the emoji of the tag
+    pub emoji_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The default reaction emoji][https://discord.com/developers/docs/resources/channel#default-reaction-object] of a forum or media channel.
+pub struct DefaultReaction {
+
+    /// The id of the custom emoji
+    pub emoji_id: Option<Id<EmojiMarker>>,
+
+    /// The unicode character of the emoji
+    pub emoji_name: Option<String>,
+}
+
+enum_number! {
+    /// [The camera video quality mode][https://discord.com/developers/docs/resources/channel#channel-object-video-quality-modes] of a voice channel.
+    pub enum VideoQualityMode: u8 {
+        Auto = 1,
+        Full = 2,
+    }
+}
+
+enum_number! {
+    /// [How posts are sorted][https://discord.com/developers/docs/resources/channel#channel-object-sort-order-types] in a forum or media channel.
+    pub enum SortOrderType: u8 {
+        LatestActivity = 0,
+        CreationDate = 1,
+    }
+}
+
+enum_number! {
+    /// [How posts are shown][https://discord.com/developers/docs/resources/channel#channel-object-forum-layout-types] in a forum channel.
+    pub enum ForumLayoutType: u8 {
+        NotSet = 0,
+        ListView = 1,
+        GalleryView = 2,
+    }
+}
+
+bitflags::bitflags! {
+
+    /// [The flags of a channel][https://discord.com/developers/docs/resources/channel#channel-object-channel-flags]
+    pub struct ChannelFlags: u32 {
+        const PINNED = 1 << 1;
+        const REQUIRE_TAG = 1 << 4;
+        const HIDE_MEDIA_DOWNLOAD_OPTIONS = 1 << 15;
+    }
+}
+
+bitflags_serde!(ChannelFlags: u32);
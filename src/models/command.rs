@@ -0,0 +1,952 @@
+//! The [Application Command][https://discord.com/developers/docs/interactions/application-commands] model & the [`CommandBuilder`] which checks it against the limits of Discord.
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ValidationError;
+use crate::models::channel::ChannelType;
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ApplicationMarker, CommandMarker, GenericMarker, GuildMarker};
+
+/// The most characters the name of a command, option or choice can contain.
+pub const COMMAND_NAME_LIMIT: usize = 32;
+
+/// The most characters the description of a command or option can contain.
+pub const COMMAND_DESCRIPTION_LIMIT: usize = 100;
+
+/// The most options a command or option can contain.
+pub const COMMAND_OPTION_LIMIT: usize = 25;
+
+/// The most choices an option can contain.
+pub const COMMAND_CHOICE_LIMIT: usize = 25;
+
+/// The most characters the name of a choice, or the value of a string choice, can contain.
+pub const COMMAND_CHOICE_TEXT_LIMIT: usize = 100;
+
+/// The highest min_length & max_length of a string option.
+pub const COMMAND_OPTION_LENGTH_LIMIT: u16 = 6000;
+
+/// The most chat input commands an application can have globally or within a guild.
+pub const CHAT_INPUT_COMMAND_LIMIT: usize = 100;
+
+/// The most user or message commands an application can have globally or within a guild.
+pub const CONTEXT_MENU_COMMAND_LIMIT: usize = 5;
+
+/// The names & descriptions of a command or option in other [locales][https://discord.com/developers/docs/reference#locales], keyed by the locale e.g: "en-GB"
+pub type Localizations = HashMap<String, String>;
+
+enum_number! {
+    #[derive(Default)]
+    /// [The type of an application command][https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-types]
+    pub enum CommandType: u8 {
+        /// A slash command
+        #[default]
+        ChatInput = 1,
+        /// A command shown when right clicking a user
+        User = 2,
+        /// A command shown when right clicking a message
+        Message = 3,
+        /// The command which launches an activity
+        PrimaryEntryPoint = 4,
+    }
+}
+
+enum_number! {
+    /// [Where a command can be used][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-context-types]
+    pub enum InteractionContextType: u8 {
+        Guild = 0,
+        BotDm = 1,
+        PrivateChannel = 2,
+    }
+}
+
+enum_number! {
+    /// [Where an application can be installed][https://discord.com/developers/docs/resources/application#application-object-application-integration-types]
+    pub enum ApplicationIntegrationType: u8 {
+        GuildInstall = 0,
+        UserInstall = 1,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+/// [An application command][https://discord.com/developers/docs/interactions/application-commands#application-command-object]
+/// The [`CommandBuilder`] is utilised to create one within the limits of Discord.
+pub struct ApplicationCommand {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the command, None for commands which havent been created yet
+    pub id: Option<Id<CommandMarker>>,
+
+    #[serde(rename = "type", default)]
+    /// The type of the command
+    pub kind: CommandType,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the application which owns the command
+    pub application_id: Option<Id<ApplicationMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild of the command, None for global commands
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    /// The name of the command, 1-32 characters
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The name of the command in other locales
+    pub name_localizations: Option<Localizations>,
+
+    #[serde(default)]
+    /// The description of chat input commands, 1-100 characters. Empty for user & message commands
+    pub description: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The description of the command in other locales
+    pub description_localizations: Option<Localizations>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The parameters of a chat input command, up to 25
+    pub options: Vec<CommandOption>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The permissions a member requires to use the command by default, as a string of bits. "0" only allows admins
+    pub default_member_permissions: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Where the command can be used, every context if None
+    pub contexts: Option<Vec<InteractionContextType>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Where the application must be installed for the command to be usable
+    pub integration_types: Option<Vec<ApplicationIntegrationType>>,
+
+    #[serde(default)]
+    /// Whether the command is age restricted
+    pub nsfw: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Autoincrementing version identifier updated whenever the command is changed
+    pub version: Option<Id<GenericMarker>>,
+}
+
+impl ApplicationCommand {
+
+    /// Checks the command against the limits of Discord, the field of the error is relative to the command.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        let is_chat_input = self.kind == CommandType::ChatInput;
+
+        validate_name("name", &self.name, is_chat_input)?;
+        for (locale, name) in self.name_localizations.iter().flatten() {
+            validate_name(&format!("name_localizations.{locale}"), name, is_chat_input)?;
+        }
+
+        match is_chat_input {
+            true => {
+                validate_description("description", &self.description)?;
+                for (locale, description) in self.description_localizations.iter().flatten() {
+                    validate_description(&format!("description_localizations.{locale}"), description)?;
+                }
+            },
+            false => {
+                if !self.description.is_empty() {
+                    return Err(ValidationError::Invalid { field: "description".to_string(), reason: "only chat input commands can have a description".to_string() })
+                }
+
+                if !self.options.is_empty() {
+                    return Err(ValidationError::Invalid { field: "options".to_string(), reason: "only chat input commands can have options".to_string() })
+                }
+            },
+        }
+
+        validate_options(&self.options, OptionLevel::Command)
+    }
+
+    /// Whether the command is the same as another as far as Discord is concerned, utilised to find which registered commands need editing.
+    /// Fields set by Discord such as the id & version are ignored, missing localizations equal empty ones & unset contexts equal the defaults.
+    /// Sub commands are compared regardless of their order, as the client lists them by name, whereas the order of other options is kept.
+    pub fn is_equivalent(&self, other: &ApplicationCommand) -> bool {
+        self.kind == other.kind
+            && self.name == other.name
+            && self.description == other.description
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && localizations_eq(&self.description_localizations, &other.description_localizations)
+            && options_eq(&self.options, &other.options)
+            && self.default_member_permissions == other.default_member_permissions
+            && unordered_eq(&self.contexts_or_default(), &other.contexts_or_default())
+            && unordered_eq(&self.integration_types_or_default(), &other.integration_types_or_default())
+            && self.nsfw == other.nsfw
+    }
+
+    /// The contexts the command can be used in, every context if they arent set.
+    fn contexts_or_default(&self) -> Vec<InteractionContextType> {
+        self.contexts.clone().unwrap_or_else(|| vec![InteractionContextType::Guild, InteractionContextType::BotDm, InteractionContextType::PrivateChannel])
+    }
+
+    /// Where the application must be installed for the command to be usable, only guilds if they arent set.
+    fn integration_types_or_default(&self) -> Vec<ApplicationIntegrationType> {
+        self.integration_types.clone().unwrap_or_else(|| vec![ApplicationIntegrationType::GuildInstall])
+    }
+
+    /// Checks a list of commands which are registered together, globally or within a guild.
+    /// Discord limits the amount of each type of command & requires their names to be unique within each type.
+    pub fn validate_all(commands: &[ApplicationCommand]) -> Result<(), ValidationError> {
+
+        for (index, command) in commands.iter().enumerate() {
+            command.validate().map_err(|error| error.prefixed(&format!("commands[{index}]")))?;
+
+            if commands[..index].iter().any(|other| other.kind == command.kind && other.name == command.name) {
+                return Err(ValidationError::Invalid { field: format!("commands[{index}].name"), reason: format!("another command of the same type is named {}", command.name) })
+            }
+        }
+
+        let count = |kind: CommandType| commands.iter().filter(|command| command.kind == kind).count();
+        ValidationError::check_count(|| "commands".to_string(), count(CommandType::ChatInput), 0, CHAT_INPUT_COMMAND_LIMIT)?;
+        ValidationError::check_count(|| "commands".to_string(), count(CommandType::User), 0, CONTEXT_MENU_COMMAND_LIMIT)?;
+        ValidationError::check_count(|| "commands".to_string(), count(CommandType::Message), 0, CONTEXT_MENU_COMMAND_LIMIT)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The permissions of a command within a guild][https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-guild-application-command-permissions-structure]
+pub struct GuildCommandPermissions {
+
+    /// The id of the command, or the id of the application when the permissions apply to every command without their own
+    pub id: Id<GenericMarker>,
+
+    /// The id of the application the command belongs to
+    pub application_id: Id<ApplicationMarker>,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The permissions of the command within the guild
+    pub permissions: Vec<CommandPermission>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+/// [Whether a role, user or channel can use a command][https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-application-command-permissions-structure]
+pub struct CommandPermission {
+
+    /// The id of the role, user or channel, the id of the guild for @everyone & the id of the guild minus 1 for every channel
+    pub id: Id<GenericMarker>,
+
+    #[serde(rename = "type")]
+    /// The type of resource the permission is for
+    pub kind: CommandPermissionType,
+
+    /// Whether the command is allowed
+    pub permission: bool,
+}
+
+enum_number! {
+    /// [The type of resource a command permission is for][https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-application-command-permission-type]
+    pub enum CommandPermissionType: u8 {
+        Role = 1,
+        User = 2,
+        Channel = 3,
+    }
+}
+
+enum_number! {
+    /// [The type of a command option][https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-type]
+    pub enum CommandOptionType: u8 {
+        SubCommand = 1,
+        SubCommandGroup = 2,
+        String = 3,
+        /// Any integer between -2^53 & 2^53
+        Integer = 4,
+        Boolean = 5,
+        User = 6,
+        Channel = 7,
+        Role = 8,
+        /// Users & roles
+        Mentionable = 9,
+        /// Any double between -2^53 & 2^53
+        Number = 10,
+        Attachment = 11,
+    }
+}
+
+impl CommandOptionType {
+
+    /// Whether the option is a sub command or sub command group rather than a parameter.
+    pub fn is_sub_command(&self) -> bool {
+        matches!(self, Self::SubCommand | Self::SubCommandGroup)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [A parameter or sub command of a chat input command][https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-structure]
+pub struct CommandOption {
+
+    #[serde(rename = "type")]
+    /// The type of the option
+    pub kind: CommandOptionType,
+
+    /// The name of the option, 1-32 characters
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The name of the option in other locales
+    pub name_localizations: Option<Localizations>,
+
+    /// The description of the option, 1-100 characters
+    pub description: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The description of the option in other locales
+    pub description_localizations: Option<Localizations>,
+
+    #[serde(default)]
+    /// Whether the option must be filled in, required options must come before optional ones
+    pub required: bool,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The values a string, integer or number option is limited to, up to 25
+    pub choices: Vec<CommandOptionChoice>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The options of a sub command, or the sub commands of a sub command group
+    pub options: Vec<CommandOption>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The types of channel which can be chosen for a channel option, any type if empty
+    pub channel_types: Vec<ChannelType>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The lowest value of an integer or number option
+    pub min_value: Option<CommandOptionValue>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The highest value of an integer or number option
+    pub max_value: Option<CommandOptionValue>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The fewest characters of a string option, 0-6000
+    pub min_length: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The most characters of a string option, 1-6000
+    pub max_length: Option<u16>,
+
+    #[serde(default)]
+    /// Whether autocomplete interactions are sent while a string, integer or number option is filled in, can not be used alongside choices
+    pub autocomplete: bool,
+}
+
+impl CommandOption {
+
+    /// Creates an optional option of the type.
+    pub fn new(kind: CommandOptionType, name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+            name_localizations: None,
+            description: description.into(),
+            description_localizations: None,
+            required: false,
+            choices: Vec::new(),
+            options: Vec::new(),
+            channel_types: Vec::new(),
+            min_value: None,
+            max_value: None,
+            min_length: None,
+            max_length: None,
+            autocomplete: false,
+        }
+    }
+
+    /// Creates a sub command containing the options.
+    pub fn sub_command(name: impl Into<String>, description: impl Into<String>, options: Vec<CommandOption>) -> Self {
+        Self {
+            options,
+            ..Self::new(CommandOptionType::SubCommand, name, description)
+        }
+    }
+
+    /// Creates a group of sub commands.
+    pub fn sub_command_group(name: impl Into<String>, description: impl Into<String>, sub_commands: Vec<CommandOption>) -> Self {
+        Self {
+            options: sub_commands,
+            ..Self::new(CommandOptionType::SubCommandGroup, name, description)
+        }
+    }
+
+    /// Sets whether the option must be filled in.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Adds a value the option is limited to.
+    pub fn choice(mut self, name: impl Into<String>, value: impl Into<CommandOptionValue>) -> Self {
+        self.choices.push(CommandOptionChoice::new(name, value));
+        self
+    }
+
+    /// Adds a sub option, such as an option of a sub command.
+    pub fn option(mut self, option: CommandOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Limits the types of channel which can be chosen for a channel option.
+    pub fn channel_types(mut self, channel_types: Vec<ChannelType>) -> Self {
+        self.channel_types = channel_types;
+        self
+    }
+
+    /// Sets the lowest value of an integer or number option.
+    pub fn min_value(mut self, min_value: impl Into<CommandOptionValue>) -> Self {
+        self.min_value = Some(min_value.into());
+        self
+    }
+
+    /// Sets the highest value of an integer or number option.
+    pub fn max_value(mut self, max_value: impl Into<CommandOptionValue>) -> Self {
+        self.max_value = Some(max_value.into());
+        self
+    }
+
+    /// Sets the fewest & most characters of a string option.
+    pub fn length(mut self, min_length: u16, max_length: u16) -> Self {
+        self.min_length = Some(min_length);
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets whether autocomplete interactions are sent while the option is filled in.
+    pub fn autocomplete(mut self, autocomplete: bool) -> Self {
+        self.autocomplete = autocomplete;
+        self
+    }
+
+    /// Sets the name of the option in another locale.
+    pub fn name_localization(mut self, locale: impl Into<String>, name: impl Into<String>) -> Self {
+        self.name_localizations.get_or_insert_with(HashMap::new).insert(locale.into(), name.into());
+        self
+    }
+
+    /// Sets the description of the option in another locale.
+    pub fn description_localization(mut self, locale: impl Into<String>, description: impl Into<String>) -> Self {
+        self.description_localizations.get_or_insert_with(HashMap::new).insert(locale.into(), description.into());
+        self
+    }
+
+    /// Whether the option is the same as another as far as Discord is concerned, see [`ApplicationCommand::is_equivalent`]
+    pub fn is_equivalent(&self, other: &CommandOption) -> bool {
+        self.kind == other.kind
+            && self.name == other.name
+            && self.description == other.description
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && localizations_eq(&self.description_localizations, &other.description_localizations)
+            && self.required == other.required
+            && self.choices.len() == other.choices.len()
+            && self.choices.iter().zip(&other.choices).all(|(choice, other)| choice.is_equivalent(other))
+            && options_eq(&self.options, &other.options)
+            && unordered_eq(&self.channel_types, &other.channel_types)
+            && option_value_eq(&self.min_value, &other.min_value)
+            && option_value_eq(&self.max_value, &other.max_value)
+            && self.min_length == other.min_length
+            && self.max_length == other.max_length
+            && self.autocomplete == other.autocomplete
+    }
+
+    /// Checks the option against the limits of Discord, the field of the error is relative to the option.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        let invalid = |field: &str, reason: &str| Err(ValidationError::Invalid { field: field.to_string(), reason: reason.to_string() });
+
+        validate_name("name", &self.name, true)?;
+        for (locale, name) in self.name_localizations.iter().flatten() {
+            validate_name(&format!("name_localizations.{locale}"), name, true)?;
+        }
+
+        validate_description("description", &self.description)?;
+        for (locale, description) in self.description_localizations.iter().flatten() {
+            validate_description(&format!("description_localizations.{locale}"), description)?;
+        }
+
+        let has_choices = matches!(self.kind, CommandOptionType::String | CommandOptionType::Integer | CommandOptionType::Number);
+        if !self.choices.is_empty() && !has_choices {
+            return invalid("choices", "only string, integer & number options can have choices")
+        }
+
+        if self.autocomplete && (!has_choices || !self.choices.is_empty()) {
+            return invalid("autocomplete", "only string, integer & number options without choices can autocomplete")
+        }
+
+        ValidationError::check_count(|| "choices".to_string(), self.choices.len(), 0, COMMAND_CHOICE_LIMIT)?;
+        for (index, choice) in self.choices.iter().enumerate() {
+            choice.validate().map_err(|error| error.prefixed(&format!("choices[{index}]")))?;
+        }
+
+        if !self.channel_types.is_empty() && self.kind != CommandOptionType::Channel {
+            return invalid("channel_types", "only channel options can have channel types")
+        }
+
+        let is_numeric = matches!(self.kind, CommandOptionType::Integer | CommandOptionType::Number);
+        if (self.min_value.is_some() || self.max_value.is_some()) && !is_numeric {
+            return invalid("min_value", "only integer & number options can have a min_value or max_value")
+        }
+
+        if let (Some(min_value), Some(max_value)) = (&self.min_value, &self.max_value) {
+            if min_value.as_f64() > max_value.as_f64() {
+                return invalid("min_value", "min_value is higher than max_value")
+            }
+        }
+
+        if (self.min_length.is_some() || self.max_length.is_some()) && self.kind != CommandOptionType::String {
+            return invalid("min_length", "only string options can have a min_length or max_length")
+        }
+
+        let limit = i64::from(COMMAND_OPTION_LENGTH_LIMIT);
+        let check_range = |field: &str, value: Option<u16>, min: i64| match value.map(i64::from) {
+            Some(value) if !(min..=limit).contains(&value) => Err(ValidationError::OutOfRange { field: field.to_string(), min, max: limit, value }),
+            _ => Ok(()),
+        };
+
+        check_range("min_length", self.min_length, 0)?;
+        check_range("max_length", self.max_length, 1)?;
+
+        match self.kind {
+            CommandOptionType::SubCommandGroup => validate_options(&self.options, OptionLevel::SubCommandGroup),
+            CommandOptionType::SubCommand => validate_options(&self.options, OptionLevel::SubCommand),
+            _ if !self.options.is_empty() => invalid("options", "only sub commands & sub command groups can have options"),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [A value an option is limited to][https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-choice-structure]
+pub struct CommandOptionChoice {
+
+    /// The name of the choice shown to the user, 1-100 characters
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The name of the choice in other locales
+    pub name_localizations: Option<Localizations>,
+
+    /// The value of the choice, of the same type as the option
+    pub value: CommandOptionValue,
+}
+
+impl CommandOptionChoice {
+
+    /// Creates a choice without localizations.
+    pub fn new(name: impl Into<String>, value: impl Into<CommandOptionValue>) -> Self {
+        Self {
+            name: name.into(),
+            name_localizations: None,
+            value: value.into(),
+        }
+    }
+
+    /// Sets the name of the choice in another locale.
+    pub fn name_localization(mut self, locale: impl Into<String>, name: impl Into<String>) -> Self {
+        self.name_localizations.get_or_insert_with(HashMap::new).insert(locale.into(), name.into());
+        self
+    }
+
+    /// Whether the choice is the same as another as far as Discord is concerned, integer & number values are compared by their value.
+    pub fn is_equivalent(&self, other: &CommandOptionChoice) -> bool {
+        self.name == other.name
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && self.value.is_equivalent(&other.value)
+    }
+
+    /// Checks the choice against the limits of Discord.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        let check_name = |field: String, name: &str| match name.is_empty() {
+            true => Err(ValidationError::Empty { field }),
+            false => ValidationError::check_length(|| field, name, COMMAND_CHOICE_TEXT_LIMIT),
+        };
+
+        check_name("name".to_string(), &self.name)?;
+        for (locale, name) in self.name_localizations.iter().flatten() {
+            check_name(format!("name_localizations.{locale}"), name)?;
+        }
+
+        match &self.value {
+            CommandOptionValue::String(value) => ValidationError::check_length(|| "value".to_string(), value, COMMAND_CHOICE_TEXT_LIMIT),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+/// The value of a choice, the limits of an integer or number option, or the value of an option within an interaction.
+pub enum CommandOptionValue {
+    Integer(i64),
+    Number(f64),
+    String(String),
+    Boolean(bool),
+}
+
+impl CommandOptionValue {
+
+    /// The value as a double, None for strings & booleans.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(value) => Some(*value as f64),
+            Self::Number(value) => Some(*value),
+            Self::String(_) | Self::Boolean(_) => None,
+        }
+    }
+
+    /// Whether the value is the same as another, regardless of whether they are integers or numbers e.g: 5 & 5.0
+    pub fn is_equivalent(&self, other: &CommandOptionValue) -> bool {
+        match (self, other) {
+            (Self::String(value), Self::String(other)) => value == other,
+            (Self::Boolean(value), Self::Boolean(other)) => value == other,
+            _ => self.as_f64().is_some() && self.as_f64() == other.as_f64(),
+        }
+    }
+}
+
+impl From<i64> for CommandOptionValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f64> for CommandOptionValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<bool> for CommandOptionValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<String> for CommandOptionValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for CommandOptionValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Builds an [`ApplicationCommand`], checking it against the limits of Discord once [`CommandBuilder::build`] is called.
+pub struct CommandBuilder {
+    command: ApplicationCommand,
+}
+
+impl CommandBuilder {
+
+    /// Creates a builder of a command with the type, name & description.
+    pub fn new(kind: CommandType, name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            command: ApplicationCommand {
+                kind,
+                name: name.into(),
+                description: description.into(),
+                ..Default::default()
+            }
+        }
+    }
+
+    /// Creates a builder of a slash command.
+    pub fn chat_input(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self::new(CommandType::ChatInput, name, description)
+    }
+
+    /// Creates a builder of a command shown when right clicking a user.
+    pub fn user(name: impl Into<String>) -> Self {
+        Self::new(CommandType::User, name, String::new())
+    }
+
+    /// Creates a builder of a command shown when right clicking a message.
+    pub fn message(name: impl Into<String>) -> Self {
+        Self::new(CommandType::Message, name, String::new())
+    }
+
+    /// Adds an option to a chat input command.
+    pub fn option(mut self, option: CommandOption) -> Self {
+        self.command.options.push(option);
+        self
+    }
+
+    /// Sets the name of the command in another locale.
+    pub fn name_localization(mut self, locale: impl Into<String>, name: impl Into<String>) -> Self {
+        self.command.name_localizations.get_or_insert_with(HashMap::new).insert(locale.into(), name.into());
+        self
+    }
+
+    /// Sets the description of the command in another locale.
+    pub fn description_localization(mut self, locale: impl Into<String>, description: impl Into<String>) -> Self {
+        self.command.description_localizations.get_or_insert_with(HashMap::new).insert(locale.into(), description.into());
+        self
+    }
+
+    /// Sets the permissions a member requires to use the command by default, 0 only allows admins.
+    pub fn default_member_permissions(mut self, permissions: u64) -> Self {
+        self.command.default_member_permissions = Some(permissions.to_string());
+        self
+    }
+
+    /// Sets where the command can be used.
+    pub fn contexts(mut self, contexts: Vec<InteractionContextType>) -> Self {
+        self.command.contexts = Some(contexts);
+        self
+    }
+
+    /// Sets where the application must be installed for the command to be usable.
+    pub fn integration_types(mut self, integration_types: Vec<ApplicationIntegrationType>) -> Self {
+        self.command.integration_types = Some(integration_types);
+        self
+    }
+
+    /// Sets whether the command is age restricted.
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.command.nsfw = nsfw;
+        self
+    }
+
+    /// Checks the command against the limits of Discord, returning the [`ApplicationCommand`] if it is within them.
+    pub fn build(self) -> Result<ApplicationCommand, ValidationError> {
+        self.command.validate()?;
+        Ok(self.command)
+    }
+}
+
+/// Where a list of options is placed, which limits the types of option it can contain.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OptionLevel {
+    Command,
+    SubCommandGroup,
+    SubCommand,
+}
+
+/// Compares localizations where missing localizations equal empty ones, as Discord may return either.
+fn localizations_eq(localizations: &Option<Localizations>, other: &Option<Localizations>) -> bool {
+    let non_empty = |localizations: &Option<Localizations>| localizations.as_ref().filter(|localizations| !localizations.is_empty()).cloned();
+    non_empty(localizations) == non_empty(other)
+}
+
+/// Compares lists of options, sub commands are matched by name & other options are compared in order.
+fn options_eq(options: &[CommandOption], other: &[CommandOption]) -> bool {
+
+    if options.len() != other.len() {
+        return false
+    }
+
+    match options.iter().all(|option| option.kind.is_sub_command()) {
+        true => options.iter().all(|option| other.iter().any(|other| other.name == option.name && option.is_equivalent(other))),
+        false => options.iter().zip(other).all(|(option, other)| option.is_equivalent(other)),
+    }
+}
+
+/// Compares optional integer or number values by their value.
+fn option_value_eq(value: &Option<CommandOptionValue>, other: &Option<CommandOptionValue>) -> bool {
+    match (value, other) {
+        (Some(value), Some(other)) => value.is_equivalent(other),
+        (value, other) => value.is_none() && other.is_none(),
+    }
+}
+
+/// Compares lists regardless of the order of their items.
+fn unordered_eq<T: PartialEq>(items: &[T], other: &[T]) -> bool {
+    items.len() == other.len()
+        && items.iter().all(|item| other.contains(item))
+        && other.iter().all(|item| items.contains(item))
+}
+
+/// Checks a list of options follows the nesting rules of Discord & that required options come first.
+fn validate_options(options: &[CommandOption], level: OptionLevel) -> Result<(), ValidationError> {
+
+    ValidationError::check_count(|| "options".to_string(), options.len(), 0, COMMAND_OPTION_LIMIT)?;
+
+    let sub_commands = options.iter().filter(|option| option.kind.is_sub_command()).count();
+    let reason = match level {
+        OptionLevel::Command if sub_commands > 0 && sub_commands < options.len() => Some("sub commands can not be mixed with other options"),
+        OptionLevel::SubCommandGroup if options.iter().any(|option| option.kind != CommandOptionType::SubCommand) => Some("sub command groups can only contain sub commands"),
+        OptionLevel::SubCommand if sub_commands > 0 => Some("sub commands can not contain sub commands"),
+        _ => None,
+    };
+
+    if let Some(reason) = reason {
+        return Err(ValidationError::Invalid { field: "options".to_string(), reason: reason.to_string() })
+    }
+
+    for (index, option) in options.iter().enumerate() {
+        let path = format!("options[{index}]");
+        option.validate().map_err(|error| error.prefixed(&path))?;
+
+        if option.required && options[..index].iter().any(|previous| !previous.required && !previous.kind.is_sub_command()) {
+            return Err(ValidationError::Invalid { field: path, reason: "required options must come before optional options".to_string() })
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the name of a command or option, names of chat input commands & options must be lowercase without spaces.
+fn validate_name(field: &str, name: &str, is_chat_input: bool) -> Result<(), ValidationError> {
+
+    if name.is_empty() {
+        return Err(ValidationError::Empty { field: field.to_string() })
+    }
+
+    ValidationError::check_length(|| field.to_string(), name, COMMAND_NAME_LIMIT)?;
+
+    let is_valid = |character: char| (character.is_alphanumeric() || character == '-' || character == '_') && !character.is_uppercase();
+    match is_chat_input && !name.chars().all(is_valid) {
+        true => Err(ValidationError::Invalid { field: field.to_string(), reason: "names must be lowercase & can only contain letters, numbers, - & _".to_string() }),
+        false => Ok(()),
+    }
+}
+
+/// Checks the description of a chat input command or option.
+fn validate_description(field: &str, description: &str) -> Result<(), ValidationError> {
+    match description.is_empty() {
+        true => Err(ValidationError::Empty { field: field.to_string() }),
+        false => ValidationError::check_length(|| field.to_string(), description, COMMAND_DESCRIPTION_LIMIT),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::error::ValidationError;
+    use super::{ApplicationCommand, CommandBuilder, CommandOption, CommandOptionType, InteractionContextType};
+
+    fn sub_command(name: &str) -> CommandOption {
+        CommandOption::sub_command(name, "description", Vec::new())
+    }
+
+    fn string_option(name: &str) -> CommandOption {
+        CommandOption::new(CommandOptionType::String, name, "description")
+    }
+
+    fn command(options: Vec<CommandOption>) -> ApplicationCommand {
+        options.into_iter().fold(CommandBuilder::chat_input("command", "description"), CommandBuilder::option).build().unwrap()
+    }
+
+    /// The command as Discord returns it once registered, with the fields Discord sets.
+    fn registered(command: &ApplicationCommand) -> ApplicationCommand {
+        let mut value = serde_json::to_value(command).unwrap();
+        value["id"] = json!("10");
+        value["application_id"] = json!("20");
+        value["version"] = json!("30");
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn chat_input_names_must_be_lowercase() {
+        assert!(CommandBuilder::chat_input("ping-pong_2", "description").build().is_ok());
+        assert!(matches!(CommandBuilder::chat_input("Ping", "description").build(), Err(ValidationError::Invalid { field, .. }) if field == "name"));
+        assert!(matches!(CommandBuilder::chat_input("ping pong", "description").build(), Err(ValidationError::Invalid { .. })));
+
+        // Context menu commands are shown as written, so any name is allowed.
+        assert!(CommandBuilder::user("Ping Pong").build().is_ok());
+    }
+
+    #[test]
+    fn required_options_must_come_before_optional_options() {
+        let command = CommandBuilder::chat_input("command", "description")
+            .option(string_option("first"))
+            .option(string_option("second").required(true))
+            .build();
+        assert!(matches!(command, Err(ValidationError::Invalid { field, .. }) if field == "options[1]"));
+
+        let command = CommandBuilder::chat_input("command", "description")
+            .option(string_option("first").required(true))
+            .option(string_option("second"))
+            .build();
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn sub_commands_cant_be_mixed_with_other_options() {
+        let command = CommandBuilder::chat_input("command", "description")
+            .option(sub_command("sub"))
+            .option(string_option("option"))
+            .build();
+        assert!(matches!(command, Err(ValidationError::Invalid { field, .. }) if field == "options"));
+
+        let group = CommandOption::sub_command_group("group", "description", vec![sub_command("sub"), string_option("option")]);
+        let command = CommandBuilder::chat_input("command", "description").option(group).build();
+        assert!(matches!(command, Err(ValidationError::Invalid { field, .. }) if field == "options[0].options"));
+    }
+
+    #[test]
+    fn commands_of_the_same_type_need_unique_names() {
+        let chat_input = CommandBuilder::chat_input("name", "description").build().unwrap();
+        let user = CommandBuilder::user("name").build().unwrap();
+        assert!(ApplicationCommand::validate_all(&[chat_input.clone(), user]).is_ok());
+
+        let result = ApplicationCommand::validate_all(&[chat_input.clone(), chat_input]);
+        assert!(matches!(result, Err(ValidationError::Invalid { field, .. }) if field == "commands[1].name"));
+    }
+
+    #[test]
+    fn fields_set_by_discord_are_ignored() {
+        let declared = command(vec![string_option("first")]);
+        assert!(declared.is_equivalent(&registered(&declared)));
+    }
+
+    #[test]
+    fn sub_commands_are_compared_regardless_of_order() {
+        let declared = command(vec![sub_command("first"), sub_command("second")]);
+        assert!(declared.is_equivalent(&command(vec![sub_command("second"), sub_command("first")])));
+        assert!(!declared.is_equivalent(&command(vec![sub_command("first"), sub_command("third")])));
+    }
+
+    #[test]
+    fn options_are_compared_in_order() {
+        let declared = command(vec![string_option("first"), string_option("second")]);
+        assert!(declared.is_equivalent(&command(vec![string_option("first"), string_option("second")])));
+        assert!(!declared.is_equivalent(&command(vec![string_option("second"), string_option("first")])));
+    }
+
+    #[test]
+    fn missing_localizations_equal_empty_ones() {
+        let declared = command(Vec::new());
+        let mut registered = registered(&declared);
+        registered.name_localizations = Some(Default::default());
+        registered.description_localizations = Some(Default::default());
+        assert!(declared.is_equivalent(&registered));
+
+        let localized = CommandBuilder::chat_input("command", "description").name_localization("fr", "commande").build().unwrap();
+        assert!(!declared.is_equivalent(&localized));
+    }
+
+    #[test]
+    fn integer_and_number_choices_are_compared_by_value() {
+        let choices = |value: serde_json::Value| serde_json::from_value::<ApplicationCommand>(json!({
+            "name": "command",
+            "description": "description",
+            "options": [{ "type": 10, "name": "number", "description": "description", "choices": [{ "name": "five", "value": value }] }],
+        })).unwrap();
+
+        assert!(choices(json!(5)).is_equivalent(&choices(json!(5.0))));
+        assert!(!choices(json!(5)).is_equivalent(&choices(json!(5.5))));
+        assert!(!choices(json!(5)).is_equivalent(&choices(json!("5"))));
+    }
+
+    #[test]
+    fn unset_contexts_equal_every_context() {
+        let declared = command(Vec::new());
+        let mut registered = registered(&declared);
+        registered.contexts = Some(vec![InteractionContextType::PrivateChannel, InteractionContextType::Guild, InteractionContextType::BotDm]);
+        assert!(declared.is_equivalent(&registered));
+
+        registered.contexts = Some(vec![InteractionContextType::Guild]);
+        assert!(!declared.is_equivalent(&registered));
+    }
+}
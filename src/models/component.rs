@@ -0,0 +1,837 @@
+//! [Message components][https://discord.com/developers/docs/interactions/message-components] such as buttons & select menus, which are placed within action rows.
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::error::ValidationError;
+use crate::models::channel::ChannelType;
+use crate::models::guild::Emoji;
+use crate::snowflake::Id;
+use crate::snowflake::marker::GenericMarker;
+
+/// The most action rows a message or modal can contain.
+pub const ACTION_ROW_LIMIT: usize = 5;
+
+/// The most buttons an action row can contain.
+pub const BUTTONS_PER_ROW_LIMIT: usize = 5;
+
+/// The most characters a custom_id can contain.
+pub const CUSTOM_ID_LIMIT: usize = 100;
+
+/// The most characters the label of a button can contain.
+pub const BUTTON_LABEL_LIMIT: usize = 80;
+
+/// The most options a string select menu can contain, which is also the most values which can be selected.
+pub const SELECT_OPTION_LIMIT: usize = 25;
+
+/// The most characters the label, value or description of a select option can contain.
+pub const SELECT_OPTION_TEXT_LIMIT: usize = 100;
+
+/// The most characters the placeholder of a select menu or text input can contain.
+pub const PLACEHOLDER_LIMIT: usize = 150;
+
+/// The most characters the label of a text input can contain.
+pub const TEXT_INPUT_LABEL_LIMIT: usize = 45;
+
+/// The most characters the value of a text input can contain.
+pub const TEXT_INPUT_VALUE_LIMIT: usize = 4000;
+
+enum_number! {
+    /// [The type of a component][https://discord.com/developers/docs/interactions/message-components#component-object-component-types]
+    pub enum ComponentType: u8 {
+        ActionRow = 1,
+        Button = 2,
+        StringSelect = 3,
+        TextInput = 4,
+        UserSelect = 5,
+        RoleSelect = 6,
+        MentionableSelect = 7,
+        ChannelSelect = 8,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// [A component][https://discord.com/developers/docs/interactions/message-components#component-object] of a message or modal.
+/// Components of a type which isnt known by the library are kept in their raw form.
+pub enum Component {
+
+    /// A container for other components
+    ActionRow(ActionRow),
+
+    /// A clickable button
+    Button(Button),
+
+    /// A dropdown menu of options, users, roles, mentionables or channels
+    SelectMenu(SelectMenu),
+
+    /// A text field within a modal
+    TextInput(TextInput),
+
+    /// A component which isnt known by the library
+    Unknown(Value),
+}
+
+impl Component {
+
+    /// The type of the component.
+    pub fn kind(&self) -> ComponentType {
+        match self {
+            Self::ActionRow(_) => ComponentType::ActionRow,
+            Self::Button(_) => ComponentType::Button,
+            Self::SelectMenu(select_menu) => select_menu.kind.into(),
+            Self::TextInput(_) => ComponentType::TextInput,
+            Self::Unknown(value) => ComponentType::from(value["type"].as_u64().unwrap_or_default() as u8),
+        }
+    }
+
+    /// Checks the component against the limits of Discord, the field of the error is relative to the component.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::ActionRow(action_row) => action_row.validate(),
+            Self::Button(button) => button.validate(),
+            Self::SelectMenu(select_menu) => select_menu.validate(),
+            Self::TextInput(text_input) => text_input.validate(),
+            Self::Unknown(_) => Ok(()),
+        }
+    }
+
+    /// Checks the top level components of a message or modal, which must be up to 5 action rows.
+    pub fn validate_all(components: &[Component]) -> Result<(), ValidationError> {
+
+        ValidationError::check_count(|| "components".to_string(), components.len(), 0, ACTION_ROW_LIMIT)?;
+
+        for (index, component) in components.iter().enumerate() {
+            let path = format!("components[{index}]");
+
+            match component {
+                Self::ActionRow(action_row) => action_row.validate().map_err(|error| error.prefixed(&path))?,
+                Self::Unknown(_) => {},
+                _ => return Err(ValidationError::Invalid { field: path, reason: "only action rows can be placed at the top level".to_string() }),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+
+        let value = match self {
+            Self::ActionRow(action_row) => serde_json::to_value(action_row),
+            Self::Button(button) => serde_json::to_value(button),
+            Self::SelectMenu(select_menu) => serde_json::to_value(select_menu),
+            Self::TextInput(text_input) => serde_json::to_value(text_input),
+            Self::Unknown(value) => return value.serialize(serializer),
+        };
+
+        // The type of the component is only known by the variant, so it is added to the fields of the component.
+        let mut value = value.map_err(ser::Error::custom)?;
+        value["type"] = Value::from(u8::from(self.kind()));
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+
+        let value = Value::deserialize(deserializer)?;
+        let kind = value.get("type")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| de::Error::missing_field("type"))?;
+
+        let component = match ComponentType::from(kind as u8) {
+            ComponentType::ActionRow => ActionRow::deserialize(value).map(Self::ActionRow),
+            ComponentType::Button => Button::deserialize(value).map(Self::Button),
+            ComponentType::StringSelect
+            | ComponentType::UserSelect
+            | ComponentType::RoleSelect
+            | ComponentType::MentionableSelect
+            | ComponentType::ChannelSelect => SelectMenu::deserialize(value).map(Self::SelectMenu),
+            ComponentType::TextInput => TextInput::deserialize(value).map(Self::TextInput),
+            ComponentType::Unknown(_) => Ok(Self::Unknown(value)),
+        };
+
+        component.map_err(de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+/// [A row of components][https://discord.com/developers/docs/interactions/message-components#action-rows]
+/// Contains up to 5 buttons, a single select menu or a single text input.
+pub struct ActionRow {
+
+    /// The components within the row
+    pub components: Vec<Component>,
+}
+
+impl ActionRow {
+
+    /// Creates an empty [`ActionRow`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a button to the row, a row can contain up to 5 buttons.
+    pub fn button(mut self, button: Button) -> Self {
+        self.components.push(Component::Button(button));
+        self
+    }
+
+    /// Adds a select menu to the row, which must be the only component within it.
+    pub fn select_menu(mut self, select_menu: SelectMenu) -> Self {
+        self.components.push(Component::SelectMenu(select_menu));
+        self
+    }
+
+    /// Adds a text input to the row, which must be the only component within it.
+    pub fn text_input(mut self, text_input: TextInput) -> Self {
+        self.components.push(Component::TextInput(text_input));
+        self
+    }
+
+    /// Checks the row follows the nesting rules of Discord & its components are within their limits.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        if self.components.is_empty() {
+            return Err(ValidationError::Empty { field: "components".to_string() })
+        }
+
+        let buttons = self.components.iter().filter(|component| matches!(component, Component::Button(_))).count();
+        let others = self.components.len() - buttons;
+
+        if buttons > 0 && others > 0 {
+            return Err(ValidationError::Invalid { field: "components".to_string(), reason: "buttons can not share a row with other components".to_string() })
+        }
+
+        match buttons > 0 {
+            true => ValidationError::check_count(|| "components".to_string(), buttons, 1, BUTTONS_PER_ROW_LIMIT)?,
+            false => ValidationError::check_count(|| "components".to_string(), others, 1, 1)?,
+        }
+
+        for (index, component) in self.components.iter().enumerate() {
+            if let Component::ActionRow(_) = component {
+                return Err(ValidationError::Invalid { field: format!("components[{index}]"), reason: "action rows can not be nested".to_string() })
+            }
+
+            component.validate().map_err(|error| error.prefixed(&format!("components[{index}]")))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<ActionRow> for Component {
+    fn from(action_row: ActionRow) -> Self {
+        Self::ActionRow(action_row)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// Builds the components of a message or modal, checking they follow the nesting rules & limits of Discord once [`ComponentsBuilder::build`] is called.
+pub struct ComponentsBuilder {
+    rows: Vec<ActionRow>,
+}
+
+impl ComponentsBuilder {
+
+    /// Creates a new [`ComponentsBuilder`] without any rows.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a row of components, up to 5 rows.
+    pub fn row(mut self, row: ActionRow) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Checks the rows against the limits of Discord, returning the components if they are within them.
+    pub fn build(self) -> Result<Vec<Component>, ValidationError> {
+        let components: Vec<Component> = self.rows.into_iter().map(Component::ActionRow).collect();
+        Component::validate_all(&components)?;
+        Ok(components)
+    }
+}
+
+enum_number! {
+    /// [The style of a button][https://discord.com/developers/docs/interactions/message-components#button-object-button-styles]
+    pub enum ButtonStyle: u8 {
+        Primary = 1,
+        Secondary = 2,
+        Success = 3,
+        Danger = 4,
+        Link = 5,
+        Premium = 6,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [A clickable button][https://discord.com/developers/docs/interactions/message-components#buttons]
+/// Link buttons have a url instead of a custom_id, premium buttons only have a sku_id.
+pub struct Button {
+
+    /// The style of the button
+    pub style: ButtonStyle,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The text shown on the button, up to 80 characters
+    pub label: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The emoji shown on the button
+    pub emoji: Option<Box<Emoji>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The developer defined identifier of the button, up to 100 characters
+    pub custom_id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the SKU a premium button purchases
+    pub sku_id: Option<Id<GenericMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The url a link button opens
+    pub url: Option<String>,
+
+    #[serde(default)]
+    /// Whether the button is disabled
+    pub disabled: bool,
+}
+
+impl Button {
+
+    /// Creates a button which sends an interaction with the custom_id when clicked.
+    pub fn new(style: ButtonStyle, custom_id: impl Into<String>) -> Self {
+        Self {
+            style,
+            label: None,
+            emoji: None,
+            custom_id: Some(custom_id.into()),
+            sku_id: None,
+            url: None,
+            disabled: false,
+        }
+    }
+
+    /// Creates a button which opens the url when clicked.
+    pub fn link(url: impl Into<String>) -> Self {
+        Self {
+            custom_id: None,
+            url: Some(url.into()),
+            ..Self::new(ButtonStyle::Link, String::new())
+        }
+    }
+
+    /// Creates a button which purchases the SKU when clicked.
+    pub fn premium(sku_id: Id<GenericMarker>) -> Self {
+        Self {
+            custom_id: None,
+            sku_id: Some(sku_id),
+            ..Self::new(ButtonStyle::Premium, String::new())
+        }
+    }
+
+    /// Sets the text shown on the button.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the emoji shown on the button.
+    pub fn emoji(mut self, emoji: Emoji) -> Self {
+        self.emoji = Some(Box::new(emoji));
+        self
+    }
+
+    /// Sets whether the button is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Checks the button against the limits of Discord & the fields its style requires.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        let invalid = |field: &str, reason: &str| Err(ValidationError::Invalid { field: field.to_string(), reason: reason.to_string() });
+
+        match self.style {
+            ButtonStyle::Link => {
+                if self.url.is_none() {
+                    return invalid("url", "link buttons require a url")
+                }
+
+                if self.custom_id.is_some() {
+                    return invalid("custom_id", "link buttons can not have a custom_id")
+                }
+            },
+            ButtonStyle::Premium => {
+                if self.sku_id.is_none() {
+                    return invalid("sku_id", "premium buttons require a sku_id")
+                }
+
+                if self.custom_id.is_some() || self.url.is_some() || self.label.is_some() || self.emoji.is_some() {
+                    return invalid("sku_id", "premium buttons can only have a sku_id")
+                }
+            },
+            _ => {
+                if self.url.is_some() {
+                    return invalid("url", "only link buttons can have a url")
+                }
+
+                validate_custom_id(self.custom_id.as_deref())?;
+            },
+        }
+
+        match &self.label {
+            Some(label) => ValidationError::check_length(|| "label".to_string(), label, BUTTON_LABEL_LIMIT),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<Button> for Component {
+    fn from(button: Button) -> Self {
+        Self::Button(button)
+    }
+}
+
+enum_number! {
+    /// The types of component which are select menus.
+    pub enum SelectMenuType: u8 {
+        String = 3,
+        User = 5,
+        Role = 6,
+        Mentionable = 7,
+        Channel = 8,
+    }
+}
+
+impl From<SelectMenuType> for ComponentType {
+    fn from(kind: SelectMenuType) -> Self {
+        Self::from(u8::from(kind))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [A dropdown menu][https://discord.com/developers/docs/interactions/message-components#select-menus] of options, users, roles, mentionables or channels.
+pub struct SelectMenu {
+
+    #[serde(rename = "type")]
+    /// The type of the select menu
+    pub kind: SelectMenuType,
+
+    /// The developer defined identifier of the select menu, up to 100 characters
+    pub custom_id: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The options of a string select menu, up to 25
+    pub options: Vec<SelectOption>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The types of channel which can be selected within a channel select menu
+    pub channel_types: Vec<ChannelType>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The text shown when nothing is selected, up to 150 characters
+    pub placeholder: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The users, roles or channels selected by default within an auto populated select menu
+    pub default_values: Vec<SelectDefaultValue>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The fewest items which must be selected, 0-25, 1 if None
+    pub min_values: Option<u8>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The most items which can be selected, 1-25, 1 if None
+    pub max_values: Option<u8>,
+
+    #[serde(default)]
+    /// Whether the select menu is disabled
+    pub disabled: bool,
+}
+
+impl SelectMenu {
+
+    /// Creates a select menu of the type with no options.
+    pub fn new(kind: SelectMenuType, custom_id: impl Into<String>) -> Self {
+        Self {
+            kind,
+            custom_id: custom_id.into(),
+            options: Vec::new(),
+            channel_types: Vec::new(),
+            placeholder: None,
+            default_values: Vec::new(),
+            min_values: None,
+            max_values: None,
+            disabled: false,
+        }
+    }
+
+    /// Creates a select menu of developer defined options.
+    pub fn string(custom_id: impl Into<String>, options: Vec<SelectOption>) -> Self {
+        Self {
+            options,
+            ..Self::new(SelectMenuType::String, custom_id)
+        }
+    }
+
+    /// Adds an option to a string select menu.
+    pub fn option(mut self, option: SelectOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Limits the types of channel which can be selected within a channel select menu.
+    pub fn channel_types(mut self, channel_types: Vec<ChannelType>) -> Self {
+        self.channel_types = channel_types;
+        self
+    }
+
+    /// Sets the text shown when nothing is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the fewest & most items which can be selected.
+    pub fn values(mut self, min_values: u8, max_values: u8) -> Self {
+        self.min_values = Some(min_values);
+        self.max_values = Some(max_values);
+        self
+    }
+
+    /// Sets whether the select menu is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Checks the select menu against the limits of Discord.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        validate_custom_id(Some(&self.custom_id))?;
+
+        match self.kind {
+            SelectMenuType::String => ValidationError::check_count(|| "options".to_string(), self.options.len(), 1, SELECT_OPTION_LIMIT)?,
+            _ if !self.options.is_empty() => return Err(ValidationError::Invalid { field: "options".to_string(), reason: "only string select menus can have options".to_string() }),
+            _ => {},
+        }
+
+        for (index, option) in self.options.iter().enumerate() {
+            option.validate().map_err(|error| error.prefixed(&format!("options[{index}]")))?;
+        }
+
+        if let Some(placeholder) = &self.placeholder {
+            ValidationError::check_length(|| "placeholder".to_string(), placeholder, PLACEHOLDER_LIMIT)?;
+        }
+
+        let limit = SELECT_OPTION_LIMIT as i64;
+        let check_range = |field: &str, value: Option<u8>, min: i64| match value.map(i64::from) {
+            Some(value) if !(min..=limit).contains(&value) => Err(ValidationError::OutOfRange { field: field.to_string(), min, max: limit, value }),
+            _ => Ok(()),
+        };
+
+        check_range("min_values", self.min_values, 0)?;
+        check_range("max_values", self.max_values, 1)?;
+
+        if let (Some(min_values), Some(max_values)) = (self.min_values, self.max_values) {
+            if min_values > max_values {
+                return Err(ValidationError::OutOfRange { field: "min_values".to_string(), min: 0, max: max_values.into(), value: min_values.into() })
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<SelectMenu> for Component {
+    fn from(select_menu: SelectMenu) -> Self {
+        Self::SelectMenu(select_menu)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [An option of a string select menu][https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-option-structure]
+pub struct SelectOption {
+
+    /// The text shown for the option, up to 100 characters
+    pub label: String,
+
+    /// The developer defined value of the option, up to 100 characters
+    pub value: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Additional text shown beneath the label, up to 100 characters
+    pub description: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The emoji shown next to the label
+    pub emoji: Option<Box<Emoji>>,
+
+    #[serde(default)]
+    /// Whether the option is selected by default
+    pub default: bool,
+}
+
+impl SelectOption {
+
+    /// Creates an option with the label & value.
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            description: None,
+            emoji: None,
+            default: false,
+        }
+    }
+
+    /// Sets the text shown beneath the label.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the emoji shown next to the label.
+    pub fn emoji(mut self, emoji: Emoji) -> Self {
+        self.emoji = Some(Box::new(emoji));
+        self
+    }
+
+    /// Sets whether the option is selected by default.
+    pub fn default_selected(mut self, default: bool) -> Self {
+        self.default = default;
+        self
+    }
+
+    /// Checks the option against the limits of Discord.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        ValidationError::check_length(|| "label".to_string(), &self.label, SELECT_OPTION_TEXT_LIMIT)?;
+        ValidationError::check_length(|| "value".to_string(), &self.value, SELECT_OPTION_TEXT_LIMIT)?;
+
+        match &self.description {
+            Some(description) => ValidationError::check_length(|| "description".to_string(), description, SELECT_OPTION_TEXT_LIMIT),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A user, role or channel selected by default][https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-default-value-structure] within an auto populated select menu.
+pub struct SelectDefaultValue {
+
+    /// The id of the user, role or channel
+    pub id: Id<GenericMarker>,
+
+    #[serde(rename = "type")]
+    /// The type of the value: "user", "role" or "channel"
+    pub kind: String,
+}
+
+enum_number! {
+    /// [The style of a text input][https://discord.com/developers/docs/interactions/message-components#text-input-object-text-input-styles]
+    pub enum TextInputStyle: u8 {
+        Short = 1,
+        Paragraph = 2,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A text field within a modal][https://discord.com/developers/docs/interactions/message-components#text-inputs]
+pub struct TextInput {
+
+    /// The developer defined identifier of the text input, up to 100 characters
+    pub custom_id: String,
+
+    #[serde(default = "default_text_input_style")]
+    /// Whether the text input is a single line or multiple lines
+    pub style: TextInputStyle,
+
+    #[serde(default)]
+    /// The label shown above the text input, up to 45 characters
+    pub label: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The fewest characters which must be entered, 0-4000
+    pub min_length: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The most characters which can be entered, 1-4000
+    pub max_length: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the text input must be filled in, true if None
+    pub required: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The text prefilled within the text input, or the text which was entered within a modal submit interaction
+    pub value: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The text shown when the text input is empty, up to 100 characters
+    pub placeholder: Option<String>,
+}
+
+fn default_text_input_style() -> TextInputStyle {
+    TextInputStyle::Short
+}
+
+impl TextInput {
+
+    /// Creates a text input with the label shown above it.
+    pub fn new(custom_id: impl Into<String>, label: impl Into<String>, style: TextInputStyle) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            style,
+            label: label.into(),
+            min_length: None,
+            max_length: None,
+            required: None,
+            value: None,
+            placeholder: None,
+        }
+    }
+
+    /// Sets the fewest & most characters which can be entered.
+    pub fn length(mut self, min_length: u16, max_length: u16) -> Self {
+        self.min_length = Some(min_length);
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets whether the text input must be filled in.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    /// Sets the text prefilled within the text input.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets the text shown when the text input is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Checks the text input against the limits of Discord.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        validate_custom_id(Some(&self.custom_id))?;
+        ValidationError::check_length(|| "label".to_string(), &self.label, TEXT_INPUT_LABEL_LIMIT)?;
+
+        let limit = TEXT_INPUT_VALUE_LIMIT as i64;
+        let check_range = |field: &str, value: Option<u16>, min: i64| match value.map(i64::from) {
+            Some(value) if !(min..=limit).contains(&value) => Err(ValidationError::OutOfRange { field: field.to_string(), min, max: limit, value }),
+            _ => Ok(()),
+        };
+
+        check_range("min_length", self.min_length, 0)?;
+        check_range("max_length", self.max_length, 1)?;
+
+        if let Some(value) = &self.value {
+            ValidationError::check_length(|| "value".to_string(), value, TEXT_INPUT_VALUE_LIMIT)?;
+        }
+
+        match &self.placeholder {
+            Some(placeholder) => ValidationError::check_length(|| "placeholder".to_string(), placeholder, SELECT_OPTION_TEXT_LIMIT),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<TextInput> for Component {
+    fn from(text_input: TextInput) -> Self {
+        Self::TextInput(text_input)
+    }
+}
+
+/// Checks a custom_id is present & up to 100 characters.
+fn validate_custom_id(custom_id: Option<&str>) -> Result<(), ValidationError> {
+    match custom_id {
+        None | Some("") => Err(ValidationError::Empty { field: "custom_id".to_string() }),
+        Some(custom_id) => ValidationError::check_length(|| "custom_id".to_string(), custom_id, CUSTOM_ID_LIMIT),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::error::ValidationError;
+    use super::{ActionRow, Button, ButtonStyle, Component, ComponentsBuilder, SelectMenu, SelectOption, ACTION_ROW_LIMIT, BUTTONS_PER_ROW_LIMIT, CUSTOM_ID_LIMIT};
+
+    fn button(custom_id: &str) -> Button {
+        Button::new(ButtonStyle::Primary, custom_id).label("label")
+    }
+
+    fn select_menu() -> SelectMenu {
+        SelectMenu::string("select", vec![SelectOption::new("label", "value")])
+    }
+
+    fn buttons(count: usize) -> ActionRow {
+        (0..count).fold(ActionRow::new(), |row, index| row.button(button(&index.to_string())))
+    }
+
+    #[test]
+    fn messages_contain_up_to_5_rows() {
+        let rows = |count: usize| (0..count).fold(ComponentsBuilder::new(), |builder, _| builder.row(buttons(1))).build();
+
+        assert_eq!(rows(ACTION_ROW_LIMIT).unwrap().len(), ACTION_ROW_LIMIT);
+        assert!(matches!(rows(ACTION_ROW_LIMIT + 1), Err(ValidationError::TooMany { field, max: ACTION_ROW_LIMIT, .. }) if field == "components"));
+    }
+
+    #[test]
+    fn rows_contain_up_to_5_buttons() {
+        assert!(buttons(BUTTONS_PER_ROW_LIMIT).validate().is_ok());
+        assert!(matches!(buttons(BUTTONS_PER_ROW_LIMIT + 1).validate(), Err(ValidationError::TooMany { max: BUTTONS_PER_ROW_LIMIT, .. })));
+        assert!(matches!(ActionRow::new().validate(), Err(ValidationError::Empty { .. })));
+    }
+
+    #[test]
+    fn select_menus_are_alone_within_their_row() {
+        assert!(ActionRow::new().select_menu(select_menu()).validate().is_ok());
+        assert!(matches!(ActionRow::new().select_menu(select_menu()).select_menu(select_menu()).validate(), Err(ValidationError::TooMany { max: 1, .. })));
+
+        let mixed = ActionRow::new().button(button("button")).select_menu(select_menu());
+        assert!(matches!(mixed.validate(), Err(ValidationError::Invalid { field, .. }) if field == "components"));
+    }
+
+    #[test]
+    fn only_action_rows_are_at_the_top_level_and_they_arent_nested() {
+        assert!(matches!(Component::validate_all(&[button("button").into()]), Err(ValidationError::Invalid { field, .. }) if field == "components[0]"));
+
+        let nested = ActionRow { components: vec![buttons(1).into()] };
+        assert!(matches!(Component::validate_all(&[buttons(1).into(), nested.into()]), Err(ValidationError::Invalid { field, .. }) if field == "components[1].components[0]"));
+    }
+
+    #[test]
+    fn custom_ids_are_up_to_100_characters() {
+        assert!(button(&"é".repeat(CUSTOM_ID_LIMIT)).validate().is_ok());
+        assert!(matches!(button(&"é".repeat(CUSTOM_ID_LIMIT + 1)).validate(), Err(ValidationError::TooLong { field, max: CUSTOM_ID_LIMIT, .. }) if field == "custom_id"));
+        assert!(matches!(button("").validate(), Err(ValidationError::Empty { field }) if field == "custom_id"));
+
+        // The path to an invalid custom_id names its row & component.
+        let row = ActionRow::new().button(button("button")).button(button(&"a".repeat(CUSTOM_ID_LIMIT + 1)));
+        assert!(matches!(Component::validate_all(&[row.into()]), Err(ValidationError::TooLong { field, .. }) if field == "components[0].components[1].custom_id"));
+    }
+
+    #[test]
+    fn action_rows_round_trip_with_their_types() {
+        let row = Component::from(ActionRow::new().button(button("button")).button(Button::link("https://discord.com").label("link")));
+        let value = serde_json::to_value(&row).unwrap();
+
+        assert_eq!(value["type"], 1);
+        assert_eq!(value["components"][0], json!({ "type": 2, "style": 1, "custom_id": "button", "label": "label", "disabled": false }));
+        assert_eq!(value["components"][1]["style"], 5);
+        assert_eq!(serde_json::from_value::<Component>(value).unwrap(), row);
+
+        let unknown = json!({ "type": 99, "field": true });
+        assert_eq!(serde_json::from_value::<Component>(unknown.clone()).unwrap(), Component::Unknown(unknown));
+    }
+}
@@ -0,0 +1,424 @@
+//! The [Embed][https://discord.com/developers/docs/resources/message#embed-object] model & the [`EmbedBuilder`] which checks it against the limits of Discord.
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ValidationError;
+use crate::models::{timestamp_from_time, Timestamp};
+
+/// The most characters the title of an embed can contain.
+pub const EMBED_TITLE_LIMIT: usize = 256;
+
+/// The most characters the description of an embed can contain.
+pub const EMBED_DESCRIPTION_LIMIT: usize = 4096;
+
+/// The most fields an embed can contain.
+pub const EMBED_FIELD_LIMIT: usize = 25;
+
+/// The most characters the name of a field can contain.
+pub const EMBED_FIELD_NAME_LIMIT: usize = 256;
+
+/// The most characters the value of a field can contain.
+pub const EMBED_FIELD_VALUE_LIMIT: usize = 1024;
+
+/// The most characters the text of a footer can contain.
+pub const EMBED_FOOTER_TEXT_LIMIT: usize = 2048;
+
+/// The most characters the name of an author can contain.
+pub const EMBED_AUTHOR_NAME_LIMIT: usize = 256;
+
+/// The most characters across the title, description, fields, footer & author of every embed within a message.
+pub const EMBED_TOTAL_LIMIT: usize = 6000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+/// [Embedded rich content][https://discord.com/developers/docs/resources/message#embed-object] within a message.
+/// Bots can only send rich embeds, the [`EmbedBuilder`] is utilised to create one within the limits of Discord.
+pub struct Embed {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The title of the embed
+    pub title: Option<String>,
+
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    /// The type of the embed, e.g: "rich" or "image", always "rich" for embeds sent by bots
+    pub kind: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The description of the embed
+    pub description: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The url of the title of the embed
+    pub url: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The timestamp shown within the footer of the embed
+    pub timestamp: Option<Timestamp>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The colour code of the embed
+    pub color: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The footer of the embed
+    pub footer: Option<EmbedFooter>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The image of the embed
+    pub image: Option<EmbedMedia>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The thumbnail of the embed
+    pub thumbnail: Option<EmbedMedia>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The video of the embed, can not be sent by bots
+    pub video: Option<EmbedMedia>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The provider of the embed, can not be sent by bots
+    pub provider: Option<EmbedProvider>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The author of the embed
+    pub author: Option<EmbedAuthor>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The fields of the embed
+    pub fields: Vec<EmbedField>,
+}
+
+impl Embed {
+
+    /// The amount of characters within the embed which count towards [`EMBED_TOTAL_LIMIT`]
+    pub fn character_count(&self) -> usize {
+        let count = |text: Option<&str>| text.map_or(0, |text| text.chars().count());
+
+        count(self.title.as_deref())
+            + count(self.description.as_deref())
+            + count(self.footer.as_ref().map(|footer| footer.text.as_str()))
+            + count(self.author.as_ref().map(|author| author.name.as_str()))
+            + self.fields.iter()
+                .map(|field| count(Some(&field.name)) + count(Some(&field.value)))
+                .sum::<usize>()
+    }
+
+    /// Checks the embed against the limits of Discord, the field of the error is relative to the embed.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+
+        if let Some(title) = &self.title {
+            ValidationError::check_length(|| "title".to_string(), title, EMBED_TITLE_LIMIT)?;
+        }
+
+        if let Some(description) = &self.description {
+            ValidationError::check_length(|| "description".to_string(), description, EMBED_DESCRIPTION_LIMIT)?;
+        }
+
+        ValidationError::check_count(|| "fields".to_string(), self.fields.len(), 0, EMBED_FIELD_LIMIT)?;
+        for (index, field) in self.fields.iter().enumerate() {
+            if field.name.is_empty() {
+                return Err(ValidationError::Empty { field: format!("fields[{index}].name") })
+            }
+
+            if field.value.is_empty() {
+                return Err(ValidationError::Empty { field: format!("fields[{index}].value") })
+            }
+
+            ValidationError::check_length(|| format!("fields[{index}].name"), &field.name, EMBED_FIELD_NAME_LIMIT)?;
+            ValidationError::check_length(|| format!("fields[{index}].value"), &field.value, EMBED_FIELD_VALUE_LIMIT)?;
+        }
+
+        if let Some(footer) = &self.footer {
+            ValidationError::check_length(|| "footer.text".to_string(), &footer.text, EMBED_FOOTER_TEXT_LIMIT)?;
+        }
+
+        if let Some(author) = &self.author {
+            ValidationError::check_length(|| "author.name".to_string(), &author.name, EMBED_AUTHOR_NAME_LIMIT)?;
+        }
+
+        let length = self.character_count();
+        match length > EMBED_TOTAL_LIMIT {
+            true => Err(ValidationError::TooLong { field: "embed".to_string(), max: EMBED_TOTAL_LIMIT, length }),
+            false => Ok(()),
+        }
+    }
+
+    /// Checks the embeds of a message against the limits of Discord, including the total characters across every embed.
+    pub fn validate_all(embeds: &[Embed]) -> Result<(), ValidationError> {
+
+        for (index, embed) in embeds.iter().enumerate() {
+            embed.validate().map_err(|error| error.prefixed(&format!("embeds[{index}]")))?;
+        }
+
+        let length = embeds.iter().map(Embed::character_count).sum();
+        match length > EMBED_TOTAL_LIMIT {
+            true => Err(ValidationError::TooLong { field: "embeds".to_string(), max: EMBED_TOTAL_LIMIT, length }),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The footer of an embed][https://discord.com/developers/docs/resources/message#embed-object-embed-footer-structure]
+pub struct EmbedFooter {
+
+    /// The text of the footer
+    pub text: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The url of the icon of the footer, only http(s) & attachments are supported
+    pub icon_url: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The proxied url of the icon of the footer
+    pub proxy_icon_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The image, thumbnail or video of an embed][https://discord.com/developers/docs/resources/message#embed-object-embed-image-structure]
+pub struct EmbedMedia {
+
+    /// The source url of the media, only http(s) & attachments are supported
+    pub url: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The proxied url of the media
+    pub proxy_url: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The height of the media
+    pub height: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The width of the media
+    pub width: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The provider of an embed][https://discord.com/developers/docs/resources/message#embed-object-embed-provider-structure]
+pub struct EmbedProvider {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The name of the provider
+    pub name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The url of the provider
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The author of an embed][https://discord.com/developers/docs/resources/message#embed-object-embed-author-structure]
+pub struct EmbedAuthor {
+
+    /// The name of the author
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The url of the name of the author
+    pub url: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The url of the icon of the author, only http(s) & attachments are supported
+    pub icon_url: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The proxied url of the icon of the author
+    pub proxy_icon_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A field of an embed][https://discord.com/developers/docs/resources/message#embed-object-embed-field-structure]
+pub struct EmbedField {
+
+    /// The name of the field
+    pub name: String,
+
+    /// The value of the field
+    pub value: String,
+
+    #[serde(default)]
+    /// Whether the field is shown on the same line as the fields next to it
+    pub inline: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+/// Builds a rich [`Embed`], checking it against the limits of Discord once [`EmbedBuilder::build`] is called.
+pub struct EmbedBuilder {
+    embed: Embed,
+}
+
+impl EmbedBuilder {
+
+    /// Creates a new [`EmbedBuilder`] for an empty rich embed.
+    pub fn new() -> Self {
+        Self {
+            embed: Embed {
+                kind: Some("rich".to_string()),
+                ..Embed::default()
+            },
+        }
+    }
+
+    /// Sets the title of the embed, up to 256 characters.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.embed.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description of the embed, up to 4096 characters.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.embed.description = Some(description.into());
+        self
+    }
+
+    /// Sets the url the title of the embed links to.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.embed.url = Some(url.into());
+        self
+    }
+
+    /// Sets the colour of the embed, e.g: 0x5865F2
+    pub fn colour(mut self, colour: u32) -> Self {
+        self.embed.color = Some(colour);
+        self
+    }
+
+    /// Sets the timestamp shown within the footer of the embed.
+    pub fn timestamp(mut self, time: SystemTime) -> Self {
+        self.embed.timestamp = Some(timestamp_from_time(time));
+        self
+    }
+
+    /// Adds a field to the embed, up to 25 fields with names up to 256 characters & values up to 1024 characters.
+    pub fn field(mut self, name: impl Into<String>, value: impl Into<String>, inline: bool) -> Self {
+        self.embed.fields.push(EmbedField {
+            name: name.into(),
+            value: value.into(),
+            inline,
+        });
+        self
+    }
+
+    /// Sets the author of the embed, the name can be up to 256 characters.
+    pub fn author(mut self, name: impl Into<String>, url: Option<String>, icon_url: Option<String>) -> Self {
+        self.embed.author = Some(EmbedAuthor {
+            name: name.into(),
+            url,
+            icon_url,
+            proxy_icon_url: None,
+        });
+        self
+    }
+
+    /// Sets the footer of the embed, the text can be up to 2048 characters.
+    pub fn footer(mut self, text: impl Into<String>, icon_url: Option<String>) -> Self {
+        self.embed.footer = Some(EmbedFooter {
+            text: text.into(),
+            icon_url,
+            proxy_icon_url: None,
+        });
+        self
+    }
+
+    /// Sets the image of the embed.
+    pub fn image(mut self, url: impl Into<String>) -> Self {
+        self.embed.image = Some(EmbedMedia::from_url(url.into()));
+        self
+    }
+
+    /// Sets the thumbnail of the embed.
+    pub fn thumbnail(mut self, url: impl Into<String>) -> Self {
+        self.embed.thumbnail = Some(EmbedMedia::from_url(url.into()));
+        self
+    }
+
+    /// Checks the embed against the limits of Discord, returning the [`Embed`] if it is within them.
+    pub fn build(self) -> Result<Embed, ValidationError> {
+        self.embed.validate()?;
+        Ok(self.embed)
+    }
+}
+
+impl EmbedMedia {
+
+    /// Creates [`EmbedMedia`] with only its url set, as sent by bots.
+    pub fn from_url(url: String) -> Self {
+        Self {
+            url,
+            proxy_url: None,
+            height: None,
+            width: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ValidationError;
+    use super::{Embed, EmbedBuilder, EmbedField, EMBED_DESCRIPTION_LIMIT, EMBED_FIELD_LIMIT, EMBED_FIELD_NAME_LIMIT, EMBED_FIELD_VALUE_LIMIT, EMBED_TITLE_LIMIT, EMBED_TOTAL_LIMIT};
+
+    /// Text of the amount of characters, each of which is more than one byte so the limits are checked in characters.
+    fn text(characters: usize) -> String {
+        "é".repeat(characters)
+    }
+
+    fn field(name: &str, value: &str) -> EmbedField {
+        EmbedField { name: name.to_string(), value: value.to_string(), inline: false }
+    }
+
+    /// Asserts the embed is valid at the limit & fails naming the field once it is one over.
+    fn assert_limit(embed: impl Fn(usize) -> Embed, limit: usize, field: &str) {
+        assert!(embed(limit).validate().is_ok(), "{field} at its limit was invalid");
+        match embed(limit + 1).validate() {
+            Err(ValidationError::TooLong { field: invalid, max, .. } | ValidationError::TooMany { field: invalid, max, .. }) => {
+                assert_eq!((invalid.as_str(), max), (field, limit));
+            },
+            result => panic!("{field} over its limit was {result:?}"),
+        }
+    }
+
+    #[test]
+    fn text_and_field_limits() {
+        assert_limit(|length| Embed { title: Some(text(length)), ..Embed::default() }, EMBED_TITLE_LIMIT, "title");
+        assert_limit(|length| Embed { description: Some(text(length)), ..Embed::default() }, EMBED_DESCRIPTION_LIMIT, "description");
+        assert_limit(|count| Embed { fields: vec![field("name", "value"); count], ..Embed::default() }, EMBED_FIELD_LIMIT, "fields");
+        assert_limit(|length| Embed { fields: vec![field(&text(length), "value")], ..Embed::default() }, EMBED_FIELD_NAME_LIMIT, "fields[0].name");
+        assert_limit(|length| Embed { fields: vec![field("name", &text(length))], ..Embed::default() }, EMBED_FIELD_VALUE_LIMIT, "fields[0].value");
+    }
+
+    #[test]
+    fn fields_must_have_a_name_and_value() {
+        let embed = Embed { fields: vec![field("name", "value"), field("", "value")], ..Embed::default() };
+        assert!(matches!(embed.validate(), Err(ValidationError::Empty { field }) if field == "fields[1].name"));
+
+        let embed = Embed { fields: vec![field("name", "")], ..Embed::default() };
+        assert!(matches!(embed.validate(), Err(ValidationError::Empty { field }) if field == "fields[0].value"));
+    }
+
+    #[test]
+    fn total_characters_of_an_embed_are_limited() {
+        let embed = |last_value: usize| EmbedBuilder::new()
+            .title(text(EMBED_TITLE_LIMIT))
+            .description(text(EMBED_DESCRIPTION_LIMIT))
+            .field(text(EMBED_FIELD_NAME_LIMIT), text(EMBED_FIELD_VALUE_LIMIT), false)
+            .field(text(EMBED_FIELD_NAME_LIMIT), text(last_value), false)
+            .build();
+
+        // Every part is within its own limit, only the total is over.
+        assert_eq!(embed(112).unwrap().character_count(), EMBED_TOTAL_LIMIT);
+        assert!(matches!(embed(113), Err(ValidationError::TooLong { field, max: EMBED_TOTAL_LIMIT, length: 6001 }) if field == "embed"));
+    }
+
+    #[test]
+    fn total_characters_across_embeds_are_limited() {
+        let embed = |length: usize| Embed { description: Some(text(length)), ..Embed::default() };
+
+        assert!(Embed::validate_all(&[embed(3000), embed(3000)]).is_ok());
+        assert!(matches!(Embed::validate_all(&[embed(3000), embed(3001)]), Err(ValidationError::TooLong { field, max: EMBED_TOTAL_LIMIT, length: 6001 }) if field == "embeds"));
+
+        // Errors within an embed name the embed they were within.
+        let result = Embed::validate_all(&[embed(10), Embed { title: Some(text(EMBED_TITLE_LIMIT + 1)), ..Embed::default() }]);
+        assert!(matches!(result, Err(ValidationError::TooLong { field, .. }) if field == "embeds[1].title"));
+    }
+}
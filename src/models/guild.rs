@@ -0,0 +1,568 @@
+//! The [Guild][https://discord.com/developers/docs/resources/guild] resource, alongside its members, roles & emojis.
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::models::Timestamp;
+use crate::models::permissions::Permissions;
+use crate::models::user::{AvatarDecorationData, User};
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ApplicationMarker, ChannelMarker, EmojiMarker, GenericMarker, GuildMarker, IntegrationMarker, RoleMarker, UserMarker};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [A guild][https://discord.com/developers/docs/resources/guild#guild-object], referred to as a server within the Discord client.
+pub struct Guild {
+
+    /// The id of the guild
+    pub id: Id<GuildMarker>,
+
+    /// The name of the guild, 2-100 characters
+    pub name: String,
+
+    /// The icon hash of the guild
+    pub icon: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The icon hash of the guild, only sent within the template object
+    pub icon_hash: Option<String>,
+
+    /// The splash hash of the guild
+    pub splash: Option<String>,
+
+    /// The discovery splash hash of the guild, only present for guilds with the "DISCOVERABLE" feature
+    pub discovery_splash: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the user is the owner of the guild, only sent when retrieving the guilds of the current user
+    pub owner: Option<bool>,
+
+    /// The id of the owner of the guild
+    pub owner_id: Id<UserMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The total permissions of the user in the guild, only sent when retrieving the guilds of the current user
+    pub permissions: Option<Permissions>,
+
+    /// The id of the afk channel
+    pub afk_channel_id: Option<Id<ChannelMarker>>,
+
+    /// The afk timeout in seconds
+    pub afk_timeout: u32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the server widget is enabled
+    pub widget_enabled: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The channel id that the widget will generate an invite to, or null if set to no invite
+    pub widget_channel_id: Option<Id<ChannelMarker>>,
+
+    /// The verification level required for the guild
+    pub verification_level: VerificationLevel,
+
+    /// The default message notifications level
+    pub default_message_notifications: DefaultMessageNotificationLevel,
+
+    /// The explicit content filter level
+    pub explicit_content_filter: ExplicitContentFilterLevel,
+
+    #[serde(default)]
+    /// The roles in the guild
+    pub roles: Vec<Role>,
+
+    #[serde(default)]
+    /// The custom emojis of the guild
+    pub emojis: Vec<Emoji>,
+
+    #[serde(default)]
+    /// The enabled features of the guild, e.g: "COMMUNITY"
+    pub features: Vec<String>,
+
+    /// The required MFA level for the guild
+    pub mfa_level: MfaLevel,
+
+    /// The application id of the guild creator if it is bot-created
+    pub application_id: Option<Id<ApplicationMarker>>,
+
+    /// The id of the channel where guild notices such as welcome messages and boost events are posted
+    pub system_channel_id: Option<Id<ChannelMarker>>,
+
+    #[serde(default)]
+    /// The flags of the system channel
+    pub system_channel_flags: SystemChannelFlags,
+
+    /// The id of the channel where Community guilds can display rules and/or guidelines
+    pub rules_channel_id: Option<Id<ChannelMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The maximum number of presences for the guild, null is always returned apart from the largest of guilds
+    pub max_presences: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The maximum number of members for the guild
+    pub max_members: Option<u32>,
+
+    /// The vanity url code for the guild
+    pub vanity_url_code: Option<String>,
+
+    /// The description of the guild
+    pub description: Option<String>,
+
+    /// The banner hash of the guild
+    pub banner: Option<String>,
+
+    /// The premium tier (Server Boost level) of the guild
+    pub premium_tier: PremiumTier,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The number of boosts the guild currently has
+    pub premium_subscription_count: Option<u32>,
+
+    /// The preferred locale of a Community guild, defaults to "en-US"
+    pub preferred_locale: String,
+
+    /// The id of the channel where admins and moderators of Community guilds receive notices from Discord
+    pub public_updates_channel_id: Option<Id<ChannelMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The maximum amount of users in a video channel
+    pub max_video_channel_users: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The maximum amount of users in a stage video channel
+    pub max_stage_video_channel_users: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The approximate number of members in the guild, sent when retrieving a guild with with_counts
+    pub approximate_member_count: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The approximate number of non-offline members in the guild, sent when retrieving a guild with with_counts
+    pub approximate_presence_count: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The welcome screen of a Community guild, shown to new members
+    pub welcome_screen: Option<Value>,
+
+    /// The NSFW level of the guild
+    pub nsfw_level: NsfwLevel,
+
+    #[serde(default)]
+    /// The custom stickers of the guild
+    pub stickers: Vec<Value>,
+
+    #[serde(default)]
+    /// Whether the guild has the boost progress bar enabled
+    pub premium_progress_bar_enabled: bool,
+
+    /// The id of the channel where admins and moderators of Community guilds receive safety alerts from Discord
+    pub safety_alerts_channel_id: Option<Id<ChannelMarker>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A guild which is unavailable][https://discord.com/developers/docs/resources/guild#unavailable-guild-object] due to an outage, or which hasnt been sent yet.
+pub struct UnavailableGuild {
+
+    /// The id of the guild
+    pub id: Id<GuildMarker>,
+
+    #[serde(default)]
+    /// Whether the guild is unavailable, false when the bot was removed from the guild
+    pub unavailable: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A member of a guild][https://discord.com/developers/docs/resources/guild#guild-member-object]
+pub struct Member {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The user this member represents, not sent within the member of a MESSAGE_CREATE event
+    pub user: Option<User>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The nickname of the member within the guild
+    pub nick: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The guild avatar hash of the member
+    pub avatar: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The guild banner hash of the member
+    pub banner: Option<String>,
+
+    /// The ids of the roles of the member
+    pub roles: Vec<Id<RoleMarker>>,
+
+    /// When the member joined the guild, null for guest members of a voice channel
+    pub joined_at: Option<Timestamp>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the member started boosting the guild
+    pub premium_since: Option<Timestamp>,
+
+    #[serde(default)]
+    /// Whether the member is deafened in voice channels
+    pub deaf: bool,
+
+    #[serde(default)]
+    /// Whether the member is muted in voice channels
+    pub mute: bool,
+
+    #[serde(default)]
+    /// The flags of the member
+    pub flags: MemberFlags,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the member has not yet passed the Membership Screening requirements of the guild
+    pub pending: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The total permissions of the member in the channel, including overwrites, only sent within an interaction
+    pub permissions: Option<Permissions>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the timeout of the member will expire, None or a time in the past if the member isnt timed out
+    pub communication_disabled_until: Option<Timestamp>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The guild avatar decoration of the member
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
+}
+
+impl Member {
+
+    /// Whether the member is currently timed out, as its communication_disabled_until is in the future.
+    pub fn is_timed_out(&self) -> bool {
+        self.communication_disabled_until.as_deref()
+            .and_then(crate::models::time_from_timestamp)
+            .is_some_and(|until| until > std::time::SystemTime::now())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A role within a guild][https://discord.com/developers/docs/topics/permissions#role-object]
+pub struct Role {
+
+    /// The id of the role, which is the id of the guild for the @everyone role
+    pub id: Id<RoleMarker>,
+
+    /// The name of the role
+    pub name: String,
+
+    /// The integer representation of the hex colour code of the role, 0 if it has no colour
+    pub color: u32,
+
+    /// Whether the role is shown separately in the member list
+    pub hoist: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The icon hash of the role
+    pub icon: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The unicode emoji of the role
+    pub unicode_emoji: Option<String>,
+
+    /// The position of the role, roles with the same position are sorted by id
+    pub position: i32,
+
+    /// The permissions of the role
+    pub permissions: Permissions,
+
+    /// Whether the role is managed by an integration
+    pub managed: bool,
+
+    /// Whether the role is mentionable
+    pub mentionable: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The tags the role has
+    pub tags: Option<RoleTags>,
+
+    #[serde(default)]
+    /// The flags of the role
+    pub flags: RoleFlags,
+}
+
+impl Role {
+
+    /// The mention of the role which can be placed within a message, e.g: <@&165511591545143296>
+    pub fn mention(&self) -> String {
+        format!("<@&{}>", self.id)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// [The tags of a role][https://discord.com/developers/docs/topics/permissions#role-object-role-tags-structure]
+/// Discord sends the boolean tags as null when they are true & leaves them out when they are false.
+pub struct RoleTags {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the bot this role belongs to
+    pub bot_id: Option<Id<UserMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the integration this role belongs to
+    pub integration_id: Option<Id<IntegrationMarker>>,
+
+    #[serde(default, skip_serializing_if = "is_false", deserialize_with = "deserialize_present", serialize_with = "serialize_present")]
+    /// Whether this is the premium subscriber (booster) role of the guild
+    pub premium_subscriber: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the subscription listing of this role
+    pub subscription_listing_id: Option<Id<GenericMarker>>,
+
+    #[serde(default, skip_serializing_if = "is_false", deserialize_with = "deserialize_present", serialize_with = "serialize_present")]
+    /// Whether this role is available for purchase
+    pub available_for_purchase: bool,
+
+    #[serde(default, skip_serializing_if = "is_false", deserialize_with = "deserialize_present", serialize_with = "serialize_present")]
+    /// Whether this role is a linked role of the guild
+    pub guild_connections: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// A role tag is true whenever it is present, its value is always null.
+fn deserialize_present<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    Option::<()>::deserialize(deserializer).map(|_| true)
+}
+
+fn serialize_present<S: Serializer>(_: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_unit()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [An emoji][https://discord.com/developers/docs/resources/emoji#emoji-object], either custom or unicode.
+pub struct Emoji {
+
+    /// The id of the emoji, None for unicode emojis
+    pub id: Option<Id<EmojiMarker>>,
+
+    /// The name of the emoji, may be None for custom emojis within reactions if the emoji was deleted
+    pub name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The ids of the roles allowed to use this emoji
+    pub roles: Vec<Id<RoleMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The user that created this emoji
+    pub user: Option<User>,
+
+    #[serde(default)]
+    /// Whether this emoji must be wrapped in colons
+    pub require_colons: bool,
+
+    #[serde(default)]
+    /// Whether this emoji is managed by an integration
+    pub managed: bool,
+
+    #[serde(default)]
+    /// Whether this emoji is animated
+    pub animated: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether this emoji can be used, may be false due to loss of Server Boosts
+    pub available: Option<bool>,
+}
+
+enum_number! {
+    /// [The verification level][https://discord.com/developers/docs/resources/guild#guild-object-verification-level] required for a guild.
+    pub enum VerificationLevel: u8 {
+        None = 0,
+        Low = 1,
+        Medium = 2,
+        High = 3,
+        VeryHigh = 4,
+    }
+}
+
+enum_number! {
+    /// [Which messages members of a guild are notified of by default][https://discord.com/developers/docs/resources/guild#guild-object-default-message-notification-level]
+    pub enum DefaultMessageNotificationLevel: u8 {
+        AllMessages = 0,
+        OnlyMentions = 1,
+    }
+}
+
+enum_number! {
+    /// [Whose messages are scanned for explicit content][https://discord.com/developers/docs/resources/guild#guild-object-explicit-content-filter-level]
+    pub enum ExplicitContentFilterLevel: u8 {
+        Disabled = 0,
+        MembersWithoutRoles = 1,
+        AllMembers = 2,
+    }
+}
+
+enum_number! {
+    /// [The MFA level required for moderation actions][https://discord.com/developers/docs/resources/guild#guild-object-mfa-level]
+    pub enum MfaLevel: u8 {
+        None = 0,
+        Elevated = 1,
+    }
+}
+
+enum_number! {
+    /// [The NSFW level of a guild][https://discord.com/developers/docs/resources/guild#guild-object-guild-nsfw-level]
+    pub enum NsfwLevel: u8 {
+        Default = 0,
+        Explicit = 1,
+        Safe = 2,
+        AgeRestricted = 3,
+    }
+}
+
+enum_number! {
+    /// [The Server Boost level of a guild][https://discord.com/developers/docs/resources/guild#guild-object-premium-tier]
+    pub enum PremiumTier: u8 {
+        None = 0,
+        Tier1 = 1,
+        Tier2 = 2,
+        Tier3 = 3,
+    }
+}
+
+bitflags::bitflags! {
+
+    /// [The flags of the system channel of a guild][https://discord.com/developers/docs/resources/guild#guild-object-system-channel-flags]
+    pub struct SystemChannelFlags: u32 {
+        const SUPPRESS_JOIN_NOTIFICATIONS = 1 << 0;
+        const SUPPRESS_PREMIUM_SUBSCRIPTIONS = 1 << 1;
+        const SUPPRESS_GUILD_REMINDER_NOTIFICATIONS = 1 << 2;
+        const SUPPRESS_JOIN_NOTIFICATION_REPLIES = 1 << 3;
+        const SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATIONS = 1 << 4;
+        const SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATION_REPLIES = 1 << 5;
+    }
+}
+
+bitflags_serde!(SystemChannelFlags: u32);
+
+bitflags::bitflags! {
+
+    /// [The flags of a guild member][https://discord.com/developers/docs/resources/guild#guild-member-object-guild-member-flags]
+    pub struct MemberFlags: u32 {
+        const DID_REJOIN = 1 << 0;
+        const COMPLETED_ONBOARDING = 1 << 1;
+        const BYPASSES_VERIFICATION = 1 << 2;
+        const STARTED_ONBOARDING = 1 << 3;
+        const IS_GUEST = 1 << 4;
+        const STARTED_HOME_ACTIONS = 1 << 5;
+        const COMPLETED_HOME_ACTIONS = 1 << 6;
+        const AUTOMOD_QUARANTINED_USERNAME = 1 << 7;
+        const DM_SETTINGS_UPSELL_ACKNOWLEDGED = 1 << 9;
+    }
+}
+
+bitflags_serde!(MemberFlags: u32);
+
+bitflags::bitflags! {
+
+    /// [The flags of a role][https://discord.com/developers/docs/topics/permissions#role-object-role-flags]
+    pub struct RoleFlags: u32 {
+        const IN_PROMPT = 1 << 0;
+    }
+}
+
+bitflags_serde!(RoleFlags: u32);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [An integration of a guild][https://discord.com/developers/docs/resources/guild#integration-object], such as a bot or a Twitch subscription.
+pub struct Integration {
+
+    /// The id of the integration
+    pub id: Id<IntegrationMarker>,
+
+    /// The name of the integration
+    pub name: String,
+
+    #[serde(rename = "type")]
+    /// The type of the integration: "twitch", "youtube", "discord" or "guild_subscription"
+    pub kind: String,
+
+    #[serde(default)]
+    /// Whether the integration is enabled
+    pub enabled: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the integration is syncing
+    pub syncing: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the role utilised for subscribers
+    pub role_id: Option<Id<RoleMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether emoticons should be synced, only for Twitch
+    pub enable_emoticons: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// What happens once a subscription expires, 0 removes the role & 1 kicks the member
+    pub expire_behavior: Option<u8>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The amount of days before an expired subscription is acted upon
+    pub expire_grace_period: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The user of the integration
+    pub user: Option<User>,
+
+    /// The account of the integration
+    pub account: IntegrationAccount,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the integration was last synced
+    pub synced_at: Option<Timestamp>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The amount of subscribers of the integration
+    pub subscriber_count: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the integration was revoked
+    pub revoked: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The bot or OAuth2 application of Discord integrations
+    pub application: Option<IntegrationApplication>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The OAuth2 scopes the application was authorised with
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The account of an integration][https://discord.com/developers/docs/resources/guild#integration-account-object]
+pub struct IntegrationAccount {
+
+    /// The id of the account, which isnt a snowflake for Twitch & YouTube integrations
+    pub id: String,
+
+    /// The name of the account
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The application of an integration][https://discord.com/developers/docs/resources/guild#integration-application-object]
+pub struct IntegrationApplication {
+
+    /// The id of the application
+    pub id: Id<ApplicationMarker>,
+
+    /// The name of the application
+    pub name: String,
+
+    /// The icon hash of the application
+    pub icon: Option<String>,
+
+    /// The description of the application
+    pub description: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The bot of the application
+    pub bot: Option<User>,
+}
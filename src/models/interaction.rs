@@ -0,0 +1,533 @@
+//! The [Interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object] model, sent when a user uses an application command, component or modal.
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use serde_json::Value;
+use tokio::time::Instant;
+
+use crate::models::channel::Channel;
+use crate::models::command::{CommandOptionType, CommandOptionValue, CommandType, InteractionContextType};
+use crate::models::component::{Component, ComponentType};
+use crate::models::guild::{Member, Role};
+use crate::models::message::{Attachment, Message};
+use crate::models::permissions::Permissions;
+use crate::models::user::User;
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ApplicationMarker, AttachmentMarker, ChannelMarker, CommandMarker, GenericMarker, GuildMarker, InteractionMarker, MessageMarker, RoleMarker, UserMarker};
+
+/// How long after an interaction is created it must be responded to, after which its token can not be used to respond.
+pub const INTERACTION_ACKNOWLEDGEMENT_DEADLINE: Duration = Duration::from_secs(3);
+
+/// How long after an interaction is created a warning is logged if it still hasnt been responded to.
+pub const INTERACTION_ACKNOWLEDGEMENT_WARNING: Duration = Duration::from_millis(2500);
+
+enum_number! {
+    /// [The type of an interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type]
+    pub enum InteractionType: u8 {
+        Ping = 1,
+        ApplicationCommand = 2,
+        MessageComponent = 3,
+        ApplicationCommandAutocomplete = 4,
+        ModalSubmit = 5,
+    }
+}
+
+enum_number! {
+    /// [The type of a response to an interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-type]
+    pub enum InteractionResponseType: u8 {
+        /// Acknowledges a ping
+        Pong = 1,
+        /// Responds with a message
+        ChannelMessageWithSource = 4,
+        /// Acknowledges the interaction, the response is edited in later while the user sees a loading state
+        DeferredChannelMessageWithSource = 5,
+        /// Acknowledges a component interaction, the message of the component is edited later
+        DeferredUpdateMessage = 6,
+        /// Edits the message of the component which was used
+        UpdateMessage = 7,
+        /// Responds to an autocomplete interaction with choices
+        ApplicationCommandAutocompleteResult = 8,
+        /// Responds with a popup modal
+        Modal = 9,
+        /// Launches the activity of the application
+        LaunchActivity = 12,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+// The data is decoded depending on the type of the interaction, so the derives are only utilised for the remaining fields.
+#[serde(remote = "Self")]
+/// [An interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object] created when a user uses an application command, component or modal.
+/// Must be responded to within 3 seconds, see [`InteractionAcknowledgement`]
+pub struct Interaction {
+
+    /// The id of the interaction
+    pub id: Id<InteractionMarker>,
+
+    /// The id of the application the interaction is for
+    pub application_id: Id<ApplicationMarker>,
+
+    #[serde(rename = "type")]
+    /// The type of the interaction
+    pub kind: InteractionType,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    /// The data of the command, component or modal which was used, None for pings
+    pub data: Option<InteractionData>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Partial information about the guild the interaction was created within
+    pub guild: Option<Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild the interaction was created within
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Partial information about the channel the interaction was created within
+    pub channel: Option<Channel>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the channel the interaction was created within
+    pub channel_id: Option<Id<ChannelMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The member which created the interaction, including their permissions, when created within a guild
+    pub member: Option<Member>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The user which created the interaction when created within a DM
+    pub user: Option<User>,
+
+    /// The token utilised to respond to the interaction, valid for 15 minutes
+    pub token: String,
+
+    /// Always 1
+    pub version: u8,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The message a component which was used is attached to
+    pub message: Option<Box<Message>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The permissions of the bot within the channel
+    pub app_permissions: Option<Permissions>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The locale of the user which created the interaction, not sent for pings
+    pub locale: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The preferred locale of the guild the interaction was created within
+    pub guild_locale: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The entitlements of the user & guild which created the interaction
+    pub entitlements: Vec<Value>,
+
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    /// The ids of the guild or user which installed the application, keyed by the installation context
+    pub authorizing_integration_owners: HashMap<String, Id<GenericMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Where the interaction was created
+    pub context: Option<InteractionContextType>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The most bytes the files of a response can contain
+    pub attachment_size_limit: Option<u64>,
+
+    #[serde(skip)]
+    /// Tracks whether the interaction has been responded to within its deadline
+    pub acknowledgement: InteractionAcknowledgement,
+}
+
+impl Serialize for Interaction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Interaction::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+
+        let mut fields = serde_json::Map::<String, Value>::deserialize(deserializer)?;
+        let data = fields.remove("data").filter(|data| !data.is_null());
+
+        let mut interaction = Interaction::deserialize(Value::Object(fields)).map_err(de::Error::custom)?;
+        if let Some(data) = data {
+            interaction.data = InteractionData::from_type(interaction.kind, data).map_err(de::Error::custom)?;
+        }
+
+        Ok(interaction)
+    }
+}
+
+impl Interaction {
+
+    /// The user which created the interaction, whether it was created within a guild or a DM.
+    pub fn author(&self) -> Option<&User> {
+        self.member.as_ref()
+            .and_then(|member| member.user.as_ref())
+            .or(self.user.as_ref())
+    }
+
+    /// The data of an application command or autocomplete interaction.
+    pub fn command_data(&self) -> Option<&CommandData> {
+        match &self.data {
+            Some(InteractionData::ApplicationCommand(data)) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// The data of a message component interaction.
+    pub fn component_data(&self) -> Option<&ComponentData> {
+        match &self.data {
+            Some(InteractionData::MessageComponent(data)) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// The data of a modal submit interaction.
+    pub fn modal_data(&self) -> Option<&ModalSubmitData> {
+        match &self.data {
+            Some(InteractionData::ModalSubmit(data)) => Some(data),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+/// [The data of an interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-data] which depends on its type.
+/// Decoded alongside the [`Interaction`] through [`InteractionData::from_type`], as the data itself doesnt contain its type.
+pub enum InteractionData {
+
+    /// The data of an application command or autocomplete interaction
+    ApplicationCommand(Box<CommandData>),
+
+    /// The data of a message component interaction
+    MessageComponent(Box<ComponentData>),
+
+    /// The data of a modal submit interaction
+    ModalSubmit(ModalSubmitData),
+}
+
+impl InteractionData {
+
+    /// Decodes the data of an interaction into the variant of the type of the interaction, so a malformed field fails with its own error.
+    /// None for types which arent known by the library, or which have no data.
+    pub fn from_type(kind: InteractionType, data: Value) -> serde_json::Result<Option<Self>> {
+        let data = match kind {
+            InteractionType::ApplicationCommand | InteractionType::ApplicationCommandAutocomplete => Self::ApplicationCommand(serde_json::from_value(data)?),
+            InteractionType::MessageComponent => Self::MessageComponent(serde_json::from_value(data)?),
+            InteractionType::ModalSubmit => Self::ModalSubmit(serde_json::from_value(data)?),
+            InteractionType::Ping | InteractionType::Unknown(_) => return Ok(None),
+        };
+
+        Ok(Some(data))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [The data of an application command interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-data-structure]
+pub struct CommandData {
+
+    /// The id of the command which was used
+    pub id: Id<CommandMarker>,
+
+    /// The name of the command which was used
+    pub name: String,
+
+    #[serde(rename = "type")]
+    /// The type of the command which was used
+    pub kind: CommandType,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The users, members, roles, channels, messages & attachments referenced by the options
+    pub resolved: Option<ResolvedData>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The options which were filled in, or the sub command which was used
+    pub options: Vec<CommandDataOption>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild the command is registered within
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the user or message a user or message command was used on
+    pub target_id: Option<Id<GenericMarker>>,
+}
+
+impl CommandData {
+
+    /// Finds a filled in option by name, looking within the sub command which was used.
+    pub fn option(&self, name: &str) -> Option<&CommandDataOption> {
+        find_option(&self.options, name)
+    }
+
+    /// The option which is being filled in within an autocomplete interaction.
+    pub fn focused_option(&self) -> Option<&CommandDataOption> {
+        find_focused(&self.options)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [An option which was filled in][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-interaction-data-option-structure], or a sub command which was used.
+pub struct CommandDataOption {
+
+    /// The name of the option
+    pub name: String,
+
+    #[serde(rename = "type")]
+    /// The type of the option
+    pub kind: CommandOptionType,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The value of the option, user, channel, role & attachment options contain an id which is within the resolved data
+    pub value: Option<CommandOptionValue>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The options of a sub command, or the sub command of a sub command group
+    pub options: Vec<CommandDataOption>,
+
+    #[serde(default)]
+    /// Whether the option is being filled in within an autocomplete interaction
+    pub focused: bool,
+}
+
+/// Finds an option by name, looking within sub commands & sub command groups.
+fn find_option<'a>(options: &'a [CommandDataOption], name: &str) -> Option<&'a CommandDataOption> {
+    options.iter().find_map(|option| match option.kind.is_sub_command() {
+        true => find_option(&option.options, name),
+        false => (option.name == name).then_some(option),
+    })
+}
+
+/// Finds the focused option, looking within sub commands & sub command groups.
+fn find_focused(options: &[CommandDataOption]) -> Option<&CommandDataOption> {
+    options.iter().find_map(|option| match option.kind.is_sub_command() {
+        true => find_focused(&option.options),
+        false => option.focused.then_some(option),
+    })
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+/// [The resources referenced by an interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-resolved-data-structure], keyed by their id.
+pub struct ResolvedData {
+
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    /// The referenced users
+    pub users: HashMap<Id<UserMarker>, User>,
+
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    /// The referenced members, which dont contain their user, deaf or mute fields
+    pub members: HashMap<Id<UserMarker>, Member>,
+
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    /// The referenced roles
+    pub roles: HashMap<Id<RoleMarker>, Role>,
+
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    /// The referenced channels, which only contain their id, name, type, permissions & thread fields
+    pub channels: HashMap<Id<ChannelMarker>, Channel>,
+
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    /// The referenced messages
+    pub messages: HashMap<Id<MessageMarker>, Message>,
+
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    /// The referenced attachments
+    pub attachments: HashMap<Id<AttachmentMarker>, Attachment>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [The data of a message component interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-message-component-data-structure]
+pub struct ComponentData {
+
+    /// The custom_id of the component which was used
+    pub custom_id: String,
+
+    /// The type of the component which was used
+    pub component_type: ComponentType,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The values which were selected within a select menu
+    pub values: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The users, members, roles & channels selected within an auto populated select menu
+    pub resolved: Option<ResolvedData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [The data of a modal submit interaction][https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-modal-submit-data-structure]
+pub struct ModalSubmitData {
+
+    /// The custom_id of the modal which was submitted
+    pub custom_id: String,
+
+    /// The action rows of the modal, containing the text inputs with the values which were entered
+    pub components: Vec<Component>,
+}
+
+impl ModalSubmitData {
+
+    /// The value which was entered within a text input of the modal.
+    pub fn text_input_value(&self, custom_id: &str) -> Option<&str> {
+        self.components.iter()
+            .filter_map(|component| match component {
+                Component::ActionRow(action_row) => Some(&action_row.components),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|component| match component {
+                Component::TextInput(text_input) if text_input.custom_id == custom_id => text_input.value.as_deref(),
+                _ => None,
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Tracks whether an [`Interaction`] has been responded to, shared between every clone of the interaction.
+/// Discord fails the interaction if it isnt responded to within 3 seconds of being created.
+pub struct InteractionAcknowledgement {
+    recieved_at: Instant,
+    acknowledged: Arc<AtomicBool>,
+
+    /// Where the response is sent when the interaction was recieved through an [`InteractionServer`][crate::interaction_server::InteractionServer]
+    /// so it can be returned as the body of the HTTP response, None when it was already used or the interaction came from the gateway.
+    #[cfg(feature = "http-interactions")]
+    responder: Arc<std::sync::Mutex<Option<InlineResponder>>>,
+}
+
+/// Sends the response of an interaction recieved through an [`InteractionServer`][crate::interaction_server::InteractionServer] back to it.
+/// None is sent when the response was instead sent to the callback endpoint, such as when it uploads files.
+#[cfg(feature = "http-interactions")]
+pub(crate) type InlineResponder = tokio::sync::oneshot::Sender<Option<crate::interactions::InteractionResponse>>;
+
+impl Default for InteractionAcknowledgement {
+    fn default() -> Self {
+        Self {
+            recieved_at: Instant::now(),
+            acknowledged: Arc::new(AtomicBool::new(false)),
+            #[cfg(feature = "http-interactions")]
+            responder: Arc::default(),
+        }
+    }
+}
+
+impl InteractionAcknowledgement {
+
+    /// Whether the interaction has been responded to.
+    pub fn is_acknowledged(&self) -> bool {
+        self.acknowledged.load(Ordering::Acquire)
+    }
+
+    /// How long remains until the interaction can no longer be responded to, zero once the deadline has passed.
+    pub fn remaining(&self) -> Duration {
+        INTERACTION_ACKNOWLEDGEMENT_DEADLINE.saturating_sub(self.recieved_at.elapsed())
+    }
+
+    /// Marks the interaction as responded to, returning whether it already was.
+    pub fn acknowledge(&self) -> bool {
+        self.acknowledged.swap(true, Ordering::AcqRel)
+    }
+
+    /// Sets where the response of the interaction is sent instead of the callback endpoint.
+    #[cfg(feature = "http-interactions")]
+    pub(crate) fn set_responder(&self, responder: InlineResponder) {
+        *self.responder.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(responder);
+    }
+
+    /// Whether the response of the interaction is sent through an [`InteractionServer`][crate::interaction_server::InteractionServer] which hasnt been responded to yet.
+    #[cfg(feature = "http-interactions")]
+    pub(crate) fn has_responder(&self) -> bool {
+        self.responder.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).is_some()
+    }
+
+    /// Takes where the response of the interaction is sent, None unless it was recieved through an [`InteractionServer`][crate::interaction_server::InteractionServer]
+    #[cfg(feature = "http-interactions")]
+    pub(crate) fn take_responder(&self) -> Option<InlineResponder> {
+        self.responder.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take()
+    }
+
+    /// Spawns a task which logs a warning if the interaction is still not responded to shortly before its deadline.
+    /// Called for every interaction recieved by a [`Bot`][crate::bot::Bot] before it is passed to the event handlers.
+    pub fn watch(&self, interaction_id: Id<InteractionMarker>) {
+        let acknowledgement = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep_until(acknowledgement.recieved_at + INTERACTION_ACKNOWLEDGEMENT_WARNING).await;
+            if !acknowledgement.is_acknowledged() {
+                tracing::warn!(
+                    "Interaction {interaction_id} has not been responded to after {:?}, it will fail in {:?} unless it is responded to or deferred",
+                    INTERACTION_ACKNOWLEDGEMENT_WARNING,
+                    acknowledgement.remaining(),
+                );
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::fixtures;
+    use crate::gateway::DispatchEvent;
+    use crate::models::command::CommandOptionValue;
+    use super::Interaction;
+
+    #[test]
+    fn interaction_create_decodes_command_data() {
+        let data = json!({
+            "id": "50",
+            "application_id": "2",
+            "type": 2,
+            "data": { "id": "60", "name": "roll", "type": 1, "options": [{ "name": "sides", "type": 4, "value": 6 }] },
+            "guild_id": "10",
+            "channel_id": "20",
+            "member": fixtures::member(30, &[]),
+            "token": "token",
+            "version": 1,
+        });
+
+        match DispatchEvent::from_name_and_data("INTERACTION_CREATE".to_string(), data) {
+            DispatchEvent::InteractionCreate(interaction) => {
+                assert_eq!(interaction.author().map(|user| user.id.get()), Some(30));
+                assert!(interaction.component_data().is_none());
+
+                let command = interaction.command_data().expect("the data of a command interaction is command data");
+                assert_eq!(command.name, "roll");
+                assert_eq!(command.options[0].value, Some(CommandOptionValue::Integer(6)));
+            },
+            event => panic!("expected an interaction, got {event:?}"),
+        }
+    }
+
+    #[test]
+    fn interaction_data_is_decoded_by_the_interaction_type() {
+        let mut data = json!({
+            "id": "50",
+            "application_id": "2",
+            "type": 3,
+            "data": { "custom_id": "confirm", "component_type": 2 },
+            "channel_id": "20",
+            "user": fixtures::user(30),
+            "token": "token",
+            "version": 1,
+        });
+
+        let interaction: Interaction = serde_json::from_value(data.clone()).unwrap();
+        assert_eq!(interaction.component_data().map(|component| component.custom_id.as_str()), Some("confirm"));
+        assert!(interaction.command_data().is_none());
+
+        // Malformed data fails rather than being decoded as another type of data.
+        data["data"]["custom_id"] = json!(5);
+        assert!(serde_json::from_value::<Interaction>(data).is_err());
+    }
+}
@@ -0,0 +1,442 @@
+//! The [Message][https://discord.com/developers/docs/resources/message] resource.
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::Timestamp;
+use crate::models::channel::{Channel, ChannelType};
+use crate::models::component::Component;
+use crate::models::embed::Embed;
+use crate::models::guild::{Emoji, Member};
+use crate::models::user::User;
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ApplicationMarker, AttachmentMarker, ChannelMarker, GenericMarker, GuildMarker, MessageMarker, RoleMarker, StickerMarker, WebhookMarker};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [A message sent within a channel][https://discord.com/developers/docs/resources/message#message-object]
+pub struct Message {
+
+    /// The id of the message
+    pub id: Id<MessageMarker>,
+
+    /// The id of the channel the message was sent in
+    pub channel_id: Id<ChannelMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild the message was sent in, only sent within Gateway events
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    /// The author of the message, for webhooks this is the id, username & avatar of the webhook
+    pub author: User,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The guild member of the author without its user, only sent within Gateway events for messages in a guild
+    pub member: Option<Member>,
+
+    /// The contents of the message, empty without the MESSAGE_CONTENT intent
+    pub content: String,
+
+    /// When the message was sent
+    pub timestamp: Timestamp,
+
+    /// When the message was last edited
+    pub edited_timestamp: Option<Timestamp>,
+
+    /// Whether the message was a text to speech message
+    pub tts: bool,
+
+    /// Whether the message mentions everyone
+    pub mention_everyone: bool,
+
+    /// The users mentioned in the message
+    pub mentions: Vec<User>,
+
+    /// The ids of the roles mentioned in the message
+    pub mention_roles: Vec<Id<RoleMarker>>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The channels mentioned in a crossposted message
+    pub mention_channels: Vec<ChannelMention>,
+
+    /// The files attached to the message
+    pub attachments: Vec<Attachment>,
+
+    /// The embedded content of the message
+    pub embeds: Vec<Embed>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The reactions to the message
+    pub reactions: Vec<Reaction>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Used for validating a message was sent, either an integer or a string
+    pub nonce: Option<Value>,
+
+    /// Whether the message is pinned
+    pub pinned: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the webhook which sent the message
+    pub webhook_id: Option<Id<WebhookMarker>>,
+
+    #[serde(rename = "type")]
+    /// The type of the message
+    pub kind: MessageType,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Sent with Rich Presence-related chat embeds
+    pub activity: Option<MessageActivity>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// A partial application, sent with Rich Presence-related chat embeds
+    pub application: Option<Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the application if the message is an interaction or application-owned webhook
+    pub application_id: Option<Id<ApplicationMarker>>,
+
+    #[serde(default)]
+    /// The flags of the message
+    pub flags: MessageFlags,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The source of a crosspost, reply, pin or forward
+    pub message_reference: Option<MessageReference>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The messages forwarded by this message
+    pub message_snapshots: Vec<Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The message replied to, None if it was deleted or Discord didnt send it
+    pub referenced_message: Option<Box<Message>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Information about the interaction this message is the response of
+    pub interaction_metadata: Option<Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The thread started from this message
+    pub thread: Option<Channel>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The components of the message, such as buttons
+    pub components: Vec<Component>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The stickers sent with the message
+    pub sticker_items: Vec<StickerItem>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The approximate position of the message in a thread
+    pub position: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Information about a role subscription purchase, for RoleSubscriptionPurchase messages
+    pub role_subscription_data: Option<Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The poll of the message
+    pub poll: Option<Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The call associated with the message
+    pub call: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A channel mentioned within a crossposted message][https://discord.com/developers/docs/resources/message#channel-mention-object]
+pub struct ChannelMention {
+
+    /// The id of the channel
+    pub id: Id<ChannelMarker>,
+
+    /// The id of the guild containing the channel
+    pub guild_id: Id<GuildMarker>,
+
+    #[serde(rename = "type")]
+    /// The type of the channel
+    pub kind: ChannelType,
+
+    /// The name of the channel
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [A file attached to a message][https://discord.com/developers/docs/resources/message#attachment-object]
+pub struct Attachment {
+
+    /// The id of the attachment
+    pub id: Id<AttachmentMarker>,
+
+    /// The name of the attached file
+    pub filename: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The title of the file
+    pub title: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The description (alt text) of the file
+    pub description: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The media type of the file
+    pub content_type: Option<String>,
+
+    /// The size of the file in bytes
+    pub size: u64,
+
+    /// The source url of the file
+    pub url: String,
+
+    /// The proxied url of the file
+    pub proxy_url: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The height of the file if it is an image
+    pub height: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The width of the file if it is an image
+    pub width: Option<u32>,
+
+    #[serde(default)]
+    /// Whether the attachment is ephemeral, ephemeral attachments are removed after a set period of time
+    pub ephemeral: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The duration of a voice message in seconds
+    pub duration_secs: Option<f64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// A base64 encoded byte array representing a sampled waveform of a voice message
+    pub waveform: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The flags of the attachment
+    pub flags: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A reaction to a message][https://discord.com/developers/docs/resources/message#reaction-object]
+pub struct Reaction {
+
+    /// The total amount of times the emoji has been used to react, including super reactions
+    pub count: u32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The amount of normal & super reactions
+    pub count_details: Option<ReactionCountDetails>,
+
+    /// Whether the current user reacted using this emoji
+    pub me: bool,
+
+    #[serde(default)]
+    /// Whether the current user super-reacted using this emoji
+    pub me_burst: bool,
+
+    /// The partial emoji of the reaction
+    pub emoji: Emoji,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The HEX colours utilised for super reactions
+    pub burst_colors: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+/// [The amount of each kind of reaction][https://discord.com/developers/docs/resources/message#reaction-count-details-object] to a message.
+pub struct ReactionCountDetails {
+
+    /// The amount of super reactions
+    pub burst: u32,
+
+    /// The amount of normal reactions
+    pub normal: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// [A reference to another message][https://discord.com/developers/docs/resources/message#message-reference-structure], utilised for replies, crossposts & forwards.
+pub struct MessageReference {
+
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    /// The type of reference, Default when missing
+    pub kind: Option<MessageReferenceType>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the referenced message
+    pub message_id: Option<Id<MessageMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the channel of the referenced message, optional when creating a reply
+    pub channel_id: Option<Id<ChannelMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild of the referenced message
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When sending, whether to fail if the referenced message doesnt exist instead of sending a normal message
+    pub fail_if_not_exists: Option<bool>,
+}
+
+enum_number! {
+    /// [The type of a message reference][https://discord.com/developers/docs/resources/message#message-reference-types]
+    pub enum MessageReferenceType: u8 {
+        Default = 0,
+        Forward = 1,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A Rich Presence activity][https://discord.com/developers/docs/resources/message#message-object-message-activity-structure] attached to a message.
+pub struct MessageActivity {
+
+    #[serde(rename = "type")]
+    /// The type of the activity: 1 join, 2 spectate, 3 listen, 5 join request
+    pub kind: u8,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The party id from a Rich Presence event
+    pub party_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The smallest amount of data required to render a sticker][https://discord.com/developers/docs/resources/sticker#sticker-item-object]
+pub struct StickerItem {
+
+    /// The id of the sticker
+    pub id: Id<StickerMarker>,
+
+    /// The name of the sticker
+    pub name: String,
+
+    /// The type of the sticker format
+    pub format_type: StickerFormatType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A sticker which can be sent within messages][https://discord.com/developers/docs/resources/sticker#sticker-object]
+pub struct Sticker {
+
+    /// The id of the sticker
+    pub id: Id<StickerMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the pack of standard stickers the sticker belongs to
+    pub pack_id: Option<Id<GenericMarker>>,
+
+    /// The name of the sticker
+    pub name: String,
+
+    /// The description of the sticker
+    pub description: Option<String>,
+
+    /// The autocomplete & suggestion tags of the sticker, separated by commas
+    pub tags: String,
+
+    #[serde(rename = "type")]
+    /// Whether the sticker is a standard or guild sticker
+    pub kind: StickerType,
+
+    /// The type of the sticker format
+    pub format_type: StickerFormatType,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the guild sticker can be used, may be false due to loss of Server Boosts
+    pub available: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild the sticker belongs to
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The user who uploaded the guild sticker
+    pub user: Option<User>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The sort order of a standard sticker within its pack
+    pub sort_value: Option<u32>,
+}
+
+enum_number! {
+    /// [Whether a sticker is a standard or guild sticker][https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-types]
+    pub enum StickerType: u8 {
+        Standard = 1,
+        Guild = 2,
+    }
+}
+
+enum_number! {
+    /// [The format of a sticker][https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-format-types]
+    pub enum StickerFormatType: u8 {
+        Png = 1,
+        Apng = 2,
+        Lottie = 3,
+        Gif = 4,
+    }
+}
+
+enum_number! {
+    /// [The type of a message][https://discord.com/developers/docs/resources/message#message-object-message-types]
+    pub enum MessageType: u8 {
+        Default = 0,
+        RecipientAdd = 1,
+        RecipientRemove = 2,
+        Call = 3,
+        ChannelNameChange = 4,
+        ChannelIconChange = 5,
+        ChannelPinnedMessage = 6,
+        UserJoin = 7,
+        GuildBoost = 8,
+        GuildBoostTier1 = 9,
+        GuildBoostTier2 = 10,
+        GuildBoostTier3 = 11,
+        ChannelFollowAdd = 12,
+        GuildDiscoveryDisqualified = 14,
+        GuildDiscoveryRequalified = 15,
+        GuildDiscoveryGracePeriodInitialWarning = 16,
+        GuildDiscoveryGracePeriodFinalWarning = 17,
+        ThreadCreated = 18,
+        Reply = 19,
+        ChatInputCommand = 20,
+        ThreadStarterMessage = 21,
+        GuildInviteReminder = 22,
+        ContextMenuCommand = 23,
+        AutoModerationAction = 24,
+        RoleSubscriptionPurchase = 25,
+        InteractionPremiumUpsell = 26,
+        StageStart = 27,
+        StageEnd = 28,
+        StageSpeaker = 29,
+        StageTopic = 31,
+        GuildApplicationPremiumSubscription = 32,
+        GuildIncidentAlertModeEnabled = 36,
+        GuildIncidentAlertModeDisabled = 37,
+        GuildIncidentReportRaid = 38,
+        GuildIncidentReportFalseAlarm = 39,
+        PurchaseNotification = 44,
+        PollResult = 46,
+    }
+}
+
+bitflags::bitflags! {
+
+    /// [The flags of a message][https://discord.com/developers/docs/resources/message#message-object-message-flags]
+    pub struct MessageFlags: u32 {
+        const CROSSPOSTED = 1 << 0;
+        const IS_CROSSPOST = 1 << 1;
+        const SUPPRESS_EMBEDS = 1 << 2;
+        const SOURCE_MESSAGE_DELETED = 1 << 3;
+        const URGENT = 1 << 4;
+        const HAS_THREAD = 1 << 5;
+        const EPHEMERAL = 1 << 6;
+        const LOADING = 1 << 7;
+        const FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1 << 8;
+        const SUPPRESS_NOTIFICATIONS = 1 << 12;
+        const IS_VOICE_MESSAGE = 1 << 13;
+        const HAS_SNAPSHOT = 1 << 14;
+        const IS_COMPONENTS_V2 = 1 << 15;
+    }
+}
+
+bitflags_serde!(MessageFlags: u32);
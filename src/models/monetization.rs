@@ -0,0 +1,108 @@
+//! The [Entitlement][https://discord.com/developers/docs/resources/entitlement] & [Subscription][https://discord.com/developers/docs/resources/subscription] resources of premium offerings.
+use serde::{Deserialize, Serialize};
+
+use crate::models::Timestamp;
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ApplicationMarker, EntitlementMarker, GuildMarker, SkuMarker, SubscriptionMarker, UserMarker};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [Access of a user or guild to a premium offering][https://discord.com/developers/docs/resources/entitlement#entitlement-object] of the application.
+pub struct Entitlement {
+
+    /// The id of the entitlement
+    pub id: Id<EntitlementMarker>,
+
+    /// The id of the SKU
+    pub sku_id: Id<SkuMarker>,
+
+    /// The id of the application
+    pub application_id: Id<ApplicationMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the user which is granted access
+    pub user_id: Option<Id<UserMarker>>,
+
+    #[serde(rename = "type")]
+    /// How the entitlement was acquired
+    pub kind: EntitlementType,
+
+    /// Whether the entitlement was deleted
+    pub deleted: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the entitlement starts, None for test entitlements
+    pub starts_at: Option<Timestamp>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the entitlement ends, None for test entitlements
+    pub ends_at: Option<Timestamp>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild which is granted access
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the entitlement was consumed, for consumable SKUs
+    pub consumed: Option<bool>,
+}
+
+enum_number! {
+    /// [How an entitlement was acquired][https://discord.com/developers/docs/resources/entitlement#entitlement-object-entitlement-types]
+    pub enum EntitlementType: u8 {
+        Purchase = 1,
+        PremiumSubscription = 2,
+        DeveloperGift = 3,
+        TestModePurchase = 4,
+        FreePurchase = 5,
+        UserGift = 6,
+        PremiumPurchase = 7,
+        ApplicationSubscription = 8,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A subscription of a user to a premium offering][https://discord.com/developers/docs/resources/subscription#subscription-object] of the application.
+pub struct Subscription {
+
+    /// The id of the subscription
+    pub id: Id<SubscriptionMarker>,
+
+    /// The id of the user which is subscribed
+    pub user_id: Id<UserMarker>,
+
+    /// The ids of the SKUs which are subscribed to
+    pub sku_ids: Vec<Id<SkuMarker>>,
+
+    /// The ids of the entitlements granted by the subscription
+    pub entitlement_ids: Vec<Id<EntitlementMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The ids of the SKUs which the user is subscribed to renew to
+    pub renewal_sku_ids: Option<Vec<Id<SkuMarker>>>,
+
+    /// When the current period of the subscription started
+    pub current_period_start: Timestamp,
+
+    /// When the current period of the subscription ends
+    pub current_period_end: Timestamp,
+
+    /// The status of the subscription
+    pub status: SubscriptionStatus,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the subscription was canceled
+    pub canceled_at: Option<Timestamp>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The ISO3166-1 alpha-2 country code of the payment source, only sent when the subscription is retrieved
+    pub country: Option<String>,
+}
+
+enum_number! {
+    /// [The status of a subscription][https://discord.com/developers/docs/resources/subscription#subscription-statuses]
+    pub enum SubscriptionStatus: u8 {
+        Active = 0,
+        Ending = 1,
+        Inactive = 2,
+    }
+}
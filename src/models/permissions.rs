@@ -0,0 +1,319 @@
+//! [Permissions][https://discord.com/developers/docs/topics/permissions] of members within guilds & channels, alongside calculating them from roles & overwrites.
+//! The calculation only utilises the guild, roles, member & channel, so it works on cached data without any requests.
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::models::channel::{Channel, PermissionOverwriteType};
+use crate::models::guild::{Guild, Member, Role};
+use crate::snowflake::Id;
+use crate::snowflake::marker::{RoleMarker, UserMarker};
+
+bitflags::bitflags! {
+
+    /// [Bitflags Struct which represents the permissions of a role, overwrite or member][https://discord.com/developers/docs/topics/permissions#permissions-bitwise-permission-flags]
+    /// Sent by Discord as a string of the bits, as they dont fit within the integers of every language.
+    pub struct Permissions: u64 {
+        const CREATE_INSTANT_INVITE = 1 << 0;
+        const KICK_MEMBERS = 1 << 1;
+        const BAN_MEMBERS = 1 << 2;
+
+        /// Allows every permission & bypasses channel overwrites
+        const ADMINISTRATOR = 1 << 3;
+        const MANAGE_CHANNELS = 1 << 4;
+        const MANAGE_GUILD = 1 << 5;
+        const ADD_REACTIONS = 1 << 6;
+        const VIEW_AUDIT_LOG = 1 << 7;
+        const PRIORITY_SPEAKER = 1 << 8;
+        const STREAM = 1 << 9;
+
+        /// Without this permission a member has no other permissions within the channel
+        const VIEW_CHANNEL = 1 << 10;
+        const SEND_MESSAGES = 1 << 11;
+        const SEND_TTS_MESSAGES = 1 << 12;
+        const MANAGE_MESSAGES = 1 << 13;
+        const EMBED_LINKS = 1 << 14;
+        const ATTACH_FILES = 1 << 15;
+        const READ_MESSAGE_HISTORY = 1 << 16;
+        const MENTION_EVERYONE = 1 << 17;
+        const USE_EXTERNAL_EMOJIS = 1 << 18;
+        const VIEW_GUILD_INSIGHTS = 1 << 19;
+        const CONNECT = 1 << 20;
+        const SPEAK = 1 << 21;
+        const MUTE_MEMBERS = 1 << 22;
+        const DEAFEN_MEMBERS = 1 << 23;
+        const MOVE_MEMBERS = 1 << 24;
+        const USE_VAD = 1 << 25;
+        const CHANGE_NICKNAME = 1 << 26;
+        const MANAGE_NICKNAMES = 1 << 27;
+        const MANAGE_ROLES = 1 << 28;
+        const MANAGE_WEBHOOKS = 1 << 29;
+        const MANAGE_GUILD_EXPRESSIONS = 1 << 30;
+        const USE_APPLICATION_COMMANDS = 1 << 31;
+        const REQUEST_TO_SPEAK = 1 << 32;
+        const MANAGE_EVENTS = 1 << 33;
+        const MANAGE_THREADS = 1 << 34;
+        const CREATE_PUBLIC_THREADS = 1 << 35;
+        const CREATE_PRIVATE_THREADS = 1 << 36;
+        const USE_EXTERNAL_STICKERS = 1 << 37;
+        const SEND_MESSAGES_IN_THREADS = 1 << 38;
+        const USE_EMBEDDED_ACTIVITIES = 1 << 39;
+        const MODERATE_MEMBERS = 1 << 40;
+        const VIEW_CREATOR_MONETIZATION_ANALYTICS = 1 << 41;
+        const USE_SOUNDBOARD = 1 << 42;
+        const CREATE_GUILD_EXPRESSIONS = 1 << 43;
+        const CREATE_EVENTS = 1 << 44;
+        const USE_EXTERNAL_SOUNDS = 1 << 45;
+        const SEND_VOICE_MESSAGES = 1 << 46;
+        const SET_VOICE_CHANNEL_STATUS = 1 << 48;
+        const SEND_POLLS = 1 << 49;
+        const USE_EXTERNAL_APPS = 1 << 50;
+        const PIN_MESSAGES = 1 << 51;
+        const BYPASS_SLOWMODE = 1 << 52;
+    }
+}
+
+impl Default for Permissions {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl Serialize for Permissions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for Permissions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = String::deserialize(deserializer)?;
+        bits.parse()
+            // Unknown bits are kept, so permissions Discord adds before the library survive being stored & sent back.
+            .map(|bits| unsafe { Self::from_bits_unchecked(bits) })
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(&bits), &"a permission bit set as a string"))
+    }
+}
+
+/// Applies overwrites to permissions, the denied permissions are removed before the allowed permissions are added.
+fn apply_overwrite(permissions: Permissions, allow: Permissions, deny: Permissions) -> Permissions {
+    (permissions - deny) | allow
+}
+
+/// The only permissions a member which is timed out keeps, unless it is the owner or an administrator.
+pub const TIMED_OUT_PERMISSIONS: Permissions = Permissions::VIEW_CHANNEL.union(Permissions::READ_MESSAGE_HISTORY);
+
+/// Limits the permissions of a timed out member which isnt an administrator to [`TIMED_OUT_PERMISSIONS`]
+fn apply_timeout(permissions: Permissions, timed_out: bool) -> Permissions {
+    match timed_out && !permissions.contains(Permissions::ADMINISTRATOR) {
+        true => permissions & TIMED_OUT_PERMISSIONS,
+        false => permissions,
+    }
+}
+
+/// The permissions of a member within a guild, before any channel overwrites are applied.
+/// The roles are the roles of the guild, including the @everyone role which shares the id of the guild.
+/// The owner of the guild & members with the ADMINISTRATOR permission have every permission, timed out members only keep [`TIMED_OUT_PERMISSIONS`]
+pub fn guild_permissions(guild: &Guild, roles: &[Role], user_id: Id<UserMarker>, member_roles: &[Id<RoleMarker>], timed_out: bool) -> Permissions {
+
+    if guild.owner_id == user_id {
+        return Permissions::all()
+    }
+
+    let everyone_role_id = guild.id.cast::<RoleMarker>();
+    let permissions = roles.iter()
+        .filter(|role| role.id == everyone_role_id || member_roles.contains(&role.id))
+        .fold(Permissions::empty(), |permissions, role| permissions | role.permissions);
+
+    match permissions.contains(Permissions::ADMINISTRATOR) {
+        true => Permissions::all(),
+        false => apply_timeout(permissions, timed_out),
+    }
+}
+
+/// The permissions of a member within a channel, applying the overwrites of the channel to [`guild_permissions`]
+/// Overwrites are applied in the order Discord applies them: the @everyone overwrite, then the overwrites of the roles of the member combined, then the overwrite of the member.
+/// Threads dont have overwrites of their own, so the parent channel of a thread should be passed instead.
+/// Overwrites cant grant a timed out member more than [`TIMED_OUT_PERMISSIONS`]
+pub fn channel_permissions(guild: &Guild, roles: &[Role], user_id: Id<UserMarker>, member_roles: &[Id<RoleMarker>], timed_out: bool, channel: &Channel) -> Permissions {
+
+    let mut permissions = guild_permissions(guild, roles, user_id, member_roles, timed_out);
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        return permissions
+    }
+
+    let overwrites = &channel.permission_overwrites;
+    let find_overwrite = |id: u64, kind: PermissionOverwriteType| overwrites.iter().find(|overwrite| overwrite.id.get() == id && overwrite.kind == kind);
+
+    if let Some(everyone) = find_overwrite(guild.id.get(), PermissionOverwriteType::Role) {
+        permissions = apply_overwrite(permissions, everyone.allow, everyone.deny);
+    }
+
+    // The overwrites of every role are combined before being applied, so an allow on one role wins over a deny on another.
+    let (allow, deny) = member_roles.iter()
+        .filter_map(|role_id| find_overwrite(role_id.get(), PermissionOverwriteType::Role))
+        .fold((Permissions::empty(), Permissions::empty()), |(allow, deny), overwrite| (allow | overwrite.allow, deny | overwrite.deny));
+    permissions = apply_overwrite(permissions, allow, deny);
+
+    if let Some(member) = find_overwrite(user_id.get(), PermissionOverwriteType::Member) {
+        permissions = apply_overwrite(permissions, member.allow, member.deny);
+    }
+
+    // Members which cant view a channel implicitly have no permissions within it, & members which cant send messages cant mention or attach anything.
+    if !permissions.contains(Permissions::VIEW_CHANNEL) {
+        return Permissions::empty()
+    }
+
+    if !permissions.contains(Permissions::SEND_MESSAGES) {
+        permissions -= Permissions::SEND_TTS_MESSAGES | Permissions::MENTION_EVERYONE | Permissions::EMBED_LINKS | Permissions::ATTACH_FILES;
+    }
+
+    apply_timeout(permissions, timed_out)
+}
+
+/// The permissions of a member within a guild or a channel of it, utilising the roles of the member.
+/// Shortened form of [`guild_permissions`] & [`channel_permissions`] for a [`Member`] which was sent with its user, checking whether it is timed out.
+pub fn member_permissions(guild: &Guild, roles: &[Role], member: &Member, channel: Option<&Channel>) -> Option<Permissions> {
+    let user_id = member.user.as_ref()?.id;
+    let timed_out = member.is_timed_out();
+    Some(match channel {
+        Some(channel) => channel_permissions(guild, roles, user_id, &member.roles, timed_out, channel),
+        None => guild_permissions(guild, roles, user_id, &member.roles, timed_out),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::fixtures;
+    use crate::models::channel::Channel;
+    use crate::models::guild::{Guild, Member, Role};
+    use crate::snowflake::Id;
+    use super::{Permissions, TIMED_OUT_PERMISSIONS, channel_permissions, member_permissions};
+
+    fn role(role_id: u64, permissions: u64) -> serde_json::Value {
+        json!({
+            "id": role_id.to_string(),
+            "name": "Role",
+            "color": 0,
+            "hoist": false,
+            "position": 0,
+            "permissions": permissions.to_string(),
+            "managed": false,
+            "mentionable": false,
+        })
+    }
+
+    fn guild_and_roles(everyone: Permissions) -> (Guild, Vec<Role>) {
+        let guild = serde_json::from_value(fixtures::guild(10, 1)).unwrap();
+        let roles = vec![
+            serde_json::from_value(role(10, everyone.bits())).unwrap(),
+            serde_json::from_value(role(11, Permissions::ADMINISTRATOR.bits())).unwrap(),
+        ];
+        (guild, roles)
+    }
+
+    /// A channel with the overwrites, each as its id, type, allowed & denied permissions.
+    fn channel(overwrites: &[(u64, u8, Permissions, Permissions)]) -> Channel {
+        let mut channel = fixtures::channel(20, 10);
+        channel["permission_overwrites"] = overwrites.iter()
+            .map(|(id, kind, allow, deny)| json!({ "id": id.to_string(), "type": kind, "allow": allow.bits().to_string(), "deny": deny.bits().to_string() }))
+            .collect();
+        serde_json::from_value(channel).unwrap()
+    }
+
+    /// The permissions of a member which isnt timed out within the channel.
+    fn permissions_within(guild: &Guild, roles: &[Role], user_id: u64, role_ids: &[u64], channel: &Channel) -> Permissions {
+        let role_ids: Vec<_> = role_ids.iter().copied().map(Id::new).collect();
+        channel_permissions(guild, roles, Id::new(user_id), &role_ids, false, channel)
+    }
+
+    fn timed_out_member(user_id: u64, role_ids: &[u64]) -> Member {
+        let mut member = fixtures::member(user_id, role_ids);
+        member["communication_disabled_until"] = json!("2999-01-01T00:00:00.000000+00:00");
+        serde_json::from_value(member).unwrap()
+    }
+
+    #[test]
+    fn overwrites_are_applied_in_order() {
+        let (guild, mut roles) = guild_and_roles(Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS);
+        roles.push(serde_json::from_value(role(12, 0)).unwrap());
+        roles.push(serde_json::from_value(role(13, 0)).unwrap());
+
+        let (send, react) = (Permissions::SEND_MESSAGES, Permissions::ADD_REACTIONS);
+        let channel = channel(&[
+            (10, 0, Permissions::empty(), send | react),
+            (12, 0, send, Permissions::empty()),
+            (13, 0, react, send),
+            (3, 1, Permissions::empty(), react),
+        ]);
+
+        // The @everyone overwrite applies to everyone.
+        assert_eq!(permissions_within(&guild, &roles, 2, &[], &channel), Permissions::VIEW_CHANNEL);
+
+        // The overwrites of the roles are combined, so the allow of one role wins over the deny of another.
+        assert_eq!(permissions_within(&guild, &roles, 2, &[12, 13], &channel), Permissions::VIEW_CHANNEL | send | react);
+        assert_eq!(permissions_within(&guild, &roles, 2, &[13], &channel), Permissions::VIEW_CHANNEL | react);
+
+        // The overwrite of the member is applied last.
+        assert_eq!(permissions_within(&guild, &roles, 3, &[12, 13], &channel), Permissions::VIEW_CHANNEL | send);
+    }
+
+    #[test]
+    fn owners_and_administrators_ignore_overwrites() {
+        let (guild, roles) = guild_and_roles(Permissions::VIEW_CHANNEL);
+        let channel = channel(&[(10, 0, Permissions::empty(), Permissions::VIEW_CHANNEL), (2, 1, Permissions::empty(), Permissions::all())]);
+
+        assert_eq!(permissions_within(&guild, &roles, 1, &[], &channel), Permissions::all());
+        assert_eq!(permissions_within(&guild, &roles, 2, &[11], &channel), Permissions::all());
+        assert_eq!(permissions_within(&guild, &roles, 3, &[], &channel), Permissions::empty());
+    }
+
+    #[test]
+    fn denied_viewing_or_sending_removes_the_permissions_depending_on_them() {
+        let message_permissions = Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS | Permissions::ATTACH_FILES | Permissions::MENTION_EVERYONE | Permissions::SEND_TTS_MESSAGES;
+        let (guild, roles) = guild_and_roles(Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY | message_permissions);
+
+        let cant_send = channel(&[(10, 0, Permissions::empty(), Permissions::SEND_MESSAGES)]);
+        assert_eq!(permissions_within(&guild, &roles, 2, &[], &cant_send), Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY);
+
+        let cant_view = channel(&[(2, 1, Permissions::empty(), Permissions::VIEW_CHANNEL)]);
+        assert_eq!(permissions_within(&guild, &roles, 2, &[], &cant_view), Permissions::empty());
+    }
+
+    #[test]
+    fn unknown_bits_are_kept() {
+        let bits = Permissions::SET_VOICE_CHANNEL_STATUS.bits() | 1 << 60;
+        let permissions: Permissions = serde_json::from_value(json!(bits.to_string())).unwrap();
+
+        assert_eq!(permissions.bits(), bits);
+        assert_eq!(serde_json::to_value(permissions).unwrap(), json!(bits.to_string()));
+    }
+
+    #[test]
+    fn timed_out_member_only_keeps_viewing_permissions() {
+        let (guild, roles) = guild_and_roles(Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES | Permissions::READ_MESSAGE_HISTORY);
+        let mut channel: Channel = serde_json::from_value(fixtures::channel(20, 10)).unwrap();
+        channel.permission_overwrites = serde_json::from_value(json!([{ "id": "2", "type": 1, "allow": Permissions::ADD_REACTIONS.bits().to_string(), "deny": "0" }])).unwrap();
+
+        let member = timed_out_member(2, &[]);
+        assert_eq!(member_permissions(&guild, &roles, &member, None), Some(TIMED_OUT_PERMISSIONS));
+        assert_eq!(member_permissions(&guild, &roles, &member, Some(&channel)), Some(TIMED_OUT_PERMISSIONS));
+    }
+
+    #[test]
+    fn timeout_doesnt_limit_owners_or_administrators() {
+        let (guild, roles) = guild_and_roles(Permissions::VIEW_CHANNEL);
+        assert_eq!(member_permissions(&guild, &roles, &timed_out_member(1, &[]), None), Some(Permissions::all()));
+        assert_eq!(member_permissions(&guild, &roles, &timed_out_member(2, &[11]), None), Some(Permissions::all()));
+    }
+
+    #[test]
+    fn expired_timeout_is_ignored() {
+        let (guild, roles) = guild_and_roles(Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES);
+        let mut member = fixtures::member(2, &[]);
+        member["communication_disabled_until"] = json!("2022-08-23T18:24:09.123000+00:00");
+        let member: Member = serde_json::from_value(member).unwrap();
+
+        assert_eq!(member_permissions(&guild, &roles, &member, None), Some(Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES));
+    }
+}
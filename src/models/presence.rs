@@ -0,0 +1,171 @@
+//! The [Presence][https://discord.com/developers/docs/events/gateway-events#presence] of users, sent through the gateway with the GUILD_PRESENCES intent.
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::guild::Emoji;
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ApplicationMarker, GuildMarker, UserMarker};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The presence of a user within a guild][https://discord.com/developers/docs/events/gateway-events#presence-update-presence-update-event-fields]
+pub struct Presence {
+
+    /// The user the presence belongs to, only the id is always sent
+    pub user: PresenceUser,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild, not sent within GUILD_CREATE & GUILD_MEMBERS_CHUNK
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    /// The status of the user
+    pub status: Status,
+
+    #[serde(default)]
+    /// The current activities of the user
+    pub activities: Vec<Activity>,
+
+    #[serde(default)]
+    /// The status of the user on each platform
+    pub client_status: ClientStatus,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// The partial user within a [`Presence`], Discord only sends the fields which changed alongside the id.
+pub struct PresenceUser {
+
+    /// The id of the user
+    pub id: Id<UserMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The username of the user
+    pub username: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The display name of the user
+    pub global_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The avatar hash of the user
+    pub avatar: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the user belongs to an OAuth2 application
+    pub bot: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+/// [The status of a user][https://discord.com/developers/docs/events/gateway-events#update-presence-status-types]
+pub enum Status {
+    Online,
+    Idle,
+    Dnd,
+    Invisible,
+    #[default]
+    Offline,
+
+    /// A status which isnt known by the library.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// [The status of a user on each platform][https://discord.com/developers/docs/events/gateway-events#client-status-object], None if they arent active on it.
+pub struct ClientStatus {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The status on the desktop application
+    pub desktop: Option<Status>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The status on the mobile application
+    pub mobile: Option<Status>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The status on the web application or the browser
+    pub web: Option<Status>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [An activity of a user][https://discord.com/developers/docs/events/gateway-events#activity-object], such as playing a game.
+pub struct Activity {
+
+    /// The name of the activity
+    pub name: String,
+
+    #[serde(rename = "type")]
+    /// The type of the activity
+    pub kind: ActivityType,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The url of the stream, for Streaming activities
+    pub url: Option<String>,
+
+    /// When the activity was added to the session of the user, in milliseconds since the unix epoch
+    pub created_at: u64,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the activity started & ends
+    pub timestamps: Option<ActivityTimestamps>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the application of the game
+    pub application_id: Option<Id<ApplicationMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// What the user is currently doing
+    pub details: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The current party status of the user, or the text of Custom activities
+    pub state: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The emoji of Custom activities
+    pub emoji: Option<Emoji>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The party of the user
+    pub party: Option<Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The images of the activity & their hover texts
+    pub assets: Option<Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the activity is an instanced game session
+    pub instance: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The activity flags
+    pub flags: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The labels of the custom buttons shown within the activity
+    pub buttons: Vec<String>,
+}
+
+enum_number! {
+    /// [The type of an activity][https://discord.com/developers/docs/events/gateway-events#activity-object-activity-types]
+    pub enum ActivityType: u8 {
+        Playing = 0,
+        Streaming = 1,
+        Listening = 2,
+        Watching = 3,
+        Custom = 4,
+        Competing = 5,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// [When an activity started & ends][https://discord.com/developers/docs/events/gateway-events#activity-object-activity-timestamps], in milliseconds since the unix epoch.
+pub struct ActivityTimestamps {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the activity started
+    pub start: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// When the activity ends
+    pub end: Option<u64>,
+}
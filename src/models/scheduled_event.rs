@@ -0,0 +1,105 @@
+//! The [Guild Scheduled Event][https://discord.com/developers/docs/resources/guild-scheduled-event] resource.
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::Timestamp;
+use crate::models::user::User;
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ChannelMarker, GenericMarker, GuildMarker, ScheduledEventMarker, UserMarker};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [An event scheduled within a guild][https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object]
+pub struct GuildScheduledEvent {
+
+    /// The id of the scheduled event
+    pub id: Id<ScheduledEventMarker>,
+
+    /// The id of the guild which the scheduled event belongs to
+    pub guild_id: Id<GuildMarker>,
+
+    /// The id of the channel the scheduled event is hosted in, None for external events
+    pub channel_id: Option<Id<ChannelMarker>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the user who created the scheduled event, None for events created before October 25th 2021
+    pub creator_id: Option<Id<UserMarker>>,
+
+    /// The name of the scheduled event
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The description of the scheduled event
+    pub description: Option<String>,
+
+    /// When the scheduled event will start
+    pub scheduled_start_time: Timestamp,
+
+    /// When the scheduled event will end, required for external events
+    pub scheduled_end_time: Option<Timestamp>,
+
+    /// Who can see the scheduled event
+    pub privacy_level: ScheduledEventPrivacyLevel,
+
+    /// The status of the scheduled event
+    pub status: ScheduledEventStatus,
+
+    /// Where the scheduled event is hosted
+    pub entity_type: ScheduledEventEntityType,
+
+    /// The id of the entity the scheduled event is hosted by, such as a stage instance
+    pub entity_id: Option<Id<GenericMarker>>,
+
+    /// Additional data of the entity, the location of external events
+    pub entity_metadata: Option<ScheduledEventEntityMetadata>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The user who created the scheduled event
+    pub creator: Option<User>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The amount of users subscribed to the scheduled event, only sent when requested with with_user_count
+    pub user_count: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The cover image hash of the scheduled event
+    pub image: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// How often the scheduled event recurs
+    pub recurrence_rule: Option<Value>,
+}
+
+enum_number! {
+    /// [Who can see a scheduled event][https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-privacy-level]
+    pub enum ScheduledEventPrivacyLevel: u8 {
+        GuildOnly = 2,
+    }
+}
+
+enum_number! {
+    /// [The status of a scheduled event][https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-status]
+    pub enum ScheduledEventStatus: u8 {
+        Scheduled = 1,
+        Active = 2,
+        Completed = 3,
+        Canceled = 4,
+    }
+}
+
+enum_number! {
+    /// [Where a scheduled event is hosted][https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-types]
+    pub enum ScheduledEventEntityType: u8 {
+        StageInstance = 1,
+        Voice = 2,
+        External = 3,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// [Additional data of the entity a scheduled event is hosted by][https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-metadata]
+pub struct ScheduledEventEntityMetadata {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The location of an external event
+    pub location: Option<String>,
+}
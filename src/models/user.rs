@@ -0,0 +1,131 @@
+//! The [User][https://discord.com/developers/docs/resources/user#user-object] resource.
+use serde::{Deserialize, Serialize};
+
+use crate::snowflake::Id;
+use crate::snowflake::marker::{GenericMarker, UserMarker};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A user of Discord][https://discord.com/developers/docs/resources/user#user-object], which includes bots.
+pub struct User {
+
+    /// The id of the user
+    pub id: Id<UserMarker>,
+
+    /// The username of the user, unique across Discord
+    pub username: String,
+
+    /// The discriminator of the user, "0" for users which have migrated to unique usernames
+    pub discriminator: String,
+
+    /// The display name of the user, for bots this is the application name
+    pub global_name: Option<String>,
+
+    /// The avatar hash of the user
+    pub avatar: Option<String>,
+
+    #[serde(default)]
+    /// Whether the user belongs to an OAuth2 application
+    pub bot: bool,
+
+    #[serde(default)]
+    /// Whether the user is an Official Discord System user
+    pub system: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the user has two factor authentication enabled
+    pub mfa_enabled: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The banner hash of the user
+    pub banner: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The banner colour of the user as an integer representation of a hex colour
+    pub accent_color: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The chosen language of the user
+    pub locale: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the email of the user has been verified
+    pub verified: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The email of the user, requires the email OAuth2 scope
+    pub email: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The flags on the account of the user
+    pub flags: Option<UserFlags>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The type of Nitro subscription of the user
+    pub premium_type: Option<PremiumType>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The public flags on the account of the user
+    pub public_flags: Option<UserFlags>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The avatar decoration of the user
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
+}
+
+impl User {
+
+    /// The mention of the user which can be placed within a message, e.g: <@80351110224678912>
+    pub fn mention(&self) -> String {
+        format!("<@{}>", self.id)
+    }
+
+    /// The name of the user shown within Discord, the global name if the user has one otherwise the username.
+    pub fn display_name(&self) -> &str {
+        self.global_name.as_deref().unwrap_or(&self.username)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The avatar decoration][https://discord.com/developers/docs/resources/user#avatar-decoration-data-object] shown around the avatar of a user.
+pub struct AvatarDecorationData {
+
+    /// The hash of the avatar decoration
+    pub asset: String,
+
+    /// The id of the SKU the avatar decoration belongs to
+    pub sku_id: Id<GenericMarker>,
+}
+
+enum_number! {
+    /// [The type of Nitro subscription][https://discord.com/developers/docs/resources/user#user-object-premium-types] of a user.
+    pub enum PremiumType: u8 {
+        None = 0,
+        NitroClassic = 1,
+        Nitro = 2,
+        NitroBasic = 3,
+    }
+}
+
+bitflags::bitflags! {
+
+    /// [The flags on the account of a user][https://discord.com/developers/docs/resources/user#user-object-user-flags]
+    pub struct UserFlags: u64 {
+        const STAFF = 1 << 0;
+        const PARTNER = 1 << 1;
+        const HYPESQUAD = 1 << 2;
+        const BUG_HUNTER_LEVEL_1 = 1 << 3;
+        const HYPESQUAD_ONLINE_HOUSE_1 = 1 << 6;
+        const HYPESQUAD_ONLINE_HOUSE_2 = 1 << 7;
+        const HYPESQUAD_ONLINE_HOUSE_3 = 1 << 8;
+        const PREMIUM_EARLY_SUPPORTER = 1 << 9;
+        const TEAM_PSEUDO_USER = 1 << 10;
+        const BUG_HUNTER_LEVEL_2 = 1 << 14;
+        const VERIFIED_BOT = 1 << 16;
+        const VERIFIED_DEVELOPER = 1 << 17;
+        const CERTIFIED_MODERATOR = 1 << 18;
+        const BOT_HTTP_INTERACTIONS = 1 << 19;
+        const ACTIVE_DEVELOPER = 1 << 22;
+    }
+}
+
+bitflags_serde!(UserFlags: u64);
@@ -0,0 +1,121 @@
+//! The [Voice][https://discord.com/developers/docs/resources/voice], [Stage Instance][https://discord.com/developers/docs/resources/stage-instance] & [Soundboard][https://discord.com/developers/docs/resources/soundboard] resources.
+use serde::{Deserialize, Serialize};
+
+use crate::models::Timestamp;
+use crate::models::guild::Member;
+use crate::models::user::User;
+use crate::snowflake::Id;
+use crate::snowflake::marker::{ChannelMarker, EmojiMarker, GuildMarker, ScheduledEventMarker, SoundMarker, StageInstanceMarker, UserMarker};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [The voice connection state of a user][https://discord.com/developers/docs/resources/voice#voice-state-object]
+pub struct VoiceState {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild, not sent within GUILD_CREATE
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    /// The id of the channel the user is connected to, None once they disconnect
+    pub channel_id: Option<Id<ChannelMarker>>,
+
+    /// The id of the user
+    pub user_id: Id<UserMarker>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The guild member of the user
+    pub member: Option<Member>,
+
+    /// The id of the voice session
+    pub session_id: String,
+
+    /// Whether the user is deafened by the guild
+    pub deaf: bool,
+
+    /// Whether the user is muted by the guild
+    pub mute: bool,
+
+    /// Whether the user deafened themselves
+    pub self_deaf: bool,
+
+    /// Whether the user muted themselves
+    pub self_mute: bool,
+
+    #[serde(default)]
+    /// Whether the user is streaming with Go Live
+    pub self_stream: bool,
+
+    /// Whether the camera of the user is enabled
+    pub self_video: bool,
+
+    /// Whether the user is unable to speak within a stage channel
+    pub suppress: bool,
+
+    /// When the user requested to speak within a stage channel
+    pub request_to_speak_timestamp: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// [A live stage][https://discord.com/developers/docs/resources/stage-instance#stage-instance-object] within a stage channel.
+pub struct StageInstance {
+
+    /// The id of the stage instance
+    pub id: Id<StageInstanceMarker>,
+
+    /// The id of the guild
+    pub guild_id: Id<GuildMarker>,
+
+    /// The id of the stage channel
+    pub channel_id: Id<ChannelMarker>,
+
+    /// The topic of the stage instance
+    pub topic: String,
+
+    /// Who can see the stage instance
+    pub privacy_level: StagePrivacyLevel,
+
+    #[serde(default)]
+    /// Whether stage discovery is disabled, deprecated by Discord
+    pub discoverable_disabled: bool,
+
+    /// The id of the scheduled event the stage instance was started for
+    pub guild_scheduled_event_id: Option<Id<ScheduledEventMarker>>,
+}
+
+enum_number! {
+    /// [Who can see a stage instance][https://discord.com/developers/docs/resources/stage-instance#stage-instance-object-privacy-level]
+    pub enum StagePrivacyLevel: u8 {
+        Public = 1,
+        GuildOnly = 2,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// [A sound which can be played within voice channels][https://discord.com/developers/docs/resources/soundboard#soundboard-sound-object]
+pub struct SoundboardSound {
+
+    /// The id of the sound
+    pub sound_id: Id<SoundMarker>,
+
+    /// The name of the sound
+    pub name: String,
+
+    /// The volume of the sound, between 0 & 1
+    pub volume: f64,
+
+    /// The id of the custom emoji of the sound
+    pub emoji_id: Option<Id<EmojiMarker>>,
+
+    /// The unicode emoji of the sound
+    pub emoji_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The id of the guild the sound belongs to, None for default sounds
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    /// Whether the sound can be used, may be false due to loss of Server Boosts
+    pub available: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The user who created the sound
+    pub user: Option<User>,
+}
@@ -0,0 +1,631 @@
+//! Rate limiting for requests made through the [`DiscordHttpClient`][crate::http::DiscordHttpClient].
+//! Follows the [rate limit headers][https://discord.com/developers/docs/topics/rate-limits#header-format] sent with each response so requests wait instead of recieving a 429.
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex as StdMutex, MutexGuard, PoisonError};
+
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use tokio::time::{Duration, Instant, sleep_until};
+
+/// The amount of requests a bot is allowed to make per second across every route.
+pub const GLOBAL_RATE_LIMIT: u32 = 50;
+
+/// How often buckets which have reset & arent utilised by any request are removed, so buckets of channels & interactions which are no longer requested dont build up.
+pub const BUCKET_EVICTION_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// Identifies which rate limit bucket a request belongs to.
+/// Discord shares a bucket between routes with the same template, but splits it by the major parameter of the route.
+pub struct RateLimitRoute {
+
+    /// The method & path template of the route, e.g: "POST channels/{channel_id}/messages"
+    pub template: String,
+
+    /// The value of the [major parameter][https://discord.com/developers/docs/topics/rate-limits#rate-limits] of the route, empty if the route has none.
+    pub major_parameter: String,
+
+    /// Whether requests to the route count towards the global rate limit, interaction callbacks & webhooks dont.
+    pub global: bool,
+}
+
+#[derive(Debug, Default)]
+/// The known state of a rate limit bucket.
+pub struct BucketState {
+
+    /// Whether a response for this bucket has been recieved, until then only one request is sent at a time.
+    pub initialised: bool,
+
+    /// The amount of requests which can be made before the bucket resets.
+    pub limit: Option<u32>,
+
+    /// The amount of requests remaining before the bucket resets, None if the bucket is unlimited.
+    pub remaining: Option<u32>,
+
+    /// When the bucket resets & its remaining requests are refilled.
+    pub reset_at: Option<Instant>,
+
+    /// The length of the last reset window Discord sent, utilised to estimate the next reset until a response arrives.
+    pub reset_after: Option<Duration>,
+}
+
+#[derive(Debug, Default)]
+/// A rate limit bucket which queues the requests made within it.
+pub struct Bucket {
+
+    /// Locked by a request while it waits on the bucket, so requests are let through in the order they arrived.
+    pub queue: Arc<Mutex<()>>,
+
+    /// The state of the bucket, only locked briefly so responses can update it while requests are waiting.
+    pub state: StdMutex<BucketState>,
+}
+
+impl Bucket {
+
+    /// Locks the [`BucketState`], the lock is never held across an await so a poisoned lock can still be utilised.
+    pub fn state(&self) -> MutexGuard<'_, BucketState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[derive(Debug)]
+/// Counts requests made within the current second to keep to the [`GLOBAL_RATE_LIMIT`].
+struct GlobalWindow {
+
+    /// When the current one second window started.
+    window_start: Instant,
+
+    /// The amount of requests made within the current window.
+    count: u32,
+
+    /// Set when Discord responded with a global rate limit, no requests are made until then.
+    blocked_until: Option<Instant>,
+}
+
+#[derive(Clone, Debug, Default)]
+/// The rate limit headers of a response from the Discord api.
+pub struct RateLimitHeaders {
+
+    /// X-RateLimit-Bucket: the unique hash of the bucket the route belongs to
+    pub bucket: Option<String>,
+
+    /// X-RateLimit-Limit: the amount of requests which can be made within the bucket
+    pub limit: Option<u32>,
+
+    /// X-RateLimit-Remaining: the amount of requests remaining within the bucket
+    pub remaining: Option<u32>,
+
+    /// X-RateLimit-Reset-After: how long until the bucket resets
+    pub reset_after: Option<Duration>,
+
+    /// X-RateLimit-Global: whether a 429 was caused by the global rate limit
+    pub global: bool,
+
+    /// Retry-After: how long to wait before retrying after a 429
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitHeaders {
+
+    /// Reads the rate limit headers from the headers of a response.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        let seconds = |name: &str| header(name)
+            .and_then(|value| value.parse::<f64>().ok())
+            .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+            .map(Duration::from_secs_f64);
+
+        Self {
+            bucket: header("x-ratelimit-bucket").map(str::to_string),
+            limit: header("x-ratelimit-limit").and_then(|value| value.parse().ok()),
+            remaining: header("x-ratelimit-remaining").and_then(|value| value.parse().ok()),
+            reset_after: seconds("x-ratelimit-reset-after"),
+            global: header("x-ratelimit-global").is_some(),
+            retry_after: seconds("retry-after"),
+        }
+    }
+}
+
+/// Permission to make a single request, returned by [`RateLimiter::acquire`] & handed back through [`RateLimiter::update`] with the response.
+pub struct RateLimitTicket {
+
+    /// The route the request was made to.
+    route: RateLimitRoute,
+
+    /// The bucket the request was made within.
+    bucket: Arc<Bucket>,
+
+    /// Held while the bucket is uninitialised, so no other request is made until its limits are known.
+    _queue_guard: Option<OwnedMutexGuard<()>>,
+}
+
+/// Tracks rate limit buckets & the global rate limit for every request made through a [`DiscordHttpClient`][crate::http::DiscordHttpClient].
+pub struct RateLimiter {
+
+    /// The bucket hashes which Discord has sent for each route template.
+    route_buckets: StdMutex<HashMap<String, String>>,
+
+    /// Every known bucket keyed by its hash (or route template while unknown) & major parameter.
+    buckets: StdMutex<HashMap<(String, String), Arc<Bucket>>>,
+
+    /// When the buckets were last checked for eviction.
+    evicted_at: StdMutex<Instant>,
+
+    /// Locked by a request while it waits on the global rate limit, so requests are let through in order.
+    global_queue: Mutex<()>,
+
+    /// The window utilised for the global rate limit.
+    global: StdMutex<GlobalWindow>,
+
+    /// The amount of requests allowed per second across every route.
+    pub global_limit: u32,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(GLOBAL_RATE_LIMIT)
+    }
+}
+
+impl RateLimiter {
+
+    /// Creates a new [`RateLimiter`] with no known buckets, large bots may have a global_limit higher than [`GLOBAL_RATE_LIMIT`].
+    pub fn new(global_limit: u32) -> Self {
+        Self {
+            route_buckets: StdMutex::new(HashMap::new()),
+            buckets: StdMutex::new(HashMap::new()),
+            evicted_at: StdMutex::new(Instant::now()),
+            global_queue: Mutex::new(()),
+            global: StdMutex::new(GlobalWindow {
+                window_start: Instant::now(),
+                count: 0,
+                blocked_until: None,
+            }),
+            global_limit,
+        }
+    }
+
+    /// Waits until a request to the route is allowed by both its bucket & the global rate limit.
+    /// Requests to the same bucket are queued in the order they called this.
+    pub async fn acquire(&self, route: &RateLimitRoute) -> RateLimitTicket {
+
+        self.evict_buckets_periodically();
+
+        let bucket = self.bucket(route);
+        let queue_guard = bucket.queue.clone().lock_owned().await;
+
+        // Wait for the bucket to reset if it has no requests remaining.
+        loop {
+            let reset_at = {
+                let mut state = bucket.state();
+                match state.reset_at {
+                    Some(reset_at) if reset_at <= Instant::now() => {
+                        state.remaining = state.limit;
+                        state.reset_at = state.reset_after.map(|reset_after| Instant::now() + reset_after);
+                        break
+                    },
+                    Some(reset_at) if state.remaining == Some(0) => reset_at,
+                    // Without a reset time the bucket is estimated to reset after the last window Discord sent.
+                    None => match (state.remaining, state.reset_after) {
+                        (Some(0), Some(reset_after)) => *state.reset_at.insert(Instant::now() + reset_after),
+                        _ => break,
+                    },
+                    _ => break,
+                }
+            };
+
+            tracing::debug!("Rate limited on {}, waiting {:?}", route.template, reset_at - Instant::now());
+            sleep_until(reset_at).await;
+        }
+
+        if route.global {
+            self.acquire_global().await;
+        }
+
+        // Once the limits of the bucket are known the request takes one of the remaining requests & lets the next request through.
+        // Until then the bucket is held so only one request is made before its limits are known.
+        // An exhausted bucket without a known reset cant be waited on either, so its held the same way until a response refills it.
+        let queue_guard = {
+            let mut state = bucket.state();
+            match (state.initialised, state.remaining) {
+                (true, Some(0)) => Some(queue_guard),
+                (true, Some(remaining)) => {
+                    state.remaining = Some(remaining.saturating_sub(1));
+                    None
+                },
+                (true, None) => None,
+                (false, _) => Some(queue_guard),
+            }
+        };
+
+        RateLimitTicket {
+            route: route.clone(),
+            bucket,
+            _queue_guard: queue_guard,
+        }
+    }
+
+    /// Updates the bucket of a request from the rate limit headers of its response.
+    pub fn update(&self, ticket: RateLimitTicket, status: StatusCode, headers: &HeaderMap) {
+
+        let rate_limit_headers = RateLimitHeaders::from_headers(headers);
+
+        // A global rate limit blocks every request until it resets.
+        if status == StatusCode::TOO_MANY_REQUESTS && rate_limit_headers.global {
+            if let Some(retry_after) = rate_limit_headers.retry_after {
+                self.block_global(retry_after);
+            }
+        }
+
+        // Share the bucket with every other route which Discord sends the same bucket hash for.
+        let bucket = match &rate_limit_headers.bucket {
+            Some(bucket_hash) => self.assign_bucket(&ticket.route, bucket_hash, &ticket.bucket),
+            None => ticket.bucket.clone(),
+        };
+
+        let mut state = bucket.state();
+
+        state.initialised = true;
+        state.limit = rate_limit_headers.limit;
+        state.remaining = rate_limit_headers.remaining;
+        state.reset_at = rate_limit_headers.reset_after.map(|reset_after| Instant::now() + reset_after);
+        state.reset_after = rate_limit_headers.reset_after;
+
+        // A 429 without headers still means the bucket is exhausted until retry_after has passed.
+        if status == StatusCode::TOO_MANY_REQUESTS && !rate_limit_headers.global {
+            if let Some(retry_after) = rate_limit_headers.retry_after {
+                state.remaining = Some(0);
+                state.reset_at = Some(Instant::now() + retry_after);
+            }
+        }
+
+        // The queue guard is dropped with the ticket, letting the next request through.
+    }
+
+    /// Marks the bucket of a route as exhausted until retry_after has passed, or every route if the rate limit was global.
+    pub fn rate_limited(&self, route: &RateLimitRoute, retry_after: Duration, global: bool) {
+
+        if global {
+            self.block_global(retry_after);
+            return
+        }
+
+        let bucket = self.bucket(route);
+        let mut state = bucket.state();
+        state.remaining = Some(0);
+        state.reset_at = Some(Instant::now() + retry_after);
+    }
+
+    /// Stops every request from being made until retry_after has passed.
+    pub fn block_global(&self, retry_after: Duration) {
+        tracing::warn!("Globally rate limited, waiting {:?}", retry_after);
+        self.global_window().blocked_until = Some(Instant::now() + retry_after);
+    }
+
+    /// Waits until a request is allowed by the global rate limit.
+    async fn acquire_global(&self) {
+
+        // The queue is held while waiting so requests are let through in order.
+        let _queue_guard = self.global_queue.lock().await;
+
+        loop {
+            let wait_until = {
+                let mut global = self.global_window();
+                let now = Instant::now();
+
+                if global.window_start + Duration::from_secs(1) <= now {
+                    global.window_start = now;
+                    global.count = 0;
+                }
+
+                match global.blocked_until {
+                    Some(blocked_until) if blocked_until > now => blocked_until,
+                    _ if global.count >= self.global_limit => global.window_start + Duration::from_secs(1),
+                    _ => {
+                        global.count += 1;
+                        break
+                    },
+                }
+            };
+
+            sleep_until(wait_until).await;
+        }
+    }
+
+    /// Locks the [`GlobalWindow`], the lock is never held across an await so a poisoned lock can still be utilised.
+    fn global_window(&self) -> MutexGuard<'_, GlobalWindow> {
+        self.global.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Retrieves the bucket for a route, creating it if it isnt known yet.
+    fn bucket(&self, route: &RateLimitRoute) -> Arc<Bucket> {
+
+        let bucket_hash = self.route_buckets.lock().unwrap_or_else(PoisonError::into_inner)
+            .get(&route.template)
+            .cloned()
+            .unwrap_or_else(|| route.template.clone());
+
+        self.buckets.lock().unwrap_or_else(PoisonError::into_inner)
+            .entry((bucket_hash, route.major_parameter.clone()))
+            .or_default()
+            .clone()
+    }
+
+    /// Records the bucket hash of a route, keeping the existing bucket if another route already shares the hash.
+    /// Returns the bucket the route is looked up within from now on.
+    fn assign_bucket(&self, route: &RateLimitRoute, bucket_hash: &str, bucket: &Arc<Bucket>) -> Arc<Bucket> {
+
+        self.route_buckets.lock().unwrap_or_else(PoisonError::into_inner)
+            .insert(route.template.clone(), bucket_hash.to_string());
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        let assigned_bucket = buckets.entry((bucket_hash.to_string(), route.major_parameter.clone()))
+            .or_insert_with(|| bucket.clone())
+            .clone();
+
+        // The route is looked up by its hash from now on, so the bucket it was created within while the hash was unknown is no longer reachable.
+        let template_key = (route.template.clone(), route.major_parameter.clone());
+        if bucket_hash != route.template && buckets.get(&template_key).is_some_and(|template_bucket| Arc::ptr_eq(template_bucket, bucket)) {
+            buckets.remove(&template_key);
+        }
+
+        assigned_bucket
+    }
+
+    /// Evicts the buckets once every [`BUCKET_EVICTION_INTERVAL`]
+    fn evict_buckets_periodically(&self) {
+
+        let mut evicted_at = self.evicted_at.lock().unwrap_or_else(PoisonError::into_inner);
+        if evicted_at.elapsed() < BUCKET_EVICTION_INTERVAL {
+            return
+        }
+
+        *evicted_at = Instant::now();
+        drop(evicted_at);
+        self.evict_buckets();
+    }
+
+    /// Removes every bucket which has reset & isnt utilised by any request, alongside the bucket hashes of routes which no longer have a bucket.
+    /// Evicted buckets are created again once requested, only one request is made within them until their limits are known again.
+    fn evict_buckets(&self) {
+
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Requests waiting on or holding a ticket for a bucket hold a reference to it, which can only be taken while the buckets are locked.
+        buckets.retain(|_, bucket| Arc::strong_count(bucket) > 1 || bucket.state().reset_at.is_some_and(|reset_at| reset_at > now));
+
+        let bucket_hashes: HashSet<&str> = buckets.keys().map(|(bucket_hash, _)| bucket_hash.as_str()).collect();
+        self.route_buckets.lock().unwrap_or_else(PoisonError::into_inner)
+            .retain(|_, bucket_hash| bucket_hashes.contains(bucket_hash.as_str()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+    use reqwest::{Method, StatusCode};
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+    use tokio::time::{Duration, Instant, timeout};
+
+    use crate::http::{DiscordHttpClient, DiscordHttpReqType, DiscordHttpRequest};
+    use crate::snowflake::Id;
+    use super::{RateLimitRoute, RateLimiter};
+
+    /// A response of the mock server, as its status, headers & body.
+    type MockResponse = (u16, Vec<(&'static str, &'static str)>, &'static str);
+
+    /// Starts a server responding to each request with the response for its path & the amount of earlier requests to the path.
+    /// Returns a client making requests to it.
+    async fn mock_client(respond: fn(&str, usize) -> MockResponse) -> DiscordHttpClient {
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let requests = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let requests = requests.clone();
+                tokio::spawn(async move {
+                    let mut stream = BufReader::new(stream);
+                    loop {
+                        let mut request_line = String::new();
+                        if stream.read_line(&mut request_line).await.unwrap_or(0) == 0 {
+                            return
+                        }
+
+                        let mut content_length = 0;
+                        loop {
+                            let mut header = String::new();
+                            stream.read_line(&mut header).await.unwrap();
+                            if header.trim().is_empty() {
+                                break
+                            }
+                            if let Some(length) = header.to_ascii_lowercase().strip_prefix("content-length:") {
+                                content_length = length.trim().parse().unwrap();
+                            }
+                        }
+                        stream.read_exact(&mut vec![0; content_length]).await.unwrap();
+
+                        let path = request_line.split(' ').nth(1).unwrap().trim_start_matches("/v10/").to_string();
+                        let earlier = {
+                            let mut requests = requests.lock().unwrap();
+                            requests.push(path.clone());
+                            requests.iter().filter(|requested| **requested == path).count() - 1
+                        };
+
+                        let (status, headers, body) = respond(&path, earlier);
+                        let mut response = format!("HTTP/1.1 {status} Mock\r\ncontent-length: {}\r\ncontent-type: application/json\r\n", body.len());
+                        for (name, value) in headers {
+                            response.push_str(&format!("{name}: {value}\r\n"));
+                        }
+                        response.push_str("\r\n");
+                        response.push_str(body);
+                        stream.get_mut().write_all(response.as_bytes()).await.unwrap();
+                    }
+                });
+            }
+        });
+
+        DiscordHttpClient::new(&format!("http://{address}"), 10, Arc::from("token")).unwrap()
+    }
+
+    fn get_channel(channel_id: u64) -> DiscordHttpRequest {
+        DiscordHttpRequest::new(DiscordHttpReqType::Channel { channel_id: Id::new(channel_id) }, Method::GET)
+    }
+
+    fn headers(values: &[(&'static str, &'static str)]) -> HeaderMap {
+        values.iter().map(|(name, value)| (HeaderName::from_static(name), HeaderValue::from_static(value))).collect()
+    }
+
+    fn route(template: &str, major_parameter: &str) -> RateLimitRoute {
+        RateLimitRoute { template: template.to_string(), major_parameter: major_parameter.to_string(), global: true }
+    }
+
+    #[tokio::test]
+    async fn exhausted_buckets_wait_for_their_reset() {
+        let rate_limiter = RateLimiter::default();
+        let channel = route("GET channels/{channel_id}", "1");
+
+        let ticket = rate_limiter.acquire(&channel).await;
+        rate_limiter.update(ticket, StatusCode::OK, &headers(&[("x-ratelimit-limit", "1"), ("x-ratelimit-remaining", "0"), ("x-ratelimit-reset-after", "0.2")]));
+        let start = Instant::now();
+
+        // The major parameter splits the bucket, so another channel isnt limited.
+        drop(timeout(Duration::from_millis(100), rate_limiter.acquire(&route("GET channels/{channel_id}", "2"))).await.unwrap());
+
+        drop(rate_limiter.acquire(&channel).await);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn routes_sharing_a_bucket_hash_share_its_limit() {
+        let rate_limiter = RateLimiter::default();
+        let (channel, messages) = (route("GET channels/{channel_id}", "1"), route("GET channels/{channel_id}/messages", "1"));
+        let exhausted = headers(&[("x-ratelimit-bucket", "shared"), ("x-ratelimit-limit", "1"), ("x-ratelimit-remaining", "0"), ("x-ratelimit-reset-after", "10")]);
+
+        let ticket = rate_limiter.acquire(&channel).await;
+        rate_limiter.update(ticket, StatusCode::OK, &exhausted);
+
+        // Another route isnt known to share the bucket until its first response.
+        let ticket = timeout(Duration::from_millis(100), rate_limiter.acquire(&messages)).await.unwrap();
+        rate_limiter.update(ticket, StatusCode::OK, &exhausted);
+
+        assert!(timeout(Duration::from_millis(100), rate_limiter.acquire(&messages)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn global_rate_limit_blocks_every_route() {
+        let rate_limiter = RateLimiter::default();
+
+        let ticket = rate_limiter.acquire(&route("GET channels/{channel_id}", "1")).await;
+        rate_limiter.update(ticket, StatusCode::TOO_MANY_REQUESTS, &headers(&[("x-ratelimit-global", "true"), ("retry-after", "1")]));
+        let start = Instant::now();
+
+        drop(rate_limiter.acquire(&route("GET guilds/{guild_id}", "10")).await);
+        assert!(start.elapsed() >= Duration::from_millis(900));
+    }
+
+    #[tokio::test]
+    async fn responses_update_the_bucket_shared_by_their_hash() {
+        let rate_limiter = RateLimiter::default();
+        let (channel, messages) = (route("GET channels/{channel_id}", "1"), route("GET channels/{channel_id}/messages", "1"));
+
+        let ticket = rate_limiter.acquire(&channel).await;
+        rate_limiter.update(ticket, StatusCode::OK, &headers(&[("x-ratelimit-bucket", "shared"), ("x-ratelimit-remaining", "5"), ("x-ratelimit-reset-after", "10")]));
+
+        // The second route was created within its own bucket, its response exhausts the bucket it shares with the first route.
+        let ticket = rate_limiter.acquire(&messages).await;
+        rate_limiter.update(ticket, StatusCode::OK, &headers(&[("x-ratelimit-bucket", "shared"), ("x-ratelimit-remaining", "0"), ("x-ratelimit-reset-after", "10")]));
+
+        assert!(timeout(Duration::from_millis(100), rate_limiter.acquire(&channel)).await.is_err());
+        assert!(timeout(Duration::from_millis(100), rate_limiter.acquire(&messages)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn exhausted_buckets_without_a_reset_make_one_request_at_a_time() {
+        let rate_limiter = RateLimiter::default();
+        let channel = route("GET channels/{channel_id}", "1");
+
+        let ticket = rate_limiter.acquire(&channel).await;
+        rate_limiter.update(ticket, StatusCode::OK, &headers(&[("x-ratelimit-remaining", "0")]));
+
+        let ticket = timeout(Duration::from_millis(100), rate_limiter.acquire(&channel)).await.unwrap();
+        assert!(timeout(Duration::from_millis(100), rate_limiter.acquire(&channel)).await.is_err());
+
+        rate_limiter.update(ticket, StatusCode::OK, &headers(&[("x-ratelimit-remaining", "2")]));
+        timeout(Duration::from_millis(100), rate_limiter.acquire(&channel)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn exhausted_buckets_without_a_reset_wait_for_the_last_reset_window() {
+        let rate_limiter = RateLimiter::default();
+        let channel = route("GET channels/{channel_id}", "1");
+
+        let ticket = rate_limiter.acquire(&channel).await;
+        rate_limiter.update(ticket, StatusCode::OK, &headers(&[("x-ratelimit-limit", "1"), ("x-ratelimit-remaining", "0")]));
+        rate_limiter.bucket(&channel).state().reset_after = Some(Duration::from_millis(200));
+        let start = Instant::now();
+
+        drop(rate_limiter.acquire(&channel).await);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn rate_limited_requests_are_retried_after_retry_after() {
+        let client = mock_client(|_, earlier| match earlier {
+            0 => (429, vec![("retry-after", "1")], r#"{"message": "You are being rate limited.", "retry_after": 0.2, "global": false}"#),
+            _ => (200, Vec::new(), "{}"),
+        }).await;
+        let start = Instant::now();
+
+        client.execute(get_channel(1)).await.unwrap();
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(200) && elapsed < Duration::from_millis(900), "retried after {elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn global_limit_skips_interaction_routes() {
+        let mut client = mock_client(|_, _| (200, Vec::new(), "{}")).await;
+        client.rate_limiter = Arc::new(RateLimiter::new(2));
+        let start = Instant::now();
+
+        for channel_id in 1..=2 {
+            client.execute(get_channel(channel_id)).await.unwrap();
+        }
+
+        // The global limit is exhausted for this second, but interaction callbacks arent counted towards it.
+        for interaction_id in 1..=3 {
+            let callback = DiscordHttpReqType::InteractionCallback { interaction_id: Id::new(interaction_id), token: "token".to_string() };
+            client.execute(DiscordHttpRequest::new(callback, Method::POST)).await.unwrap();
+        }
+        assert!(start.elapsed() < Duration::from_millis(500));
+
+        client.execute(get_channel(3)).await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(900));
+    }
+
+    #[tokio::test]
+    async fn buckets_are_evicted_once_reset_and_unused() {
+        let rate_limiter = RateLimiter::default();
+        let hashed = headers(&[("x-ratelimit-bucket", "hash"), ("x-ratelimit-remaining", "1"), ("x-ratelimit-reset-after", "0.05")]);
+
+        let ticket = rate_limiter.acquire(&route("GET channels/{channel_id}", "1")).await;
+        rate_limiter.update(ticket, StatusCode::OK, &hashed);
+        let held_ticket = rate_limiter.acquire(&route("GET channels/{channel_id}", "2")).await;
+        assert_eq!(rate_limiter.buckets.lock().unwrap().len(), 2);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        rate_limiter.evict_buckets();
+
+        // The bucket which is still utilised by a request is kept, alongside the hash of its route.
+        let buckets = rate_limiter.buckets.lock().unwrap();
+        assert_eq!(buckets.keys().collect::<Vec<_>>(), [&("hash".to_string(), "2".to_string())]);
+        assert!(rate_limiter.route_buckets.lock().unwrap().contains_key("GET channels/{channel_id}"));
+        drop((buckets, held_ticket));
+    }
+}
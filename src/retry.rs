@@ -0,0 +1,175 @@
+//! The [`RetryPolicy`] which decides when a failed request to the Discord api is sent again.
+use reqwest::{Method, StatusCode};
+use serde::Deserialize;
+use tokio::time::Duration;
+
+#[derive(Clone, Debug)]
+/// Configures how a [`DiscordHttpClient`][crate::http::DiscordHttpClient] retries requests which failed.
+/// Rate limited requests are always safe to retry as Discord did not process them,
+/// but server & connection errors are only retried for idempotent methods unless retry_non_idempotent is set.
+pub struct RetryPolicy {
+
+    /// The maximum amount of times a request is sent, including the first attempt.
+    pub max_attempts: u32,
+
+    /// The delay before the first retry of a server or connection error, doubled for each retry after.
+    pub base_delay: Duration,
+
+    /// The longest delay between retries of a server or connection error.
+    pub max_delay: Duration,
+
+    /// Whether requests which recieved a 429 are retried after the rate limit resets.
+    pub retry_rate_limited: bool,
+
+    /// Whether requests which recieved a 502, 503 or 504 are retried.
+    pub retry_server_errors: bool,
+
+    /// Whether requests which failed to connect or timed out are retried.
+    pub retry_connection_errors: bool,
+
+    /// Whether server & connection errors are retried for methods which arent idempotent, such as POST & PATCH.
+    /// These may have been processed by Discord already, so retrying them could create duplicates.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            retry_rate_limited: true,
+            retry_server_errors: true,
+            retry_connection_errors: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+/// [The body of a 429 response][https://discord.com/developers/docs/topics/rate-limits#exceeding-a-rate-limit] sent when a rate limit was exceeded.
+pub struct RateLimitedBody {
+
+    /// The amount of seconds to wait before another request can be made
+    pub retry_after: f64,
+
+    #[serde(default)]
+    /// Whether the global rate limit was exceeded rather than the limit of a route
+    pub global: bool,
+}
+
+impl RetryPolicy {
+
+    /// A [`RetryPolicy`] which never retries a request.
+    pub fn never() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Whether another attempt is allowed after the amount of attempts made so far.
+    pub fn has_attempts_remaining(&self, attempts: u32) -> bool {
+        attempts < self.max_attempts
+    }
+
+    /// Whether a request with the method may be retried after a server or connection error.
+    pub fn allows_method(&self, method: &Method) -> bool {
+        self.retry_non_idempotent || is_idempotent(method)
+    }
+
+    /// Whether a response with this status should be retried for the method.
+    pub fn should_retry_status(&self, status: StatusCode, method: &Method) -> bool {
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => self.retry_rate_limited,
+            StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => self.retry_server_errors && self.allows_method(method),
+            _ => false,
+        }
+    }
+
+    /// Whether a request which failed to be sent should be retried for the method.
+    pub fn should_retry_error(&self, error: &reqwest::Error, method: &Method) -> bool {
+        self.retry_connection_errors
+            && (error.is_connect() || error.is_timeout())
+            && self.allows_method(method)
+    }
+
+    /// Exponential backoff with jitter for the retry after the amount of attempts made so far.
+    /// Half of the delay is fixed and the other half is random, so clients retrying together spread out.
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(16);
+        let delay = self.base_delay.saturating_mul(1 << exponent).min(self.max_delay);
+        delay / 2 + delay.mul_f64(rand::random::<f64>() / 2.0)
+    }
+}
+
+/// Whether sending a request with the method more than once has the same effect as sending it once.
+pub fn is_idempotent(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS)
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+    use tokio::time::Duration;
+
+    use super::{RetryPolicy, is_idempotent};
+
+    #[test]
+    fn backoff_doubles_with_jitter_up_to_the_max_delay() {
+        let policy = RetryPolicy { base_delay: Duration::from_millis(100), max_delay: Duration::from_secs(1), ..RetryPolicy::default() };
+
+        for (attempts, delay) in [(1, 100), (2, 200), (3, 400), (4, 800), (5, 1000), (40, 1000)] {
+            let delay = Duration::from_millis(delay);
+            for _ in 0..20 {
+                let backoff = policy.backoff(attempts);
+                assert!(backoff >= delay / 2 && backoff <= delay, "attempt {attempts} backed off {backoff:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn only_rate_limits_and_server_errors_are_retried() {
+        let policy = RetryPolicy::default();
+
+        for status in [StatusCode::TOO_MANY_REQUESTS, StatusCode::BAD_GATEWAY, StatusCode::SERVICE_UNAVAILABLE, StatusCode::GATEWAY_TIMEOUT] {
+            assert!(policy.should_retry_status(status, &Method::GET), "{status} wasnt retried");
+        }
+        for status in [StatusCode::BAD_REQUEST, StatusCode::UNAUTHORIZED, StatusCode::FORBIDDEN, StatusCode::NOT_FOUND, StatusCode::INTERNAL_SERVER_ERROR, StatusCode::OK] {
+            assert!(!policy.should_retry_status(status, &Method::GET), "{status} was retried");
+        }
+
+        let policy = RetryPolicy { retry_rate_limited: false, retry_server_errors: false, ..RetryPolicy::default() };
+        assert!(!policy.should_retry_status(StatusCode::TOO_MANY_REQUESTS, &Method::GET));
+        assert!(!policy.should_retry_status(StatusCode::BAD_GATEWAY, &Method::GET));
+    }
+
+    #[test]
+    fn server_errors_are_only_retried_for_idempotent_methods_unless_overridden() {
+        assert!([Method::GET, Method::HEAD, Method::PUT, Method::DELETE, Method::OPTIONS].iter().all(is_idempotent));
+        assert!(![Method::POST, Method::PATCH].iter().any(is_idempotent));
+
+        let policy = RetryPolicy::default();
+        assert!(!policy.should_retry_status(StatusCode::BAD_GATEWAY, &Method::POST));
+        assert!(!policy.should_retry_status(StatusCode::SERVICE_UNAVAILABLE, &Method::PATCH));
+
+        // Rate limited requests werent processed by Discord, so they are retried regardless of the method.
+        assert!(policy.should_retry_status(StatusCode::TOO_MANY_REQUESTS, &Method::POST));
+
+        let policy = RetryPolicy { retry_non_idempotent: true, ..RetryPolicy::default() };
+        assert!(policy.should_retry_status(StatusCode::BAD_GATEWAY, &Method::POST));
+        assert!(policy.should_retry_status(StatusCode::GATEWAY_TIMEOUT, &Method::PATCH));
+    }
+
+    #[test]
+    fn attempts_are_limited_by_max_attempts() {
+        let policy = RetryPolicy::default();
+        assert!(policy.has_attempts_remaining(1));
+        assert!(policy.has_attempts_remaining(2));
+        assert!(!policy.has_attempts_remaining(3));
+
+        assert!(!RetryPolicy::never().has_attempts_remaining(1));
+    }
+}
@@ -0,0 +1,630 @@
+//! The lifecycle of a single shards connection to Discords gateway, including resuming & reconnecting.
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+use futures_util::future::{FutureExt, Shared};
+use futures_util::{SinkExt, StreamExt};
+use reqwest::Url;
+use serde_json::Value;
+use tokio::sync::mpsc::{Receiver as GatewaySinkReceiver, Sender as MpscSender};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::oneshot::{self, Receiver as OneshotReceiver, Sender as OneshotSender};
+use tokio::time::*;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+use crate::DISCORD_API_VERSION;
+use crate::error::{Error, Result};
+use crate::event_handler::{Context, EventHandlers, dispatch_event};
+use crate::gateway::*;
+use crate::gateway_structs::*;
+use crate::http::DiscordHttpClientReqSender;
+use crate::websocket::*;
+use crate::cache::CacheBackend;
+
+/// The longest amount of time (in seconds) a shard will wait between failed connection attempts.
+const MAX_BACKOFF_SECONDS: u64 = 60;
+
+/// The amount of failed connection attempts after which a shard gives up resuming & identifies instead.
+const MAX_RESUME_ATTEMPTS: u32 = 3;
+
+/// The amount of events which can wait for the cache to be updated, further events arent applied to the cache until it catches up.
+const CACHE_UPDATE_QUEUE_SIZE: usize = 1024;
+
+/// Shortened Alias for the Mpsc channel sender which shards report errors they can not recover from through.
+pub type ShardErrorSender = MpscSender<Error>;
+
+/// Resolves once the cache has been updated with a queued event, whether or not the update succeeded.
+type CacheUpdated = Shared<OneshotReceiver<()>>;
+
+/// Updates the cache with the events of a single shard within its own task, in the order they were recieved.
+/// This keeps a slow cache, such as one stored within another server, from blocking the shard from recieving events & heartbeating.
+struct CacheUpdater {
+    shard_id: u32,
+    sender: MpscSender<(DispatchEvent, OneshotSender<()>)>,
+}
+
+impl CacheUpdater {
+
+    /// Spawns the task updating the cache, which stops once the updater is dropped & every queued event is applied.
+    fn spawn(shard_id: u32, cache: Arc<dyn CacheBackend>, queue_size: usize) -> Self {
+        let (sender, mut reciever) = mpsc::channel::<(DispatchEvent, OneshotSender<()>)>(queue_size);
+
+        tokio::spawn(async move {
+            while let Some((event, updated)) = reciever.recv().await {
+                if let Err(error) = cache.update(&event).await {
+                    tracing::warn!("Shard {shard_id} failed to update the cache with {}: {error}", event.event_name());
+                }
+                let _ = updated.send(());
+            }
+        });
+
+        Self { shard_id, sender }
+    }
+
+    /// Queues an event to be applied to the cache, returning a future which resolves once it has been.
+    /// The shard never waits on the cache, if the queue is full the event isnt applied & the future resolves immediately.
+    fn queue(&self, event: DispatchEvent) -> CacheUpdated {
+        let (updated_sender, updated) = oneshot::channel();
+
+        // The task only stops once the updater is dropped, if it panicked the handlers are called without waiting.
+        if let Err(TrySendError::Full((event, _))) = self.sender.try_send((event, updated_sender)) {
+            tracing::warn!("Shard {} cache is too far behind, {} was not applied to it", self.shard_id, event.event_name());
+        }
+        updated.shared()
+    }
+}
+
+#[derive(Clone)]
+/// Handles shared between every shard which a [`ShardRunner`] utilises outside of its own connection.
+pub struct ShardHandles {
+
+    /// The handlers which are called for every dispatched event.
+    pub event_handlers: EventHandlers,
+
+    /// The sender utilised by handlers for making requests to the Discord api.
+    pub http_sender: DiscordHttpClientReqSender,
+
+    /// The sender utilised for reporting fatal errors which stop a shard.
+    pub shard_error_sender: ShardErrorSender,
+
+    /// The cache updated with every dispatched event, if enabled.
+    pub cache: Option<Arc<dyn CacheBackend>>,
+}
+
+#[derive(Clone, Debug)]
+/// Information about an established session which allows it to be resumed after a disconnect.
+pub struct ShardSession {
+
+    /// The id of the session, recieved in the Ready event
+    pub session_id: String,
+
+    /// The gateway url which must be utilised when resuming this session
+    pub resume_gateway_url: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// What a shard should do once its connection to the gateway has ended.
+pub enum ConnectionEnd {
+
+    /// Reconnect & send a Resume payload so missed events are replayed.
+    Resume,
+
+    /// Reconnect & send a new Identify payload as the session can no longer be resumed.
+    Reidentify,
+
+    /// The connection was closed with a close code which the shard can not recover from, so it must stop.
+    Fatal(GatewayCloseCode),
+}
+
+/// Keeps a single shard connected to Discords gateway.
+/// Resumes the [`ShardSession`] whenever possible, and falls back to identifying with a new session with backoff between failed attempts.
+pub struct ShardRunner {
+
+    /// The [`Gateway`] of this shard which is shared with the [`ShardMap`] & event handlers.
+    pub gateway: Gateway,
+
+    /// The url utilised for connecting when a new session is identified.
+    pub gateway_url: Url,
+
+    /// The Identify payload sent whenever a new session is started.
+    pub identify_payload: Payload<Identify>,
+
+    /// The current session, if one has been established & can be resumed.
+    pub session: Option<ShardSession>,
+
+    /// The handlers & senders shared with every other shard.
+    pub handles: ShardHandles,
+
+    /// The amount of connection attempts since a session was last established successfully.
+    pub failed_attempts: u32,
+
+    /// Applies the events of this shard to the cache, if enabled, kept across reconnects so events are applied in order.
+    cache_updater: Option<CacheUpdater>,
+}
+
+impl ShardRunner {
+
+    /// Creates a new [`ShardRunner`] without an existing session.
+    pub fn new(gateway: Gateway, gateway_url: Url, identify_payload: Payload<Identify>, handles: ShardHandles) -> Self {
+        let cache_updater = handles.cache.clone().map(|cache| CacheUpdater::spawn(gateway.connection_id.shard_id, cache, CACHE_UPDATE_QUEUE_SIZE));
+        Self {
+            gateway,
+            gateway_url,
+            identify_payload,
+            session: None,
+            cache_updater,
+            handles,
+            failed_attempts: 0,
+        }
+    }
+
+    /// Keeps the shard connected until the connection is closed with a fatal [`GatewayCloseCode`], which is sent through the shard_error_sender.
+    /// first_identify is notified once the first handshake has been sent, so shards can be started in order.
+    pub async fn run(mut self, mut sink_channel_reciever: GatewaySinkReceiver<GatewayCommand>, first_identify: OneshotSender<()>) {
+
+        let shard_id = self.gateway.connection_id.shard_id;
+        let mut first_identify = Some(first_identify);
+
+        loop {
+
+            // Wait before reconnecting if the previous attempts did not manage to establish a session
+            if self.failed_attempts > 0 {
+                let backoff = self.backoff_duration();
+                tracing::info!("Shard {shard_id} reconnecting in {:?}", backoff);
+                sleep(backoff).await;
+            }
+
+            self.begin_attempt();
+
+            let connection_end = match self.connect_and_run(&mut sink_channel_reciever, &mut first_identify).await {
+                Ok(connection_end) => connection_end,
+                Err(error) => {
+                    tracing::warn!("Shard {shard_id} failed to connect to the Discord Gateway: {error}");
+                    ConnectionEnd::Resume
+                },
+            };
+
+            match connection_end {
+                ConnectionEnd::Resume => {},
+                ConnectionEnd::Reidentify => {
+
+                    // Discord requires a random wait between 1 & 5 seconds before identifying after an invalid session.
+                    self.reset_session();
+                    sleep(Duration::from_millis(rand::random::<u64>() % 4000 + 1000)).await;
+                },
+                ConnectionEnd::Fatal(close_code) => {
+                    tracing::error!("Shard {shard_id} is stopping: {close_code}");
+
+                    // Errors are ignored as the reciever being dropped means nobody is waiting on the bot anymore.
+                    let _ = self.handles.shard_error_sender.send(Error::GatewayClosed { shard_id, close_code }).await;
+                    return
+                },
+            }
+        }
+    }
+
+    /// Connects to the gateway, sends the Identify or Resume handshake & processes the connection until it ends.
+    async fn connect_and_run(&mut self, sink_channel_reciever: &mut GatewaySinkReceiver<GatewayCommand>, first_identify: &mut Option<OneshotSender<()>>) -> Result<ConnectionEnd> {
+
+        let gateway_url = self.connection_url()?;
+
+        tracing::debug!("Connecting shard {} to {gateway_url}", self.gateway.connection_id.shard_id);
+
+        let (websocket_stream, _response) = tokio_tungstenite::connect_async(&gateway_url).await?;
+
+        // Split the stream up into a sink and a stream for channels.
+        let (mut write_sink, mut read_stream) = websocket_stream.split();
+
+        // Get the Hello payload send from discord.
+        let hello_payload = read_stream.read_deserialize_next_payload::<Payload<Hello>>().await?;
+
+        self.gateway.connection_id.heartbeat_interval.store(hello_payload.data.heartbeat_interval, Ordering::Release);
+
+        // Heartbeats sent through the previous connection will never be acknowledged.
+        self.gateway.heartbeat_tracker().awaiting_ack = false;
+
+        // Commands queued while disconnected belong to the previous connection so they are discarded,
+        // this ensures the handshake is the first payload sent through the new connection.
+        while sink_channel_reciever.try_recv().is_ok() {}
+
+        self.gateway.gateway_sink_sender.send(self.handshake()).await
+            .map_err(|_| Error::ChannelClosed("Gateway sink"))?;
+
+        if let Some(first_identify) = first_identify.take() {
+            let _ = first_identify.send(());
+        }
+
+        let heartbeat_gateway = self.gateway.clone();
+
+        // Run the connection until any part of it stops, the recieving half decides how the shard should reconnect.
+        let connection_end = tokio::select! {
+            connection_end = self.recieve_gateway_events(&mut read_stream) => connection_end,
+            _ = process_gateway_send_commands(sink_channel_reciever, &mut write_sink) => ConnectionEnd::Resume,
+            _ = heartbeat_gateway.heartbeat() => ConnectionEnd::Resume,
+        };
+
+        // Close the connection with a non 1000 close code so the session stays resumable.
+        // This fails if the connection was already dropped which is acceptable behaviour.
+        let close_frame = CloseFrame {
+            code: CloseCode::Library(4000),
+            reason: "Reconnecting".into(),
+        };
+        let _ = timeout(Duration::from_secs(1), write_sink.send(Message::Close(Some(close_frame)))).await;
+
+        Ok(connection_end)
+    }
+
+    /// Recieve payloads from Discords gateway through this shards read stream until the connection should end.
+    /// Each payload is decoded into a [`GatewayEvent`] and published through the gateway_stream_sender.
+    /// Dispatched events are also passed to every [`EventHandler`] within the shared event_handlers.
+    async fn recieve_gateway_events(&mut self, read_stream: &mut ReadSplitStream) -> ConnectionEnd {
+
+        let shard_id = self.gateway.connection_id.shard_id;
+
+        // Recieve the next message until the stream is closed
+        while let Some(message) = read_stream.next().await {
+
+            let message = match message {
+                Ok(message) => message,
+                Err(error) => {
+                    tracing::warn!("Shard {shard_id} failed to read from the Discord Gateway: {error}");
+                    break
+                },
+            };
+
+            // Payloads are only sent as text as compression is not supported
+            let text_payload = match message {
+                Message::Text(text_payload) => text_payload,
+                Message::Close(close_frame) => return Self::classify_close(shard_id, close_frame),
+                _ => continue,
+            };
+
+            // Deserialize the payload in a blocking task as large payloads such as GuildCreate can take a while.
+            let payload = tokio::task::spawn_blocking(move || serde_json::from_str::<Payload<Value>>(&text_payload)).await;
+            let payload = match payload {
+                Ok(Ok(payload)) => payload,
+                Ok(Err(error)) => {
+                    tracing::warn!("Shard {shard_id} recieved a payload which could not be deserialized: {error}");
+                    continue
+                },
+                Err(_) => continue,
+            };
+
+            // Store the sequence number so it can be utilised for heartbeating & resuming
+            if let Some(sequence_number) = payload.sequence_number {
+                self.gateway.connection_id.sequence_identifier.store(sequence_number, Ordering::Release);
+            }
+
+            let event = GatewayEvent::from_payload(payload);
+
+            let connection_end = match &event {
+                GatewayEvent::Dispatch(dispatch) => {
+                    self.update_session(dispatch);
+
+                    // The cache is updated within its own task, each handler waits on the update of this event so they see the state after it.
+                    let cache_updated = self.cache_updater.as_ref().map(|cache_updater| cache_updater.queue(dispatch.as_ref().clone()));
+
+                    // Interactions must be responded to within 3 seconds, so a warning is logged if the handlers are about to miss it.
+                    if let DispatchEvent::InteractionCreate(interaction) = dispatch.as_ref() {
+                        interaction.acknowledgement.watch(interaction.id);
+                    }
+
+                    // Call each event handler within its own task so slow handlers dont block recieving events.
+                    for handler in self.handles.event_handlers.iter() {
+                        let context = Context {
+                            gateway: Some(self.gateway.clone()),
+                            http: self.handles.http_sender.clone(),
+                            cache: self.handles.cache.clone(),
+                        };
+                        let (handler, event, cache_updated) = (handler.clone(), dispatch.as_ref().clone(), cache_updated.clone());
+                        tokio::spawn(async move {
+                            if let Some(cache_updated) = cache_updated {
+                                let _ = cache_updated.await;
+                            }
+                            dispatch_event(handler, context, event).await
+                        });
+                    }
+                    None
+                },
+                GatewayEvent::Heartbeat => {
+                    // Discord requested a heartbeat so one is sent immediately.
+                    self.gateway.send_heartbeat().await;
+                    None
+                },
+                GatewayEvent::HeartbeatAck => {
+                    self.gateway.heartbeat_tracker().record_ack();
+                    None
+                },
+                event => Self::requested_connection_end(event),
+            };
+
+            // Send the event to all subscribed recievers.
+            // Errors are ignored as there being no recievers subscribed is acceptable behaviour.
+            let _ = self.gateway.gateway_stream_sender.send(event);
+
+            if let Some(connection_end) = connection_end {
+                tracing::info!("Shard {shard_id} is reconnecting, will {connection_end:?}");
+                return connection_end
+            }
+        }
+
+        // The connection was dropped, attempt to resume it.
+        ConnectionEnd::Resume
+    }
+
+    /// Counts a new connection attempt, giving up on resuming a session which keeps failing so a new one is identified instead.
+    fn begin_attempt(&mut self) {
+        if self.failed_attempts >= MAX_RESUME_ATTEMPTS && self.session.is_some() {
+            tracing::info!("Shard {} failed to resume {} times, identifying with a new session", self.gateway.connection_id.shard_id, self.failed_attempts);
+            self.reset_session();
+        }
+
+        self.failed_attempts += 1;
+    }
+
+    /// The url to connect to, resuming must utilise the url sent alongside the session.
+    fn connection_url(&self) -> Result<Url> {
+        match &self.session {
+            Some(session) => Ok(Url::parse(&format!("{}/?v={}&encoding=json", session.resume_gateway_url, DISCORD_API_VERSION))?),
+            None => Ok(self.gateway_url.clone()),
+        }
+    }
+
+    /// The handshake sent once connected, a Resume if there is a session & an Identify otherwise.
+    fn handshake(&self) -> GatewayCommand {
+        match &self.session {
+            Some(session) => GatewayCommand::Resume(Payload::new(6, Resume {
+                token: self.identify_payload.data.token.clone(),
+                session_id: session.session_id.clone(),
+                sequence_number: self.gateway.connection_id.sequence_identifier.load(Ordering::Acquire),
+            })),
+            None => GatewayCommand::Identify(self.identify_payload.clone()),
+        }
+    }
+
+    /// How the connection should end after Discord requested a reconnect or invalidated the session, None for every other payload.
+    fn requested_connection_end(event: &GatewayEvent) -> Option<ConnectionEnd> {
+        match event {
+            GatewayEvent::Reconnect => Some(ConnectionEnd::Resume),
+            GatewayEvent::InvalidSession(true) => Some(ConnectionEnd::Resume),
+            GatewayEvent::InvalidSession(false) => Some(ConnectionEnd::Reidentify),
+            _ => None,
+        }
+    }
+
+    /// Decides how the shard should reconnect from the close frame the gateway sent.
+    fn classify_close(shard_id: u32, close_frame: Option<CloseFrame>) -> ConnectionEnd {
+
+        // A close without a code is treated the same as a dropped connection.
+        let close_code = match close_frame {
+            Some(close_frame) => GatewayCloseCode::from(u16::from(close_frame.code)),
+            None => return ConnectionEnd::Resume,
+        };
+
+        tracing::info!("Shard {shard_id} connection closed: {close_code}");
+
+        match close_code.action() {
+            CloseCodeAction::Resume => ConnectionEnd::Resume,
+            CloseCodeAction::Reidentify => ConnectionEnd::Reidentify,
+            CloseCodeAction::Fatal => ConnectionEnd::Fatal(close_code),
+        }
+    }
+
+    /// Stores the session from a Ready event, and resets the backoff once a session is established or resumed.
+    fn update_session(&mut self, dispatch: &DispatchEvent) {
+        match dispatch {
+            DispatchEvent::Ready(ready) => {
+                self.session = Some(ShardSession {
+                    session_id: ready.session_id.clone(),
+                    resume_gateway_url: ready.resume_gateway_url.clone(),
+                });
+                self.failed_attempts = 0;
+            },
+            DispatchEvent::Resumed => self.failed_attempts = 0,
+            _ => {},
+        }
+    }
+
+    /// Forgets the current session so the next connection identifies with a new one.
+    fn reset_session(&mut self) {
+        self.session = None;
+        self.gateway.connection_id.sequence_identifier.store(0, Ordering::Release);
+    }
+
+    /// Exponential backoff based on the amount of failed attempts with added jitter, capped at [`MAX_BACKOFF_SECONDS`].
+    fn backoff_duration(&self) -> Duration {
+        let exponent = self.failed_attempts.saturating_sub(1).min(6);
+        let backoff = Duration::from_secs((1u64 << exponent).min(MAX_BACKOFF_SECONDS));
+        backoff + Duration::from_millis(rand::random::<u64>() % 1000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    use reqwest::Url;
+    use serde_json::json;
+    use tokio::sync::{broadcast, mpsc};
+    use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+    use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+    use crate::cache::{CacheConfig, InMemoryCache};
+    use crate::fixtures;
+    use crate::gateway::{DispatchEvent, Gateway, GatewayCloseCode, GatewayCommand, GatewayConnectionIdentifier, GatewayEvent, HeartbeatTracker};
+    use crate::gateway_structs::{Identify, IdentifyConnectionProperties, Payload};
+    use crate::snowflake::Id;
+    use super::{CACHE_UPDATE_QUEUE_SIZE, CacheUpdater, ConnectionEnd, MAX_RESUME_ATTEMPTS, ShardHandles, ShardRunner};
+
+    fn runner() -> ShardRunner {
+        let (gateway_sink_sender, _) = mpsc::channel(1);
+        let (gateway_stream_sender, _) = broadcast::channel(1);
+        let gateway = Gateway {
+            gateway_sink_sender,
+            gateway_stream_sender,
+            connection_id: GatewayConnectionIdentifier {
+                shard_id: 0,
+                shard_total: 1,
+                heartbeat_interval: Arc::new(AtomicU32::new(0)),
+                sequence_identifier: Arc::new(AtomicU32::new(0)),
+                heartbeat_tracker: Arc::new(Mutex::new(HeartbeatTracker::default())),
+            },
+        };
+        let identify = Payload::new(2, Identify {
+            token: "token".into(),
+            connection_properties: IdentifyConnectionProperties { operating_system: "linux", browser: "tonsoe", device: "tonsoe" },
+            shard: [0, 1],
+            intents: 0,
+        });
+        let handles = ShardHandles {
+            event_handlers: Arc::new(Vec::new()),
+            http_sender: mpsc::channel(1).0,
+            shard_error_sender: mpsc::channel(1).0,
+            cache: None,
+        };
+        ShardRunner::new(gateway, Url::parse("wss://gateway.discord.gg/?v=10&encoding=json").unwrap(), identify, handles)
+    }
+
+    fn ready() -> DispatchEvent {
+        DispatchEvent::from_name_and_data("READY".to_string(), json!({
+            "v": 10,
+            "user": fixtures::user(1),
+            "guilds": [],
+            "session_id": "session",
+            "resume_gateway_url": "wss://resume.discord.gg",
+            "shard": [0, 1],
+            "application": { "id": "2", "flags": 0 },
+        }))
+    }
+
+    fn close(code: u16) -> ConnectionEnd {
+        ShardRunner::classify_close(0, Some(CloseFrame { code: CloseCode::from(code), reason: "".into() }))
+    }
+
+    #[test]
+    fn reconnect_and_resumable_invalid_session_resume() {
+        assert_eq!(ShardRunner::requested_connection_end(&GatewayEvent::Reconnect), Some(ConnectionEnd::Resume));
+        assert_eq!(ShardRunner::requested_connection_end(&GatewayEvent::InvalidSession(true)), Some(ConnectionEnd::Resume));
+        assert_eq!(ShardRunner::requested_connection_end(&GatewayEvent::InvalidSession(false)), Some(ConnectionEnd::Reidentify));
+        assert_eq!(ShardRunner::requested_connection_end(&GatewayEvent::HeartbeatAck), None);
+        assert_eq!(ShardRunner::requested_connection_end(&GatewayEvent::Heartbeat), None);
+    }
+
+    #[test]
+    fn close_frames_are_classified_by_their_code() {
+        assert_eq!(ShardRunner::classify_close(0, None), ConnectionEnd::Resume);
+        assert_eq!(close(1006), ConnectionEnd::Resume);
+        assert_eq!(close(4000), ConnectionEnd::Resume);
+        assert_eq!(close(4009), ConnectionEnd::Reidentify);
+        assert_eq!(close(4004), ConnectionEnd::Fatal(GatewayCloseCode::AuthenticationFailed));
+    }
+
+    #[test]
+    fn ready_stores_the_session_which_is_resumed() {
+        let mut runner = runner();
+        assert!(matches!(runner.handshake(), GatewayCommand::Identify(_)));
+
+        runner.failed_attempts = 2;
+        runner.update_session(&ready());
+        runner.gateway.connection_id.sequence_identifier.store(42, Ordering::Release);
+        assert_eq!(runner.failed_attempts, 0);
+
+        // The sequence & resume url are kept across a reconnect which resumes.
+        runner.begin_attempt();
+        assert_eq!(runner.connection_url().unwrap().as_str(), "wss://resume.discord.gg/?v=10&encoding=json");
+        match runner.handshake() {
+            GatewayCommand::Resume(resume) => {
+                assert_eq!(resume.data.session_id, "session");
+                assert_eq!(resume.data.sequence_number, 42);
+            },
+            command => panic!("expected a resume, got {command:?}"),
+        }
+
+        runner.update_session(&DispatchEvent::Resumed);
+        assert_eq!(runner.failed_attempts, 0);
+    }
+
+    #[test]
+    fn reset_session_identifies_with_the_gateway_url() {
+        let mut runner = runner();
+        runner.update_session(&ready());
+        runner.gateway.connection_id.sequence_identifier.store(42, Ordering::Release);
+
+        runner.reset_session();
+        assert!(runner.session.is_none());
+        assert_eq!(runner.gateway.connection_id.sequence_identifier.load(Ordering::Acquire), 0);
+        assert_eq!(runner.connection_url().unwrap().as_str(), "wss://gateway.discord.gg/?v=10&encoding=json");
+        assert!(matches!(runner.handshake(), GatewayCommand::Identify(_)));
+    }
+
+    #[test]
+    fn failing_resumes_fall_back_to_identifying() {
+        let mut runner = runner();
+        runner.update_session(&ready());
+
+        for _ in 0..MAX_RESUME_ATTEMPTS {
+            runner.begin_attempt();
+            assert!(runner.session.is_some());
+        }
+
+        runner.begin_attempt();
+        assert!(runner.session.is_none());
+        assert_eq!(runner.failed_attempts, MAX_RESUME_ATTEMPTS + 1);
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_the_cap() {
+        let mut runner = runner();
+        for (failed_attempts, seconds) in [(1, 1), (2, 2), (3, 4), (4, 8), (6, 32), (7, 60), (20, 60)] {
+            runner.failed_attempts = failed_attempts;
+            let backoff = runner.backoff_duration();
+            assert!(backoff >= Duration::from_secs(seconds), "{failed_attempts} attempts waited {backoff:?}");
+            assert!(backoff < Duration::from_secs(seconds) + Duration::from_secs(1), "{failed_attempts} attempts waited {backoff:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn cache_updates_are_applied_in_order() {
+        let cache = Arc::new(InMemoryCache::new(CacheConfig::new()));
+        let updater = CacheUpdater::spawn(0, cache.clone(), CACHE_UPDATE_QUEUE_SIZE);
+
+        let guild_create = DispatchEvent::from_name_and_data("GUILD_CREATE".to_string(), fixtures::guild_create(10, Vec::new(), Vec::new()));
+        let guild_delete = DispatchEvent::from_name_and_data("GUILD_DELETE".to_string(), serde_json::json!({ "id": "10" }));
+        let created = updater.queue(guild_create);
+        let deleted = updater.queue(guild_delete);
+
+        created.await.unwrap();
+        deleted.await.unwrap();
+        assert!(cache.guild(Id::new(10)).is_none());
+    }
+
+    #[tokio::test]
+    async fn cache_update_resolves_once_applied() {
+        let cache = Arc::new(InMemoryCache::new(CacheConfig::new()));
+        let updater = CacheUpdater::spawn(0, cache.clone(), CACHE_UPDATE_QUEUE_SIZE);
+
+        let guild_create = DispatchEvent::from_name_and_data("GUILD_CREATE".to_string(), fixtures::guild_create(10, Vec::new(), Vec::new()));
+        updater.queue(guild_create).await.unwrap();
+        assert!(cache.guild(Id::new(10)).is_some());
+    }
+
+    #[tokio::test]
+    async fn full_cache_queue_doesnt_wait() {
+        let cache = Arc::new(InMemoryCache::new(CacheConfig::new()));
+        let updater = CacheUpdater::spawn(0, cache.clone(), 1);
+
+        // The updater task cant run until the test yields, so the second event finds the queue full.
+        let created = updater.queue(DispatchEvent::from_name_and_data("GUILD_CREATE".to_string(), fixtures::guild_create(10, Vec::new(), Vec::new())));
+        let dropped = updater.queue(DispatchEvent::from_name_and_data("GUILD_CREATE".to_string(), fixtures::guild_create(11, Vec::new(), Vec::new())));
+
+        assert!(dropped.await.is_err());
+        created.await.unwrap();
+        assert!(cache.guild(Id::new(10)).is_some());
+        assert!(cache.guild(Id::new(11)).is_none());
+    }
+}
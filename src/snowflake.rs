@@ -0,0 +1,381 @@
+//! The [`Snowflake`] IDs Discord utilises for every resource & the [`Id`] type which marks what resource an ID belongs to.
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::num::ParseIntError;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The first second of 2015 in milliseconds since the unix epoch, which [`Snowflake`] timestamps are relative to.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// The highest amount of milliseconds since the [`DISCORD_EPOCH`] which fits within the 42 timestamp bits of a [`Snowflake`].
+const MAX_TIMESTAMP: u64 = (1 << 42) - 1;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// [A unique ID][https://discord.com/developers/docs/reference#snowflakes] within Discord, containing the time it was created at.
+/// Discord sends these as strings, but they are deserialized from either a string or an integer.
+pub struct Snowflake(pub u64);
+
+impl Snowflake {
+
+    /// Creates the lowest [`Snowflake`] which could have been created at the unix timestamp in milliseconds.
+    /// Utilised for pagination, e.g: retrieving the messages sent after a point in time.
+    /// Saturates rather than wrapping: timestamps before the [`DISCORD_EPOCH`] create the lowest snowflake,
+    /// & timestamps too far in the future to fit within 42 bits create the snowflake of the latest representable time.
+    pub fn from_unix_millis(unix_millis: u64) -> Self {
+        Self(unix_millis.saturating_sub(DISCORD_EPOCH).min(MAX_TIMESTAMP) << 22)
+    }
+
+    /// Creates the lowest [`Snowflake`] which could have been created at the time.
+    pub fn from_time(time: SystemTime) -> Self {
+        let unix_millis = time.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        Self::from_unix_millis(unix_millis.try_into().unwrap_or(u64::MAX))
+    }
+
+    /// The value of the [`Snowflake`].
+    pub fn get(self) -> u64 {
+        self.0
+    }
+
+    /// When the [`Snowflake`] was created in milliseconds since the unix epoch.
+    pub fn unix_millis(self) -> u64 {
+        (self.0 >> 22) + DISCORD_EPOCH
+    }
+
+    /// When the [`Snowflake`] was created.
+    pub fn created_at(self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.unix_millis())
+    }
+
+    /// The internal ID of the worker which created the [`Snowflake`].
+    pub fn worker_id(self) -> u8 {
+        ((self.0 >> 17) & 0x1F) as u8
+    }
+
+    /// The internal ID of the process which created the [`Snowflake`].
+    pub fn process_id(self) -> u8 {
+        ((self.0 >> 12) & 0x1F) as u8
+    }
+
+    /// Incremented for every [`Snowflake`] created by the process.
+    pub fn increment(self) -> u16 {
+        (self.0 & 0xFFF) as u16
+    }
+}
+
+impl From<u64> for Snowflake {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Snowflake> for u64 {
+    fn from(snowflake: Snowflake) -> Self {
+        snowflake.0
+    }
+}
+
+impl FromStr for Snowflake {
+    type Err = ParseIntError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value.parse().map(Self)
+    }
+}
+
+impl fmt::Display for Snowflake {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for Snowflake {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Snowflake {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SnowflakeVisitor)
+    }
+}
+
+/// Deserializes a [`Snowflake`] from a string or an integer.
+struct SnowflakeVisitor;
+
+impl<'de> de::Visitor<'de> for SnowflakeVisitor {
+    type Value = Snowflake;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a snowflake as a string or integer")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Snowflake(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u64::try_from(value)
+            .map(Snowflake)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+/// A [`Snowflake`] marked with the type of resource it identifies, so e.g: a user ID cant be passed where a channel ID is expected.
+/// The markers are found within [`marker`].
+pub struct Id<Marker> {
+    snowflake: Snowflake,
+    marker: PhantomData<fn() -> Marker>,
+}
+
+impl<Marker> Id<Marker> {
+
+    /// Creates an [`Id`] from the value of its [`Snowflake`].
+    pub const fn new(value: u64) -> Self {
+        Self {
+            snowflake: Snowflake(value),
+            marker: PhantomData,
+        }
+    }
+
+    /// The value of the [`Snowflake`] of the [`Id`].
+    pub fn get(self) -> u64 {
+        self.snowflake.0
+    }
+
+    /// The [`Snowflake`] of the [`Id`], which contains when the resource was created.
+    pub fn snowflake(self) -> Snowflake {
+        self.snowflake
+    }
+
+    /// Changes the marker of the [`Id`], for resources which share an ID such as the @everyone role & its guild.
+    pub fn cast<NewMarker>(self) -> Id<NewMarker> {
+        Id::from(self.snowflake)
+    }
+}
+
+impl<Marker> From<Snowflake> for Id<Marker> {
+    fn from(snowflake: Snowflake) -> Self {
+        Self {
+            snowflake,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Marker> From<u64> for Id<Marker> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Marker> From<Id<Marker>> for Snowflake {
+    fn from(id: Id<Marker>) -> Self {
+        id.snowflake
+    }
+}
+
+impl<Marker> FromStr for Id<Marker> {
+    type Err = ParseIntError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value.parse().map(Self::new)
+    }
+}
+
+// The traits below are implemented manually, as deriving them would require the marker to implement them too.
+
+impl<Marker> Clone for Id<Marker> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Marker> Copy for Id<Marker> {}
+
+impl<Marker> PartialEq for Id<Marker> {
+    fn eq(&self, other: &Self) -> bool {
+        self.snowflake == other.snowflake
+    }
+}
+
+impl<Marker> Eq for Id<Marker> {}
+
+impl<Marker> PartialOrd for Id<Marker> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Marker> Ord for Id<Marker> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.snowflake.cmp(&other.snowflake)
+    }
+}
+
+impl<Marker> Hash for Id<Marker> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.snowflake.hash(state)
+    }
+}
+
+impl<Marker> fmt::Debug for Id<Marker> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let marker = std::any::type_name::<Marker>().rsplit("::").next().unwrap_or_default();
+        write!(f, "Id<{marker}>({})", self.snowflake)
+    }
+}
+
+impl<Marker> fmt::Display for Id<Marker> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.snowflake.fmt(f)
+    }
+}
+
+impl<Marker> Serialize for Id<Marker> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.snowflake.serialize(serializer)
+    }
+}
+
+impl<'de, Marker> Deserialize<'de> for Id<Marker> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Snowflake::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// The markers of each type of resource an [`Id`] can identify.
+pub mod marker {
+
+    /// Marks the [`Id`][super::Id] of an application.
+    pub enum ApplicationMarker {}
+
+    /// Marks the [`Id`][super::Id] of an attachment.
+    pub enum AttachmentMarker {}
+
+    /// Marks the [`Id`][super::Id] of an entry within the audit log of a guild.
+    pub enum AuditLogEntryMarker {}
+
+    /// Marks the [`Id`][super::Id] of an auto moderation rule.
+    pub enum AutoModerationRuleMarker {}
+
+    /// Marks the [`Id`][super::Id] of a channel, including threads.
+    pub enum ChannelMarker {}
+
+    /// Marks the [`Id`][super::Id] of an application command.
+    pub enum CommandMarker {}
+
+    /// Marks the [`Id`][super::Id] of an emoji.
+    pub enum EmojiMarker {}
+
+    /// Marks the [`Id`][super::Id] of an entitlement to a premium offering.
+    pub enum EntitlementMarker {}
+
+    /// Marks the [`Id`][super::Id] of a guild.
+    pub enum GuildMarker {}
+
+    /// Marks the [`Id`][super::Id] of an integration.
+    pub enum IntegrationMarker {}
+
+    /// Marks the [`Id`][super::Id] of an interaction.
+    pub enum InteractionMarker {}
+
+    /// Marks the [`Id`][super::Id] of a message.
+    pub enum MessageMarker {}
+
+    /// Marks the [`Id`][super::Id] of a role.
+    pub enum RoleMarker {}
+
+    /// Marks the [`Id`][super::Id] of a scheduled event within a guild.
+    pub enum ScheduledEventMarker {}
+
+    /// Marks the [`Id`][super::Id] of a SKU, a premium offering of an application.
+    pub enum SkuMarker {}
+
+    /// Marks the [`Id`][super::Id] of a soundboard sound.
+    pub enum SoundMarker {}
+
+    /// Marks the [`Id`][super::Id] of a stage instance.
+    pub enum StageInstanceMarker {}
+
+    /// Marks the [`Id`][super::Id] of a sticker.
+    pub enum StickerMarker {}
+
+    /// Marks the [`Id`][super::Id] of a premium subscription.
+    pub enum SubscriptionMarker {}
+
+    /// Marks the [`Id`][super::Id] of a user.
+    pub enum UserMarker {}
+
+    /// Marks the [`Id`][super::Id] of a webhook.
+    pub enum WebhookMarker {}
+
+    /// Marks the [`Id`][super::Id] of a resource which can be one of several types, such as the target of a permission overwrite.
+    pub enum GenericMarker {}
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{DISCORD_EPOCH, Id, Snowflake};
+    use super::marker::UserMarker;
+
+    /// The example from the [Discord documentation][https://discord.com/developers/docs/reference#snowflakes]
+    const EXAMPLE: Snowflake = Snowflake(175928847299117063);
+
+    #[test]
+    fn documented_snowflake_is_decomposed() {
+        assert_eq!(EXAMPLE.unix_millis(), 1462015105796);
+        assert_eq!(EXAMPLE.unix_millis() - DISCORD_EPOCH, 41944705796);
+        assert_eq!(EXAMPLE.worker_id(), 1);
+        assert_eq!(EXAMPLE.process_id(), 0);
+        assert_eq!(EXAMPLE.increment(), 7);
+    }
+
+    #[test]
+    fn lowest_snowflake_of_a_time_is_created() {
+        let snowflake = Snowflake::from_unix_millis(EXAMPLE.unix_millis());
+        assert_eq!(snowflake.unix_millis(), EXAMPLE.unix_millis());
+        assert_eq!((snowflake.worker_id(), snowflake.process_id(), snowflake.increment()), (0, 0, 0));
+        assert!(snowflake <= EXAMPLE);
+    }
+
+    #[test]
+    fn snowflakes_deserialize_from_strings_and_integers() {
+        assert_eq!(serde_json::from_value::<Snowflake>(json!("175928847299117063")).unwrap(), EXAMPLE);
+        assert_eq!(serde_json::from_value::<Snowflake>(json!(175928847299117063u64)).unwrap(), EXAMPLE);
+        assert_eq!(serde_json::from_value::<Id<UserMarker>>(json!("175928847299117063")).unwrap(), Id::new(EXAMPLE.get()));
+
+        assert!(serde_json::from_value::<Snowflake>(json!(-1)).is_err());
+        assert!(serde_json::from_value::<Snowflake>(json!("not a snowflake")).is_err());
+    }
+
+    #[test]
+    fn ids_serialize_as_strings() {
+        let id: Id<UserMarker> = Id::new(EXAMPLE.get());
+        assert_eq!(serde_json::to_value(id).unwrap(), json!("175928847299117063"));
+        assert_eq!(id.to_string(), "175928847299117063");
+        assert_eq!(format!("{id:?}"), "Id<UserMarker>(175928847299117063)");
+    }
+
+    #[test]
+    fn timestamps_outside_of_42_bits_saturate() {
+        assert_eq!(Snowflake::from_unix_millis(0), Snowflake(0));
+        assert_eq!(Snowflake::from_unix_millis(DISCORD_EPOCH - 1), Snowflake(0));
+
+        let latest = Snowflake::from_unix_millis(u64::MAX);
+        assert_eq!(latest.unix_millis(), DISCORD_EPOCH + (1 << 42) - 1);
+        assert_eq!(Snowflake::from_unix_millis(DISCORD_EPOCH + (1 << 42)), latest);
+    }
+}
@@ -3,15 +3,17 @@ use std::env;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU32;
+use std::time::Duration;
 use crate::DISCORD_API_VERSION;
 use crate::bot::*;
 use crate::gateway::*;
 use crate::gateway_structs::*;
-use anyhow::Context;
+use crate::shard::{ShardRunner, ShardHandles, ShardErrorSender};
+use crate::http::DiscordHttpClientReqSender;
 use futures_util::SinkExt;
 use futures_util::StreamExt;
 use async_trait::async_trait;
-use anyhow::Result;
+use crate::error::{Error, Result};
 use futures_util::stream::SplitSink;
 use futures_util::stream::SplitStream;
 use reqwest::Url;
@@ -20,7 +22,6 @@ use tokio::net::TcpStream;
 use tokio::sync::mpsc::{Receiver as GatewaySinkReceiver};
 
 use tokio::sync::*;
-use tokio::task;
 use tokio_tungstenite::MaybeTlsStream;
 use tokio_tungstenite::WebSocketStream;
 use tokio_tungstenite::tungstenite::Message;
@@ -45,7 +46,9 @@ pub struct DiscordGatewayClient {
 impl DiscordGatewayClient {
 
     /// Creates a new [`DiscordGatewayClient`] with sharded gateway connections using information provided by the GET gateway/bot request.
-    pub async fn new_with_shards(bot: &Bot, gateway_bot_response: GetGatewayBotResponse) -> Result<Self> {
+    /// Every shard recieves a clone of the [`Bot`]s event handlers & the [`DiscordHttpClientReqSender`] so events can be responded to.
+    /// Errors which stop a shard are sent through shard_error_sender rather than panicking within the shards task.
+    pub async fn new_with_shards(bot: &Bot, gateway_bot_response: GetGatewayBotResponse, http_sender: DiscordHttpClientReqSender, shard_error_sender: ShardErrorSender) -> Result<Self> {
 
         // Get amount of shards to use when connecting to the Discord Gateway
         let shard_amount = match bot.sharding_option {
@@ -59,7 +62,7 @@ impl DiscordGatewayClient {
             ShardingOption::SetAmount(amount) => {
                 
                 if amount == 0 {
-                    return Err(anyhow::Error::msg("Amount of shards set manually must be > 0"))
+                    return Err(Error::Configuration("Amount of shards set manually must be > 0".to_string()))
                 }
 
                 amount
@@ -70,8 +73,7 @@ impl DiscordGatewayClient {
         let session_limits = gateway_bot_response.session_start_limit;
 
         // Get the url for the gateway we want the shards to connect to.
-        let gateway_url = Url::from_str(&format!("{}/?v{}&encoding=json", gateway_bot_response.url, DISCORD_API_VERSION))
-            .context("Failed to create Discord Gateway websocket URL")?;
+        let gateway_url = Url::from_str(&format!("{}/?v={}&encoding=json", gateway_bot_response.url, DISCORD_API_VERSION))?;
         
         // Create the map for shards, this is wrapped in an Arc & a RwLock since we dont want to wait for ALL shards to be started before the bot can respond etc.
         let shard_map = Arc::new(RwLock::new(HashMap::new()));
@@ -92,6 +94,14 @@ impl DiscordGatewayClient {
         // Get the raw value of the intents stored within the bot to send to discord.
         let intents = bot.intents.bits();
 
+        // The handlers & senders shared between all shards.
+        let shard_handles = ShardHandles {
+            event_handlers: Arc::new(bot.event_handlers.clone()),
+            http_sender,
+            shard_error_sender,
+            cache: bot.cache.clone(),
+        };
+
         let shard_map_spawning   = shard_map.clone();
         // Create the shards, since there is a wait required we spawn this as a task so it doesnt block other functionality from existing shards which have already been created.
         tokio::spawn(
@@ -101,7 +111,7 @@ impl DiscordGatewayClient {
                     // The payload to send to discord to handshake with the gateway for our bot
                     let shard_identify = Identify {
                         token: token.clone(),
-                        connection_properties: connection_properties,
+                        connection_properties,
                         shard: [shard_id, shard_amount],
                         intents,
                     };
@@ -115,10 +125,15 @@ impl DiscordGatewayClient {
                         tokio::time::sleep(tokio::time::Duration::from_secs(5)).await
                     }
                     
-                    // Create the shard and add it to the map
-                    shard_map_spawning.add_shard_to_map(shard_id, shard_amount, &gateway_url, identify_payload)
-                        .await
-                        .expect("Attempted to spawn shard");
+                    // Create the shard and add it to the map, if it fails no further shards are started & the error is reported.
+                    let shard_result = shard_map_spawning.add_shard_to_map(shard_id, shard_amount, &gateway_url, identify_payload, shard_handles.clone())
+                        .await;
+
+                    if let Err(error) = shard_result {
+                        tracing::error!("Failed to start shard {shard_id}: {error}");
+                        let _ = shard_handles.shard_error_sender.send(error).await;
+                        return
+                    }
                     
                 }
             }
@@ -134,13 +149,14 @@ impl DiscordGatewayClient {
 /// Trait which exists so we can add a method which will add a new shard to a [`ShardMap`]
 pub trait NewShardToMap {
     /// Method which adds a new shard to this [`ShardMap`]
-    async fn add_shard_to_map(&self, shard_id: u32, total_shards: u32, gateway_url: &Url, identify_payload: Payload<Identify>) -> Result<()> ;
+    async fn add_shard_to_map(&self, shard_id: u32, total_shards: u32, gateway_url: &Url, identify_payload: Payload<Identify>, shard_handles: ShardHandles) -> Result<()> ;
 }
 
 #[async_trait]
 impl NewShardToMap for ShardMap {
-    /// Creates & connects a new shard [`Gateway`] to the discord api and adds the [`Gateway`] to this [`ShardMap`]
-    async fn add_shard_to_map(&self, shard_id: u32, shard_amount: u32, gateway_url: &Url, identify_payload: Payload<Identify>) -> Result<()> {
+    /// Creates a new shard [`Gateway`], adds it to this [`ShardMap`] & spawns the [`ShardRunner`] which keeps it connected to the discord api.
+    /// Returns once the shard has sent its first Identify payload.
+    async fn add_shard_to_map(&self, shard_id: u32, shard_amount: u32, gateway_url: &Url, identify_payload: Payload<Identify>, shard_handles: ShardHandles) -> Result<()> {
 
         // Create the sender and reciever utilised for the Gateway when recieving commands
         let (sink_channel_sender, sink_channel_reciever) = mpsc::channel(32);
@@ -148,65 +164,69 @@ impl NewShardToMap for ShardMap {
         // Create the sender and reciever utilised for the Gateway when sending responses recieved from discord.
         let (stream_channel_sender, _stream_channel_reciever) = broadcast::channel(32);
 
-        println!("{:#?}", gateway_url.to_string());
-
-        // Since we have to spawn them in order from 0 -> shard_amount we cant spawn them concurrently, utilises except as failing to create a shard can lead to catastrophic failure.
-        let (websocket_stream, _response) = tokio_tungstenite::connect_async(gateway_url).await
-            .expect("Attempted to create Gateway Shard");
-
-        // Split the stream up into a sink and a stream for channels.
-        let (write_sink, mut read_stream) = websocket_stream.split();
-
-        // Get the Hello payload send from discord.
-        let hello_payload = read_stream.read_deserialize_next_payload::<Payload<Hello>>().await
-            .context("Failed to recieve Hello Payload from Discord Gateway")?;
-
         // Identifier struct for our connection containing information on sharding & heartbeating
+        // The heartbeat interval is set from the Hello payload each time the shard connects.
         let connection_identifier = GatewayConnectionIdentifier {
             shard_id,
             shard_total: shard_amount,
-            heartbeat_interval: hello_payload.data.heartbeat_interval,
-            sequence_identifier: Arc::new(AtomicU32::new(0))
+            heartbeat_interval: Arc::new(AtomicU32::new(0)),
+            sequence_identifier: Arc::new(AtomicU32::new(0)),
+            heartbeat_tracker: Arc::new(std::sync::Mutex::new(HeartbeatTracker::default())),
         };
 
-        // Send the identify payload through the sink which will send the handshake payload to the gateway 
-        sink_channel_sender.send(GatewayCommand::Identify(identify_payload)).await
-        .expect("Failed to send Identify Payload to sink channel.");
-
         // Create our Gateway utilised for communicating with the channels which send and recieve to Discords Gateway
         let gateway = Gateway {
             gateway_sink_sender:sink_channel_sender,
             gateway_stream_sender:stream_channel_sender, 
-            connection_id: connection_identifier.clone(), 
+            connection_id: connection_identifier, 
         };
 
-        // Spawn the processing function for sending values through the gateway.
-        tokio::spawn(async move {
-            process_gateway_send_commands(sink_channel_reciever, write_sink).await
-        });
-
-        let heartbeat_gateway = gateway.clone();
-        // Spawn the function which will keep sending heartbeats to Discords gateway.
-        tokio::spawn( async move {
-            heartbeat_gateway.heartbeat().await
-        });
+        // The runner which connects the shard, and reconnects or resumes it whenever the connection is lost.
+        let shard_runner = ShardRunner::new(gateway.clone(), gateway_url.clone(), identify_payload, shard_handles);
 
-        let receiving_gateway = gateway.clone();
-        // Spawn the function which will recieve gateway events
-        tokio::spawn( async move {
-            receiving_gateway.recieve_gateway_events(read_stream).await
+        // Spawn the shard, it notifies us once it has identified so shards are still started in order.
+        let (identified_sender, identified_reciever) = oneshot::channel();
+        tokio::spawn(async move {
+            shard_runner.run(sink_channel_reciever, identified_sender).await
         });
 
         // Gain the write guard to our shard_map so we can add this Gateway to it.
-        let mut shard_map_write = self.write().await;
+        self.write().await.insert(shard_id, gateway);
 
-        // Insert our gateway into the shard_map
-        shard_map_write.insert(shard_id, gateway);
+        // The runner only drops this without sending if it stopped, in which case it has already reported why.
+        identified_reciever.await
+            .map_err(|_| Error::ChannelClosed("Shard identify"))?;
 
         Ok(())
     }
 }
 
+#[async_trait]
+/// Trait which exists so the latency of shards can be read from a [`ShardMap`]
+pub trait ShardLatency {
+    /// The heartbeat latency of a shard, None if the shard doesnt exist or hasnt had a heartbeat acknowledged yet.
+    async fn shard_latency(&self, shard_id: u32) -> Option<Duration>;
+
+    /// The heartbeat latency of every shard in this [`ShardMap`] which has had a heartbeat acknowledged.
+    async fn shard_latencies(&self) -> HashMap<u32, Duration>;
+}
+
+#[async_trait]
+impl ShardLatency for ShardMap {
+    async fn shard_latency(&self, shard_id: u32) -> Option<Duration> {
+        self.read().await
+            .get(&shard_id)
+            .and_then(Gateway::latency)
+    }
+
+    async fn shard_latencies(&self) -> HashMap<u32, Duration> {
+        self.read().await
+            .iter()
+            .filter_map(|(shard_id, gateway)| Some((*shard_id, gateway.latency()?)))
+            .collect()
+    }
+}
+
 #[async_trait]
 /// Trait which adds deserialization read methods to payloads recieved from the Discord Gateway
 pub trait DeserializeRecievePayload {
@@ -222,8 +242,7 @@ impl DeserializeRecievePayload for ReadSplitStream {
         // Recieve the next payload from the gateway
         let next_payload = self.next()
             .await
-            .context("Gateway was closed when attempting to read next item")?
-            .context("Failed in checking to see if Gateway was connected")?;
+            .ok_or(Error::GatewayConnectionEnded)??;
 
         // Convert the recieved payload message into &str type for deserialization
         let string_payload = next_payload.to_string();
@@ -232,8 +251,7 @@ impl DeserializeRecievePayload for ReadSplitStream {
         tokio::task::spawn_blocking( move || -> Result<T> {
             
             // Deserialize the payload and return it
-            serde_json::from_str::<T>(&string_payload)
-                .context(format!("Failed to deserialize payload into type {:?}", std::any::type_name::<T>()))
+            Ok(serde_json::from_str::<T>(&string_payload)?)
         
 
         }).await?
@@ -242,36 +260,31 @@ impl DeserializeRecievePayload for ReadSplitStream {
 
 
 /// Process a [`GatewayCommand`] send through the channel into a Message & send the message to Discords gateway.
-pub async fn process_gateway_send_commands(mut sink_channel_reciever: GatewaySinkReceiver<GatewayCommand>, sink: WriteSplitSink) {
-    
-    // Create a new Mutex with Arc for concurrency with the split sink
-    let sink = Arc::new(Mutex::new(sink));
+/// Commands are sent in the order they are recieved, returns once the connection is unable to send any more payloads.
+pub async fn process_gateway_send_commands(sink_channel_reciever: &mut GatewaySinkReceiver<GatewayCommand>, sink: &mut WriteSplitSink) {
 
     // Recieve new inbound gateway commands from the reciever
     while let Some(command) = sink_channel_reciever.recv().await {
 
-        // Clone/create another Arc to the sink so we can send it into the tokio task for sending to discord.
-        let send_sink = sink.clone();
-
-        // Spawn a task to send the commands payload through the gateway
-        task::spawn( async move {
-
-            // Serialize the recieved payload into a message so we can send it through the sink
-            let message_payload =  match command {
-                GatewayCommand::Heartbeat(heartbeat_payload) => heartbeat_payload.serialize_to_message().await,
-                GatewayCommand::Identify(identify_payload) => identify_payload.serialize_to_message().await,
-            }.context("Failed to Serialize payload into Message").unwrap();
-
-            // Send the message through the sink.
-            send_sink
-                .lock()
-                .await
-                .send(message_payload)
-                .await
-                .expect("Failed to send payload to Discord")
+        // Serialize the recieved payload into a message so we can send it through the sink
+        let message_payload = match command {
+            GatewayCommand::Heartbeat(heartbeat_payload) => heartbeat_payload.serialize_to_message().await,
+            GatewayCommand::Identify(identify_payload) => identify_payload.serialize_to_message().await,
+            GatewayCommand::Resume(resume_payload) => resume_payload.serialize_to_message().await,
+        };
 
-        });
+        let message_payload = match message_payload {
+            Ok(message_payload) => message_payload,
+            Err(error) => {
+                tracing::warn!("Failed to serialize payload for the Discord Gateway: {error}");
+                continue
+            },
+        };
 
+        // Send the message through the sink, the connection has been lost if this fails.
+        if let Err(error) = sink.send(message_payload).await {
+            tracing::warn!("Failed to send payload to the Discord Gateway: {error}");
+            return
+        }
     }
-
-}
\ No newline at end of file
+}